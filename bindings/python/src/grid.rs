@@ -0,0 +1,339 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use dilemma_tactix_lib::{
+    Choice,
+    GameGrid,
+    MatchHistory,
+    Strategy,
+    StrategyKind,
+    Tournament,
+};
+use numpy::{
+    ndarray::Array2,
+    IntoPyArray,
+    PyArray2,
+};
+use pyo3::{
+    prelude::*,
+    types::PyDict,
+};
+
+use crate::{
+    options::{
+        PyChoice,
+        PyGameOptions,
+        PyNumberPair,
+    },
+    value_error,
+};
+
+/// Creates the built-in strategy with the given name.
+fn strategy(name: &str, seed: Option<u64>) -> PyResult<Box<dyn Strategy + Send>> {
+    name.parse::<StrategyKind>()
+        .map(|kind| kind.strategy(seed))
+        .map_err(value_error)
+}
+
+/// Collects one value per round and player into a `(rounds, 2)` array.
+fn per_round<T, F>(history: &MatchHistory, mut value: F) -> Array2<T>
+where
+    F: FnMut(usize, usize) -> T,
+{
+    Array2::from_shape_fn((history.len(), 2), |(round, player)| value(round, player))
+}
+
+/// A grid of payoffs that matches are played on.
+#[pyclass(name = "GameGrid", module = "dilemma_tactix", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub struct PyGameGrid(GameGrid);
+
+#[pymethods]
+impl PyGameGrid {
+    #[new]
+    const fn new(game_options: PyGameOptions) -> Self {
+        Self(GameGrid::new(game_options.0))
+    }
+
+    /// The options the grid was made from.
+    #[getter]
+    const fn game_options(&self) -> PyGameOptions {
+        PyGameOptions(self.0.game_options)
+    }
+
+    /// Returns the scores for a pair of choices.
+    fn return_score(&self, aleph_choice: PyChoice, beth_choice: PyChoice) -> PyNumberPair {
+        PyNumberPair(self.0.return_score(aleph_choice.into(), beth_choice.into()))
+    }
+
+    /// Returns the grid as the table printed by `show_grid()`.
+    fn make_grid(&self) -> String {
+        self.0.make_grid().to_string()
+    }
+
+    /// Prints the grid to the standard output of the process.
+    fn show_grid(&self) {
+        self.0.show_grid();
+    }
+
+    /// Plays a match between two built-in strategies.
+    ///
+    /// `seed` seeds the `random` strategy, which is otherwise seeded from the
+    /// operating system. Raises `ValueError` for an unknown strategy.
+    #[pyo3(signature = (aleph, beth, rounds, seed = None))]
+    fn play_match(
+        &self,
+        py: Python<'_>,
+        aleph: &str,
+        beth: &str,
+        rounds: usize,
+        seed: Option<u64>,
+    ) -> PyResult<PyMatchResult> {
+        let mut aleph_strategy = strategy(aleph, seed)?;
+        let mut beth_strategy = strategy(beth, seed)?;
+
+        let history = py.detach(|| {
+            self.0
+                .play_match(aleph_strategy.as_mut(), beth_strategy.as_mut(), rounds)
+        });
+
+        Ok(PyMatchResult {
+            aleph: aleph_strategy.name().to_string(),
+            beth: beth_strategy.name().to_string(),
+            history,
+        })
+    }
+
+    /// Plays every built-in strategy named in `strategies` against every
+    /// other one.
+    ///
+    /// `seed` seeds the `random` strategy, which is otherwise seeded from the
+    /// operating system. Raises `ValueError` for an unknown strategy.
+    #[pyo3(signature = (strategies, rounds, seed = None))]
+    fn play_tournament(
+        &self,
+        py: Python<'_>,
+        strategies: Vec<String>,
+        rounds: usize,
+        seed: Option<u64>,
+    ) -> PyResult<PyTournamentResult> {
+        let mut entrants = strategies
+            .iter()
+            .map(|name| strategy(name, seed))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let tournament = py.detach(|| Tournament::round_robin(&self.0, &mut entrants, rounds));
+
+        Ok(PyTournamentResult(tournament))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GameGrid({})", self.0.game_options)
+    }
+
+    fn __str__(&self) -> String {
+        self.make_grid()
+    }
+}
+
+/// The outcome of a match, with one row per round played.
+///
+/// Choices are given as `0` for Atlantis and `1` for Olympus, and the first
+/// column of every array belongs to Aleph and the second to Beth.
+#[pyclass(name = "MatchResult", module = "dilemma_tactix", frozen)]
+#[derive(Debug, Clone)]
+pub struct PyMatchResult {
+    aleph:   String,
+    beth:    String,
+    history: MatchHistory,
+}
+
+#[pymethods]
+impl PyMatchResult {
+    /// The name of the strategy playing as Aleph.
+    #[getter]
+    fn aleph(&self) -> &str {
+        &self.aleph
+    }
+
+    /// The name of the strategy playing as Beth.
+    #[getter]
+    fn beth(&self) -> &str {
+        &self.beth
+    }
+
+    /// The choices made, as a `uint8` array of shape `(rounds, 2)`.
+    #[getter]
+    fn choices<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<u8>> {
+        let (aleph, beth) = (self.history.aleph_choices(), self.history.beth_choices());
+
+        per_round(&self.history, |round, player| {
+            let choice = if player == 0 {
+                aleph[round]
+            } else {
+                beth[round]
+            };
+
+            u8::from(choice == Choice::Olympus)
+        })
+        .into_pyarray(py)
+    }
+
+    /// The score of every round, as a `uint32` array of shape `(rounds, 2)`.
+    #[getter]
+    fn scores<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<u32>> {
+        let scores = self.history.scores();
+
+        per_round(&self.history, |round, player| {
+            if player == 0 {
+                scores[round].first()
+            } else {
+                scores[round].second()
+            }
+        })
+        .into_pyarray(py)
+    }
+
+    /// The running totals after every round, as a `uint64` array of shape
+    /// `(rounds, 2)`.
+    #[getter]
+    fn cumulative<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<u64>> {
+        let mut totals = [0_u64; 2];
+        let scores = self.history.scores();
+
+        per_round(&self.history, |round, player| {
+            let score = if player == 0 {
+                scores[round].first()
+            } else {
+                scores[round].second()
+            };
+
+            totals[player] += u64::from(score);
+
+            totals[player]
+        })
+        .into_pyarray(py)
+    }
+
+    /// Aleph's total score.
+    #[getter]
+    fn aleph_total(&self) -> u64 {
+        self.history.aleph_total()
+    }
+
+    /// Beth's total score.
+    #[getter]
+    fn beth_total(&self) -> u64 {
+        self.history.beth_total()
+    }
+
+    /// `"Aleph"` or `"Beth"`, or `None` for a tie.
+    #[getter]
+    fn winner(&self) -> Option<String> {
+        self.history.winner().map(|player| format!("{player:?}"))
+    }
+
+    /// Why the match was cut short, or `None` if every round was played.
+    #[getter]
+    fn forfeit(&self) -> Option<String> {
+        self.history.forfeit().map(|forfeit| {
+            format!(
+                "{:?} forfeited in round {}: {}",
+                forfeit.player, forfeit.round, forfeit.reason
+            )
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.history.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MatchResult(aleph={:?}, beth={:?}, rounds={}, totals=({}, {}))",
+            self.aleph,
+            self.beth,
+            self.history.len(),
+            self.history.aleph_total(),
+            self.history.beth_total()
+        )
+    }
+}
+
+/// The outcome of a round-robin tournament.
+#[pyclass(name = "TournamentResult", module = "dilemma_tactix", frozen)]
+#[derive(Debug)]
+pub struct PyTournamentResult(Tournament);
+
+#[pymethods]
+impl PyTournamentResult {
+    /// The names of the entrants, in the order they were given.
+    #[getter]
+    fn names(&self) -> Vec<String> {
+        self.0.names().to_vec()
+    }
+
+    /// The total each entrant scored against each other entrant, as a
+    /// `uint64` array of shape `(entrants, entrants)`.
+    ///
+    /// Row `i`, column `j` holds what entrant `i` scored in its match against
+    /// entrant `j`.
+    #[getter]
+    fn scores<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<u64>> {
+        let entrants = self.0.names().len();
+        let mut scores = Array2::zeros((entrants, entrants));
+
+        for pairing in self.0.pairings() {
+            scores[[pairing.aleph, pairing.beth]] = pairing.history.aleph_total();
+            scores[[pairing.beth, pairing.aleph]] = pairing.history.beth_total();
+        }
+
+        scores.into_pyarray(py)
+    }
+
+    /// Every match played, in the order they were played.
+    #[getter]
+    fn matches(&self) -> Vec<PyMatchResult> {
+        let names = self.0.names();
+
+        self.0
+            .pairings()
+            .iter()
+            .map(|pairing| PyMatchResult {
+                aleph:   names[pairing.aleph].clone(),
+                beth:    names[pairing.beth].clone(),
+                history: pairing.history.clone(),
+            })
+            .collect()
+    }
+
+    /// The standings, best first, as a list of dicts with the keys `name`,
+    /// `total`, `wins`, `ties` and `losses`.
+    fn standings<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.0
+            .standings()
+            .into_iter()
+            .map(|standing| {
+                let row = PyDict::new(py);
+
+                row.set_item("name", standing.name)?;
+                row.set_item("total", standing.total)?;
+                row.set_item("wins", standing.wins)?;
+                row.set_item("ties", standing.ties)?;
+                row.set_item("losses", standing.losses)?;
+
+                Ok(row)
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TournamentResult(names={:?}, matches={})",
+            self.0.names(),
+            self.0.pairings().len()
+        )
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Python bindings for the Dilemma Tactix library.
+//!
+//! The extension module is called `dilemma_tactix` and is built with
+//! [maturin](https://www.maturin.rs):
+//!
+//! ```text
+//! cd bindings/python
+//! maturin develop --release
+//! ```
+//!
+//! It mirrors the Rust API for setting up games, and returns simulation
+//! output as numpy arrays so that it can be analysed without scraping the
+//! tables printed by the binaries:
+//!
+//! ```python
+//! import dilemma_tactix as dt
+//!
+//! grid = dt.GameGrid(dt.GameOptions.preset("prisoners_dilemma"))
+//! result = grid.play_match("tit-for-tat", "always-defect", 100)
+//!
+//! result.cumulative[-1]  # array([ 99, 104], dtype=uint64)
+//! ```
+//!
+//! # Classes
+//!
+//! | Name | Rust Type |
+//! | ---- | --------- |
+//! | `Choice` | [`Choice`](dilemma_tactix_lib::Choice) |
+//! | `NumberPair` | [`NumberPair`](dilemma_tactix_lib::NumberPair) |
+//! | `GameOptions` | [`GameOptions`](dilemma_tactix_lib::GameOptions) |
+//! | `GameOptionsBuilder` | [`GameOptionsBuilder`](dilemma_tactix_lib::GameOptionsBuilder) |
+//! | `GameGrid` | [`GameGrid`](dilemma_tactix_lib::GameGrid) |
+//! | `MatchResult` | [`MatchHistory`](dilemma_tactix_lib::MatchHistory) |
+//! | `TournamentResult` | [`Tournament`](dilemma_tactix_lib::Tournament) |
+
+mod grid;
+mod options;
+
+use dilemma_tactix_lib::{
+    BuilderError,
+    GamePresets,
+    StrategyKind,
+};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+};
+
+/// Turns a library error into a Python `ValueError`.
+fn value_error(error: BuilderError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Returns the names of the built-in strategies.
+#[pyfunction]
+fn strategies() -> Vec<&'static str> {
+    StrategyKind::ALL.iter().map(StrategyKind::name).collect()
+}
+
+/// Returns the names of the preset games.
+#[pyfunction]
+fn presets() -> Vec<&'static str> {
+    GamePresets::preset_names().to_vec()
+}
+
+/// The `dilemma_tactix` Python module.
+#[pymodule]
+fn dilemma_tactix(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Fail on import rather than on the first array returned.
+    m.py().import("numpy")?;
+
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+
+    m.add_class::<options::PyChoice>()?;
+    m.add_class::<options::PyNumberPair>()?;
+    m.add_class::<options::PyGameOptions>()?;
+    m.add_class::<options::PyGameOptionsBuilder>()?;
+    m.add_class::<grid::PyGameGrid>()?;
+    m.add_class::<grid::PyMatchResult>()?;
+    m.add_class::<grid::PyTournamentResult>()?;
+
+    m.add_function(wrap_pyfunction!(strategies, m)?)?;
+    m.add_function(wrap_pyfunction!(presets, m)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,342 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use dilemma_tactix_lib::{
+    Choice,
+    GameOptions,
+    GameOptionsBuilder,
+    GameOptionsBuilderTypes,
+    GameParams,
+    NumberPair,
+};
+use numpy::{
+    ndarray::Array3,
+    IntoPyArray,
+    PyArray3,
+};
+use pyo3::prelude::*;
+
+use crate::value_error;
+
+/// One of the two choices available to each player.
+///
+/// `Atlantis` is the first choice of a game and `Olympus` the second; what
+/// they are called in a particular game is given by
+/// `GameOptions.choice_name()`.
+#[pyclass(name = "Choice", module = "dilemma_tactix", eq, eq_int, frozen, hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PyChoice {
+    /// The first choice, cooperating in the classic games.
+    Atlantis,
+    /// The second choice, defecting in the classic games.
+    Olympus,
+}
+
+impl From<Choice> for PyChoice {
+    fn from(choice: Choice) -> Self {
+        match choice {
+            Choice::Atlantis => Self::Atlantis,
+            Choice::Olympus => Self::Olympus,
+        }
+    }
+}
+
+impl From<PyChoice> for Choice {
+    fn from(choice: PyChoice) -> Self {
+        match choice {
+            PyChoice::Atlantis => Self::Atlantis,
+            PyChoice::Olympus => Self::Olympus,
+        }
+    }
+}
+
+/// A pair of scores, the first for Aleph and the second for Beth.
+#[pyclass(name = "NumberPair", module = "dilemma_tactix", eq, frozen, hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyNumberPair(pub NumberPair);
+
+#[pymethods]
+impl PyNumberPair {
+    #[new]
+    const fn new(first: u32, second: u32) -> Self {
+        Self(NumberPair::new(first, second))
+    }
+
+    /// Aleph's score.
+    #[getter]
+    const fn first(&self) -> u32 {
+        self.0.first()
+    }
+
+    /// Beth's score.
+    #[getter]
+    const fn second(&self) -> u32 {
+        self.0.second()
+    }
+
+    /// Returns the pair as a `(first, second)` tuple.
+    #[pyo3(name = "to_tuple")]
+    const fn as_tuple(&self) -> (u32, u32) {
+        (self.0.first(), self.0.second())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("NumberPair({}, {})", self.0.first(), self.0.second())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// A score pair given from Python, either as a `NumberPair` or as a plain
+/// `(first, second)` tuple.
+#[derive(FromPyObject)]
+pub enum PairArg {
+    /// A `NumberPair`.
+    Pair(PyNumberPair),
+    /// A `(first, second)` tuple.
+    Tuple((u32, u32)),
+}
+
+impl From<PairArg> for NumberPair {
+    fn from(pair: PairArg) -> Self {
+        match pair {
+            PairArg::Pair(pair) => pair.0,
+            PairArg::Tuple((first, second)) => Self::new(first, second),
+        }
+    }
+}
+
+/// The choices and payoffs of a single game.
+///
+/// Build one with `GameOptionsBuilder`, take a preset with
+/// `GameOptions.preset()`, or call `GameOptions(min_value, max_value)` for a
+/// random game.
+#[pyclass(name = "GameOptions", module = "dilemma_tactix", eq, frozen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PyGameOptions(pub GameOptions);
+
+#[pymethods]
+impl PyGameOptions {
+    #[new]
+    #[pyo3(signature = (min_value = 1, max_value = 10))]
+    fn new(min_value: u32, max_value: u32) -> PyResult<Self> {
+        GameOptions::try_new(min_value, max_value)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Returns the preset game with the given name.
+    ///
+    /// Raises `ValueError` if there is no such preset.
+    #[staticmethod]
+    fn preset(name: &str) -> PyResult<Self> {
+        GameOptionsBuilder::from_preset(name)
+            .and_then(GameOptionsBuilder::build)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// The name of the Atlantis choice.
+    #[getter]
+    const fn choice_atlantis(&self) -> &str {
+        self.0.choice_atlantis()
+    }
+
+    /// The name of the Olympus choice.
+    #[getter]
+    const fn choice_olympus(&self) -> &str {
+        self.0.choice_olympus()
+    }
+
+    /// The scores when both players choose Atlantis.
+    #[getter]
+    const fn atlantis_atlantis(&self) -> PyNumberPair {
+        PyNumberPair(self.0.atlantis_atlantis())
+    }
+
+    /// The scores when Aleph chooses Atlantis and Beth chooses Olympus.
+    #[getter]
+    const fn atlantis_olympus(&self) -> PyNumberPair {
+        PyNumberPair(self.0.atlantis_olympus())
+    }
+
+    /// The scores when Aleph chooses Olympus and Beth chooses Atlantis.
+    #[getter]
+    const fn olympus_atlantis(&self) -> PyNumberPair {
+        PyNumberPair(self.0.olympus_atlantis())
+    }
+
+    /// The scores when both players choose Olympus.
+    #[getter]
+    const fn olympus_olympus(&self) -> PyNumberPair {
+        PyNumberPair(self.0.olympus_olympus())
+    }
+
+    /// Returns the name this game gives to a choice.
+    fn choice_name(&self, choice: PyChoice) -> &str {
+        self.0.choice_name(choice.into())
+    }
+
+    /// Returns the choice with the given name, or `None` if the game has no
+    /// such choice.
+    ///
+    /// `A` and `B`, `Atlantis` and `Olympus`, and the game's own names for
+    /// its choices are accepted in any letter case.
+    fn parse_choice(&self, name: &str) -> Option<PyChoice> {
+        self.0.parse_choice(name).map(PyChoice::from)
+    }
+
+    /// Returns the name of the family of games this game belongs to, such as
+    /// `prisoners_dilemma` or `chicken`.
+    fn classify(&self) -> &'static str {
+        self.0.classify().name()
+    }
+
+    /// Returns the payoffs as a `uint32` array of shape `(2, 2, 2)`.
+    ///
+    /// The array is indexed by Aleph's choice, then Beth's choice, then the
+    /// player, with `0` standing for Atlantis and Aleph and `1` for Olympus
+    /// and Beth.
+    fn payoff_matrix<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray3<u32>> {
+        Array3::from_shape_fn((2, 2, 2), |(aleph, beth, player)| {
+            let score = match (aleph, beth) {
+                (0, 0) => self.0.atlantis_atlantis(),
+                (0, _) => self.0.atlantis_olympus(),
+                (_, 0) => self.0.olympus_atlantis(),
+                _ => self.0.olympus_olympus(),
+            };
+
+            if player == 0 {
+                score.first()
+            } else {
+                score.second()
+            }
+        })
+        .into_pyarray(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GameOptions({})", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Builds `GameOptions` one setting at a time.
+///
+/// Every setter returns a new builder, so calls can be chained:
+///
+/// ```python
+/// options = (
+///     GameOptionsBuilder("customized")
+///     .choice_atlantis("share")
+///     .choice_olympus("steal")
+///     .atlantis_atlantis((3, 3))
+///     .build()
+/// )
+/// ```
+///
+/// Settings that do not apply to the kind of builder raise `ValueError`, as
+/// do out-of-range values.
+#[pyclass(name = "GameOptionsBuilder", module = "dilemma_tactix", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub struct PyGameOptionsBuilder(GameOptionsBuilder);
+
+#[pymethods]
+impl PyGameOptionsBuilder {
+    #[new]
+    #[pyo3(signature = (builder_type = "customized"))]
+    fn new(builder_type: &str) -> PyResult<Self> {
+        builder_type
+            .parse::<GameOptionsBuilderTypes>()
+            .map(|builder_type| Self(GameOptionsBuilder::new(builder_type)))
+            .map_err(value_error)
+    }
+
+    /// Returns a customized builder starting from the preset game with the
+    /// given name.
+    #[staticmethod]
+    fn from_preset(name: &str) -> PyResult<Self> {
+        GameOptionsBuilder::from_preset(name)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the smallest score a randomized game may use.
+    fn min_value(&self, min_value: u32) -> PyResult<Self> {
+        self.0.min_value(min_value).map(Self).map_err(value_error)
+    }
+
+    /// Sets the largest score a randomized game may use.
+    fn max_value(&self, max_value: u32) -> PyResult<Self> {
+        self.0.max_value(max_value).map(Self).map_err(value_error)
+    }
+
+    /// Sets the name of the Atlantis choice.
+    fn choice_atlantis(&self, choice_atlantis: &str) -> PyResult<Self> {
+        GameParams::intern_name("choice_atlantis", choice_atlantis)
+            .and_then(|name| self.0.choice_atlantis(name))
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the name of the Olympus choice.
+    fn choice_olympus(&self, choice_olympus: &str) -> PyResult<Self> {
+        GameParams::intern_name("choice_olympus", choice_olympus)
+            .and_then(|name| self.0.choice_olympus(name))
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the scores when both players choose Atlantis.
+    fn atlantis_atlantis(&self, atlantis_atlantis: PairArg) -> PyResult<Self> {
+        self.0
+            .atlantis_atlantis(atlantis_atlantis.into())
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the scores when Aleph chooses Atlantis and Beth chooses Olympus.
+    fn atlantis_olympus(&self, atlantis_olympus: PairArg) -> PyResult<Self> {
+        self.0
+            .atlantis_olympus(atlantis_olympus.into())
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the scores when Aleph chooses Olympus and Beth chooses Atlantis.
+    fn olympus_atlantis(&self, olympus_atlantis: PairArg) -> PyResult<Self> {
+        self.0
+            .olympus_atlantis(olympus_atlantis.into())
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the scores when both players choose Olympus.
+    fn olympus_olympus(&self, olympus_olympus: PairArg) -> PyResult<Self> {
+        self.0
+            .olympus_olympus(olympus_olympus.into())
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Sets the seed of a seeded game.
+    fn seed(&self, seed: u64) -> PyResult<Self> {
+        self.0.seed(seed).map(Self).map_err(value_error)
+    }
+
+    /// Builds the game.
+    fn build(&self) -> PyResult<PyGameOptions> {
+        self.0.build().map(PyGameOptions).map_err(value_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
@@ -0,0 +1,112 @@
+# SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+#
+# SPDX-License-Identifier: Apache-2.0
+# SPDX-License-Identifier: MIT
+
+import numpy as np
+import pytest
+
+import dilemma_tactix as dt
+
+
+@pytest.fixture
+def grid():
+    return dt.GameGrid(dt.GameOptions.preset("prisoners_dilemma"))
+
+
+def test_listings():
+    assert "tit-for-tat" in dt.strategies()
+    assert "prisoners_dilemma" in dt.presets()
+
+
+def test_preset():
+    options = dt.GameOptions.preset("prisoners_dilemma")
+
+    assert options.choice_atlantis == "cooperate"
+    assert options.atlantis_olympus == dt.NumberPair(0, 5)
+    assert options.classify() == "prisoners_dilemma"
+    assert options.parse_choice("defect") == dt.Choice.Olympus
+    assert options.choice_name(dt.Choice.Atlantis) == "cooperate"
+
+    with pytest.raises(ValueError):
+        dt.GameOptions.preset("no_such_game")
+
+
+def test_payoff_matrix():
+    matrix = dt.GameOptions.preset("prisoners_dilemma").payoff_matrix()
+
+    assert matrix.shape == (2, 2, 2)
+    assert matrix.dtype == np.uint32
+    assert matrix[0, 1].tolist() == [0, 5]
+    assert matrix[1, 0].tolist() == [5, 0]
+
+
+def test_builder():
+    options = (
+        dt.GameOptionsBuilder("customized")
+        .choice_atlantis("share")
+        .choice_olympus("steal")
+        .atlantis_atlantis((3, 3))
+        .olympus_olympus(dt.NumberPair(1, 1))
+        .build()
+    )
+
+    assert options.choice_olympus == "steal"
+    assert options.atlantis_atlantis.to_tuple() == (3, 3)
+    assert options.olympus_olympus == dt.NumberPair(1, 1)
+
+    with pytest.raises(ValueError):
+        dt.GameOptionsBuilder("customized").seed(7)
+
+    with pytest.raises(ValueError):
+        dt.GameOptionsBuilder("no_such_builder")
+
+    with pytest.raises(ValueError):
+        dt.GameOptionsBuilder("customized").choice_atlantis("x" * 100)
+
+
+def test_seeded_builder_is_reproducible():
+    first = dt.GameOptionsBuilder("seeded").seed(7).build()
+    second = dt.GameOptionsBuilder("seeded").seed(7).build()
+
+    assert first == second
+
+
+def test_grid(grid):
+    assert grid.return_score(dt.Choice.Atlantis, dt.Choice.Olympus) == dt.NumberPair(0, 5)
+    assert "cooperate" in str(grid)
+
+
+def test_play_match(grid):
+    result = grid.play_match("tit-for-tat", "always-defect", 3)
+
+    assert len(result) == 3
+    assert result.winner == "Beth"
+    assert result.forfeit is None
+    assert result.choices.tolist() == [[0, 1], [1, 1], [1, 1]]
+    assert result.scores.tolist() == [[0, 5], [1, 1], [1, 1]]
+    assert result.cumulative.tolist() == [[0, 5], [1, 6], [2, 7]]
+    assert (result.aleph_total, result.beth_total) == (2, 7)
+
+
+def test_play_match_seeded(grid):
+    first = grid.play_match("random", "random", 50, seed=1)
+    second = grid.play_match("random", "random", 50, seed=1)
+
+    assert np.array_equal(first.choices, second.choices)
+
+
+def test_play_match_unknown_strategy(grid):
+    with pytest.raises(ValueError):
+        grid.play_match("no-such-strategy", "grudger", 3)
+
+
+def test_play_tournament(grid):
+    result = grid.play_tournament(["tit-for-tat", "always-defect", "always-cooperate"], 3)
+
+    assert result.names == ["tit-for-tat", "always-defect", "always-cooperate"]
+    assert len(result.matches) == 3
+    assert result.scores.shape == (3, 3)
+    assert result.scores[0, 1] == 2
+    assert result.scores[1, 0] == 7
+    assert result.standings()[0]["name"] == "always-defect"
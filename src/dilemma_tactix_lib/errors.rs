@@ -5,23 +5,216 @@
 
 use std::fmt;
 
-#[derive(Debug)]
+use crate::GameOptionsBuilderTypes;
+
+/// The error type for building games and their components.
+///
+/// Every fallible constructor in this crate reports its failure using one of
+/// these variants, so library users can match on the cause instead of parsing
+/// a message.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     BuilderError,
+///     GameOptionsBuilder,
+///     GameOptionsBuilderTypes,
+/// };
+///
+/// let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized)
+///     .min_value(1);
+///
+/// assert_eq!(
+///     builder.unwrap_err(),
+///     BuilderError::InvalidBuilderType {
+///         field:        "min_value",
+///         builder_type: GameOptionsBuilderTypes::Customized,
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BuilderError {
-    InvalidOptionSpecified(String),
-    InvalidOptionValueSpecified(String),
+    /// The field can not be set on this type of builder.
+    InvalidBuilderType {
+        /// The name of the field that was set.
+        field:        &'static str,
+        /// The type of builder the field was set on.
+        builder_type: GameOptionsBuilderTypes,
+    },
+    /// The lower bound for random scores is greater than the upper bound.
+    InvalidBounds {
+        /// The requested lower bound.
+        min_value: u32,
+        /// The requested upper bound.
+        max_value: u32,
+    },
+    /// A choice name was set to an empty string.
+    EmptyChoiceName(&'static str),
+    /// Both choices were given the same name.
+    DuplicateChoiceNames(&'static str),
+    /// A choice name is longer than names are allowed to be.
+    ChoiceNameTooLong {
+        /// The name of the field that was set.
+        field:      &'static str,
+        /// The most characters a name may have.
+        max_length: usize,
+    },
+    /// So many distinct choice names have been used that no more can be
+    /// kept.
+    TooManyChoiceNames(usize),
+    /// A field that this type of builder requires was never set.
+    MissingOption(&'static str),
+    /// There is no preset with the given name.
+    UnknownPreset(String),
+    /// There is no builder type with the given name.
+    UnknownBuilderType(String),
+    /// There is no strategy with the given name.
+    UnknownStrategy(String),
+    /// An index was outside of the list it was used to look up.
+    IndexOutOfBounds {
+        /// The requested index.
+        index:  usize,
+        /// The length of the list.
+        length: usize,
+    },
 }
 
 impl fmt::Display for BuilderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Self::InvalidOptionSpecified(ref s) => {
-                write!(f, "Invalid option specified: {s}")
+            Self::InvalidBuilderType {
+                field,
+                builder_type,
+            } => {
+                write!(f, "Field {field} can not be set when using {builder_type}")
+            }
+            Self::InvalidBounds {
+                min_value,
+                max_value,
+            } => {
+                write!(
+                    f,
+                    "min_value ({min_value}) must not be greater than max_value ({max_value})"
+                )
+            }
+            Self::EmptyChoiceName(field) => {
+                write!(f, "{field} must not be empty")
+            }
+            Self::DuplicateChoiceNames(name) => {
+                write!(f, "Both choices are named {name}; choice names must differ")
+            }
+            Self::ChoiceNameTooLong { field, max_length } => {
+                write!(f, "{field} must be at most {max_length} characters long")
+            }
+            Self::TooManyChoiceNames(limit) => {
+                write!(
+                    f,
+                    "No more than {limit} distinct choice names can be used; reuse an earlier name"
+                )
             }
-            Self::InvalidOptionValueSpecified(ref s) => {
-                write!(f, "Invalid option value specified: {s}")
+            Self::MissingOption(field) => {
+                write!(f, "Field {field} must be set")
+            }
+            Self::UnknownPreset(ref name) => {
+                write!(f, "No preset named {name} exists")
+            }
+            Self::UnknownBuilderType(ref name) => {
+                write!(
+                    f,
+                    "Unknown builder type {name}; expected randomized, seeded or customized"
+                )
+            }
+            Self::UnknownStrategy(ref name) => {
+                write!(f, "Unknown strategy {name}")
+            }
+            Self::IndexOutOfBounds { index, length } => {
+                write!(f, "Index {index} is out of bounds for length {length}")
             }
         }
     }
 }
 
 impl std::error::Error for BuilderError {}
+
+/// The error type for strategies that can fail while playing.
+///
+/// Built-in strategies never fail. Strategies backed by something outside the
+/// engine, such as an [`ExternalStrategy`](crate::ExternalStrategy), a
+/// `ScriptedStrategy` or a `PluginStrategy`, report why they stopped playing
+/// with one of these variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrategyError {
+    /// The strategy could not be started.
+    Spawn(String),
+    /// Communicating with the strategy failed.
+    Io(String),
+    /// The strategy did not answer in time.
+    Timeout {
+        /// The round the strategy was asked to play.
+        round: usize,
+    },
+    /// The strategy exited or closed its output before answering.
+    Crashed {
+        /// The round the strategy was asked to play.
+        round:  usize,
+        /// A description of how the strategy exited, if known.
+        status: String,
+    },
+    /// A scripted strategy could not be loaded.
+    InvalidScript(String),
+    /// A scripted strategy raised an error or ran out of operations.
+    ScriptFailed {
+        /// The round the strategy was asked to play.
+        round:   usize,
+        /// The error raised by the script.
+        message: String,
+    },
+    /// A WebAssembly plugin could not be loaded.
+    InvalidPlugin(String),
+    /// A WebAssembly plugin trapped or ran out of fuel.
+    PluginFailed {
+        /// The round the strategy was asked to play.
+        round:   usize,
+        /// The error raised by the plugin.
+        message: String,
+    },
+    /// The strategy answered with something that is not a valid move.
+    InvalidResponse {
+        /// The round the strategy was asked to play.
+        round:    usize,
+        /// The response that could not be understood.
+        response: String,
+    },
+}
+
+impl fmt::Display for StrategyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Spawn(e) => write!(f, "Failed to start strategy: {e}"),
+            Self::Io(e) => write!(f, "Failed to communicate with strategy: {e}"),
+            Self::Timeout { round } => {
+                write!(f, "Strategy did not answer in round {round} in time")
+            }
+            Self::Crashed { round, status } => {
+                write!(f, "Strategy stopped in round {round} ({status})")
+            }
+            Self::InvalidScript(e) => write!(f, "Failed to load script: {e}"),
+            Self::ScriptFailed { round, message } => {
+                write!(f, "Script failed in round {round}: {message}")
+            }
+            Self::InvalidPlugin(e) => write!(f, "Failed to load plugin: {e}"),
+            Self::PluginFailed { round, message } => {
+                write!(f, "Plugin failed in round {round}: {message}")
+            }
+            Self::InvalidResponse { round, response } => {
+                write!(
+                    f,
+                    "Strategy sent an invalid move in round {round}: {response}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for StrategyError {}
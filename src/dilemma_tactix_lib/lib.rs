@@ -11,14 +11,50 @@ pub(crate) use constants::RANDOM_SEED;
 
 mod errors;
 mod models;
+mod strategies;
 
-pub use errors::BuilderError;
+pub use errors::{
+    BuilderError,
+    StrategyError,
+};
 pub use models::{
     Choice,
     ChoiceNameOptions,
+    CustomizedBuilder,
+    Forfeit,
+    GameClass,
     GameGrid,
     GameOptions,
     GameOptionsBuilder,
     GameOptionsBuilderTypes,
+    GameParams,
+    GamePresets,
+    Lattice,
+    MatchHistory,
     NumberPair,
+    Pairing,
+    Player,
+    Population,
+    RandomizedBuilder,
+    Round,
+    SeededBuilder,
+    Standing,
+    Tournament,
+};
+#[cfg(feature = "plugins")]
+pub use strategies::PluginStrategy;
+#[cfg(feature = "scripting")]
+pub use strategies::ScriptedStrategy;
+pub use strategies::{
+    AlwaysCooperate,
+    AlwaysDefect,
+    ExternalStrategy,
+    Grudger,
+    Pavlov,
+    RandomStrategy,
+    Strategy,
+    StrategyKind,
+    StrategySource,
+    TitForTat,
+    TitForTwoTats,
 };
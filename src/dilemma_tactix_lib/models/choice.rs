@@ -6,8 +6,13 @@
 // Import the fmt module for formatting
 use std::fmt;
 
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
 // Define an enum called Choice with two variants: Atlantis and Olympus
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Choice {
     Atlantis,
     Olympus,
@@ -9,6 +9,8 @@ use rand::{
 };
 use rand_chacha::ChaCha12Rng;
 
+use crate::BuilderError;
+
 /// A data struct that holds the names of the choices.
 ///
 /// This is a simple data struct that is used to internally store
@@ -88,6 +90,7 @@ use rand_chacha::ChaCha12Rng;
 /// * [Universal Paperclips](https://www.decisionproblem.com/paperclips/index2.html)
 /// * [get_random_pair](ChoiceNameOptions::get_random_pair)
 /// * [get_choice_pair](ChoiceNameOptions::get_choice_pair)
+/// * [GamePresets](crate::GamePresets)
 #[derive(Debug, Clone, Copy)]
 #[allow(clippy::doc_markdown)]
 pub struct ChoiceNameOptions {}
@@ -151,12 +154,15 @@ impl ChoiceNameOptions {
     ///
     /// # Panics
     ///
-    /// This function will panic if the index is greater than the length of the
-    /// array.
+    /// This function will panic if the index is not less than the length of
+    /// the array. Use
+    /// [`try_get_choice_pair`](ChoiceNameOptions::try_get_choice_pair) if the
+    /// index comes from user input.
     ///
     /// # See Also
     ///
     /// * [`get_random_pair`](ChoiceNameOptions::get_random_pair)
+    /// * [`try_get_choice_pair`](ChoiceNameOptions::try_get_choice_pair)
     #[must_use]
     pub const fn get_choice_pair(n: usize) -> (&'static str, &'static str) {
         if n < Self::CHOICE_PAIRS_LENGTH {
@@ -168,6 +174,60 @@ impl ChoiceNameOptions {
         panic!("Index out of bounds.");
     }
 
+    /// Get a specific choice pair, checking the index first.
+    ///
+    /// This is the non-panicking version of
+    /// [`get_choice_pair`](ChoiceNameOptions::get_choice_pair).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The index of the choice pair to return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     ChoiceNameOptions as CNO,
+    /// };
+    ///
+    /// let (choice_atlantis, choice_olympus) = CNO::try_get_choice_pair(1)?;
+    ///
+    /// assert_eq!(choice_atlantis, "swerve");
+    ///
+    /// assert_eq!(choice_olympus, "straight");
+    ///
+    /// assert!(CNO::try_get_choice_pair(100).is_err());
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError::IndexOutOfBounds`] if the index is not less
+    /// than the length of the array.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the two choices.
+    ///
+    /// # See Also
+    ///
+    /// * [`get_choice_pair`](ChoiceNameOptions::get_choice_pair)
+    pub const fn try_get_choice_pair(
+        n: usize,
+    ) -> Result<(&'static str, &'static str), BuilderError> {
+        if n < Self::CHOICE_PAIRS_LENGTH {
+            let pair = Self::CHOICE_PAIRS[n];
+
+            return Ok((pair.0, pair.1));
+        }
+
+        Err(BuilderError::IndexOutOfBounds {
+            index:  n,
+            length: Self::CHOICE_PAIRS_LENGTH,
+        })
+    }
+
     /// Get a random choice pair.
     ///
     /// This function returns a random choice pair, based on the length
@@ -227,9 +287,9 @@ impl ChoiceNameOptions {
     ///
     /// let (choice_atlantis, choice_olympus) = CNO::get_random_pair_seeded(2024);
     ///
-    /// assert_eq!(choice_atlantis, "discrete");
+    /// assert_eq!(choice_atlantis, "particle");
     /// assert!(CNO::choice_atlantis_options().contains(&choice_atlantis));
-    /// assert_eq!(choice_olympus, "continuous");
+    /// assert_eq!(choice_olympus, "wave");
     /// assert!(CNO::choice_olympus_options().contains(&choice_olympus));
     /// ```
     ///
@@ -416,9 +476,9 @@ mod tests {
         // These assertions check that the choices are as expected for the given seed,
         // ensuring that get_random_pair_seeded is correctly using the seed to generate
         // choices
-        assert_eq!(choice_atlantis, "discrete");
+        assert_eq!(choice_atlantis, "particle");
 
-        assert_eq!(choice_olympus, "continuous");
+        assert_eq!(choice_olympus, "wave");
     }
 
     // This test checks that the get_random_pair_seeded method is repeatable for a
@@ -430,17 +490,17 @@ mod tests {
         // These assertions check that the choices are as expected for the given seed,
         // ensuring that get_random_pair_seeded is correctly using the seed to generate
         // choices
-        assert_eq!(choice_atlantis_a, "discrete");
+        assert_eq!(choice_atlantis_a, "particle");
 
-        assert_eq!(choice_olympus_a, "continuous");
+        assert_eq!(choice_olympus_a, "wave");
 
         // This part repeats the same checks, to ensure that the same seed will always
         // generate the same pair of choices
         let (choice_atlantis_b, choice_olympus_b) = ChoiceNameOptions::get_random_pair_seeded(seed);
 
-        assert_eq!(choice_atlantis_b, "discrete");
+        assert_eq!(choice_atlantis_b, "particle");
 
-        assert_eq!(choice_olympus_b, "continuous");
+        assert_eq!(choice_olympus_b, "wave");
 
         // This assertion checks that the choices are the same for both pairs, ensuring
         // that get_random_pair_seeded is correctly using the seed to generate choices
@@ -462,4 +522,38 @@ mod tests {
 
         assert_eq!(choice_olympus, "defect");
     }
+
+    // This test checks that no choice pair is listed twice, so that every index
+    // names a different game
+    #[rstest]
+    fn test_choice_pairs_are_distinct() {
+        let pairs = ChoiceNameOptions::choice_pairs();
+
+        for (index, pair) in pairs.iter().enumerate() {
+            assert!(!pairs[..index].contains(pair), "{pair:?} is listed twice");
+        }
+
+        assert_eq!(
+            ChoiceNameOptions::get_choice_pair(10),
+            ("discrete", "continuous")
+        );
+    }
+
+    // This test checks that try_get_choice_pair reports an out of bounds index
+    // instead of panicking
+    #[rstest]
+    fn test_try_get_choice_pair() {
+        assert_eq!(
+            ChoiceNameOptions::try_get_choice_pair(0),
+            Ok(("cooperate", "defect"))
+        );
+
+        assert_eq!(
+            ChoiceNameOptions::try_get_choice_pair(17),
+            Err(BuilderError::IndexOutOfBounds {
+                index:  17,
+                length: 17,
+            })
+        );
+    }
 }
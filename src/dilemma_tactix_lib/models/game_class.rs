@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::GameOptions;
+
+/// The family a two-player game belongs to, judged by how its payoffs are
+/// ordered.
+///
+/// Symmetric games are classified using Aleph's payoffs, named after the
+/// usual roles when Atlantis is the cooperative move:
+///
+/// * `R`, the reward, when both players choose Atlantis.
+/// * `S`, the sucker's payoff, for choosing Atlantis against Olympus.
+/// * `T`, the temptation, for choosing Olympus against Atlantis.
+/// * `P`, the punishment, when both players choose Olympus.
+///
+/// Which choice is the cooperative one is a matter of labels, so a game that
+/// only fits a class with the two choices swapped still belongs to it. For
+/// example, the `hawk_dove` preset lists the aggressive move first and is
+/// still classified as [`GameClass::Chicken`].
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     GameClass,
+///     GamePresets,
+/// };
+///
+/// let game_options = GamePresets::get_preset("hawk_dove").unwrap();
+///
+/// assert_eq!(GameClass::from(&game_options), GameClass::Chicken);
+/// assert_eq!(GameClass::Chicken.to_string(), "chicken");
+/// ```
+///
+/// # Classes
+///
+/// | Name | Payoff Ordering |
+/// | ---- | --------------- |
+/// | `prisoners_dilemma` | T > R > P > S |
+/// | `chicken` | T > R > S > P |
+/// | `stag_hunt` | R > T > P > S |
+/// | `deadlock` | T > P > R > S |
+/// | `harmony` | R > T and S > P |
+/// | `leader` | T > S > R > P |
+/// | `hero` | S > T > R > P |
+/// | `coordination` | R > T, R > P and P > S |
+/// | `other` | Any other symmetric game |
+/// | `asymmetric` | Beth's payoffs are not the mirror of Aleph's |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameClass {
+    /// Defecting always pays, but mutual cooperation beats mutual defection.
+    PrisonersDilemma,
+    /// Each player wants to defect only if the other does not.
+    Chicken,
+    /// Cooperation pays only if the other player cooperates too.
+    StagHunt,
+    /// Mutual defection is best for both players.
+    Deadlock,
+    /// Cooperation is best for both players, whatever the other does.
+    Harmony,
+    /// Players want to choose differently, and the one defecting gains most.
+    Leader,
+    /// Players want to choose differently, and the one cooperating gains most.
+    Hero,
+    /// Players want to choose the same, and prefer mutual cooperation.
+    Coordination,
+    /// A symmetric game that fits none of the other classes.
+    Other,
+    /// A game whose payoffs differ between Aleph and Beth.
+    Asymmetric,
+}
+
+impl GameClass {
+    /// Every class, in the order they are tried.
+    pub const ALL: [Self; 10] = [
+        Self::PrisonersDilemma,
+        Self::Chicken,
+        Self::StagHunt,
+        Self::Deadlock,
+        Self::Harmony,
+        Self::Leader,
+        Self::Hero,
+        Self::Coordination,
+        Self::Other,
+        Self::Asymmetric,
+    ];
+
+    /// Returns the name of the class.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::PrisonersDilemma => "prisoners_dilemma",
+            Self::Chicken => "chicken",
+            Self::StagHunt => "stag_hunt",
+            Self::Deadlock => "deadlock",
+            Self::Harmony => "harmony",
+            Self::Leader => "leader",
+            Self::Hero => "hero",
+            Self::Coordination => "coordination",
+            Self::Other => "other",
+            Self::Asymmetric => "asymmetric",
+        }
+    }
+
+    /// Classifies a symmetric game from Aleph's payoffs, with Atlantis taken
+    /// as the cooperative move.
+    const fn from_payoffs(reward: u32, sucker: u32, temptation: u32, punishment: u32) -> Self {
+        let (r, s, t, p) = (reward, sucker, temptation, punishment);
+
+        if t > r && r > p && p > s {
+            Self::PrisonersDilemma
+        } else if t > r && r > s && s > p {
+            Self::Chicken
+        } else if r > t && t > p && p > s {
+            Self::StagHunt
+        } else if t > p && p > r && r > s {
+            Self::Deadlock
+        } else if r > t && s > p {
+            Self::Harmony
+        } else if t > s && s > r && r > p {
+            Self::Leader
+        } else if s > t && t > r && r > p {
+            Self::Hero
+        } else if r > t && p > s && r > p {
+            Self::Coordination
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl From<&GameOptions> for GameClass {
+    fn from(game_options: &GameOptions) -> Self {
+        let aa = game_options.atlantis_atlantis();
+        let ao = game_options.atlantis_olympus();
+        let oa = game_options.olympus_atlantis();
+        let oo = game_options.olympus_olympus();
+
+        let symmetric = aa.first() == aa.second()
+            && oo.first() == oo.second()
+            && ao.first() == oa.second()
+            && oa.first() == ao.second();
+
+        if !symmetric {
+            return Self::Asymmetric;
+        }
+
+        match Self::from_payoffs(aa.first(), ao.first(), oa.first(), oo.first()) {
+            // Try again with Olympus as the cooperative move
+            Self::Other => Self::from_payoffs(oo.first(), oa.first(), ao.first(), aa.first()),
+            class => class,
+        }
+    }
+}
+
+impl fmt::Display for GameClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        CustomizedBuilder,
+        GamePresets,
+        NumberPair,
+    };
+
+    #[rstest]
+    #[case("prisoners_dilemma", GameClass::PrisonersDilemma)]
+    #[case("price_war", GameClass::PrisonersDilemma)]
+    #[case("chicken", GameClass::Chicken)]
+    #[case("hawk_dove", GameClass::Chicken)]
+    #[case("stag_hunt", GameClass::StagHunt)]
+    #[case("leader", GameClass::Leader)]
+    #[case("coordination", GameClass::Coordination)]
+    #[case("battle_of_the_sexes", GameClass::Asymmetric)]
+    #[case("matching_pennies", GameClass::Asymmetric)]
+    fn test_presets(#[case] preset: &str, #[case] expected: GameClass) {
+        let game_options = GamePresets::get_preset(preset).unwrap();
+
+        assert_eq!(game_options.classify(), expected);
+    }
+
+    #[rstest]
+    #[case((1, 0, 3, 2), GameClass::Deadlock)]
+    #[case((3, 2, 1, 0), GameClass::Harmony)]
+    #[case((1, 4, 3, 0), GameClass::Hero)]
+    #[case((1, 1, 1, 1), GameClass::Other)]
+    fn test_symmetric_games(#[case] payoffs: (u32, u32, u32, u32), #[case] expected: GameClass) {
+        let (r, s, t, p) = payoffs;
+
+        let game_options = CustomizedBuilder::new()
+            .atlantis_atlantis(NumberPair::new(r, r))
+            .atlantis_olympus(NumberPair::new(s, t))
+            .olympus_atlantis(NumberPair::new(t, s))
+            .olympus_olympus(NumberPair::new(p, p))
+            .build()
+            .unwrap();
+
+        assert_eq!(GameClass::from(&game_options), expected);
+    }
+
+    #[test]
+    fn test_names_are_unique() {
+        for class in GameClass::ALL {
+            assert_eq!(
+                GameClass::ALL
+                    .iter()
+                    .filter(|other| other.name() == class.name())
+                    .count(),
+                1
+            );
+            assert_eq!(
+                serde_json::to_value(class).unwrap(),
+                serde_json::Value::from(class.name())
+            );
+        }
+    }
+}
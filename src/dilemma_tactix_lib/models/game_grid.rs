@@ -13,7 +13,10 @@ use prettytable::{
 use crate::{
     Choice,
     GameOptions,
+    MatchHistory,
     NumberPair,
+    Player,
+    Strategy,
 };
 
 /// A representation of the game board.
@@ -33,13 +36,15 @@ use crate::{
 ///     Choice,
 ///     GameGrid,
 ///     GameOptions,
+/// # BuilderError,
 /// };
 ///
-/// let game_options = GameOptions::builder("customized").build();
+/// let game_options = GameOptions::builder("customized").build()?;
 ///
 /// let game_grid = GameGrid::new(game_options);
 ///
 /// game_grid.show_grid();
+/// # Ok::<(), BuilderError>(())
 /// ```
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
 pub struct GameGrid {
@@ -56,11 +61,13 @@ impl GameGrid {
     ///     Choice,
     ///     GameGrid,
     ///     GameOptions,
+    /// # BuilderError,
     /// };
     ///
-    /// let game_options = GameOptions::builder("customized").build();
+    /// let game_options = GameOptions::builder("customized").build()?;
     ///
     /// let game_grid = GameGrid::new(game_options);
+    /// # Ok::<(), BuilderError>(())
     /// ```
     ///
     /// # Returns
@@ -129,13 +136,15 @@ impl GameGrid {
     ///     Choice,
     ///     GameGrid,
     ///     GameOptions,
+    /// # BuilderError,
     /// };
     ///
-    /// let game_options = GameOptions::builder("customized").build();
+    /// let game_options = GameOptions::builder("customized").build()?;
     ///
     /// let game_grid = GameGrid::new(game_options);
     ///
     /// game_grid.show_grid();
+    /// # Ok::<(), BuilderError>(())
     /// ```
     ///
     /// # See Also
@@ -161,13 +170,15 @@ impl GameGrid {
     ///     Choice,
     ///     GameGrid,
     ///     GameOptions,
+    /// # BuilderError,
     /// };
     ///
-    /// let game_options = GameOptions::builder("customized").build();
+    /// let game_options = GameOptions::builder("customized").build()?;
     ///
     /// let game_grid = GameGrid::new(game_options);
     ///
     /// let result = game_grid.return_score(Choice::Atlantis, Choice::Atlantis);
+    /// # Ok::<(), BuilderError>(())
     /// ```
     ///
     /// # Returns
@@ -187,4 +198,162 @@ impl GameGrid {
             (Choice::Olympus, Choice::Olympus) => self.game_options.olympus_olympus(),
         }
     }
+
+    /// Plays a single round and records it in `history`.
+    ///
+    /// # Arguments
+    ///
+    /// * `history` - The history of the match being played.
+    /// * `aleph_choice` - The choice made by Player Aleph.
+    /// * `beth_choice` - The choice made by Player Beth.
+    ///
+    /// # Returns
+    ///
+    /// A `NumberPair` containing the scores for the round.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::return_score()`](#method.return_score)
+    /// * [`GameGrid::play_match()`](#method.play_match)
+    pub fn play_round(
+        &self,
+        history: &mut MatchHistory,
+        aleph_choice: Choice,
+        beth_choice: Choice,
+    ) -> NumberPair {
+        let score = self.return_score(aleph_choice, beth_choice);
+
+        history.record(aleph_choice, beth_choice, score);
+
+        score
+    }
+
+    /// Plays an iterated match between two strategies.
+    ///
+    /// Both strategies are reset before the first round and then asked for
+    /// their choice once per round, each being handed its own history first.
+    /// If a strategy fails, the match stops and the failure is recorded as a
+    /// [`Forfeit`](crate::Forfeit) in the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph` - The strategy playing as Player Aleph.
+    /// * `beth` - The strategy playing as Player Beth.
+    /// * `rounds` - The number of rounds to play.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysDefect,
+    ///     CustomizedBuilder,
+    ///     GameGrid,
+    ///     TitForTat,
+    /// # BuilderError,
+    /// };
+    ///
+    /// let game_options =
+    ///     CustomizedBuilder::from_preset("prisoners_dilemma")?.build()?;
+    ///
+    /// let game_grid = GameGrid::new(game_options);
+    ///
+    /// let history = game_grid.play_match(&mut TitForTat, &mut AlwaysDefect, 3);
+    ///
+    /// assert_eq!(history.len(), 3);
+    /// assert_eq!(history.aleph_total(), 2);
+    /// assert_eq!(history.beth_total(), 7);
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `MatchHistory` of every round played.
+    pub fn play_match(
+        &self,
+        aleph: &mut dyn Strategy,
+        beth: &mut dyn Strategy,
+        rounds: usize,
+    ) -> MatchHistory {
+        self.play_match_observed(aleph, beth, rounds, &mut |_| {})
+    }
+
+    /// Plays an iterated match between two strategies, reporting each round
+    /// as it is played.
+    ///
+    /// This is [`play_match()`](#method.play_match) for callers that want to
+    /// show a match as it unfolds, such as a live scoreboard.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph` - The strategy playing as Player Aleph.
+    /// * `beth` - The strategy playing as Player Beth.
+    /// * `rounds` - The number of rounds to play.
+    /// * `observer` - Called with the history so far after every round, and once more if the match
+    ///   is forfeited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysDefect,
+    ///     CustomizedBuilder,
+    ///     GameGrid,
+    ///     TitForTat,
+    /// # BuilderError,
+    /// };
+    ///
+    /// let game_options =
+    ///     CustomizedBuilder::from_preset("prisoners_dilemma")?.build()?;
+    ///
+    /// let mut totals = Vec::new();
+    ///
+    /// GameGrid::new(game_options).play_match_observed(
+    ///     &mut TitForTat,
+    ///     &mut AlwaysDefect,
+    ///     3,
+    ///     &mut |history| totals.push(history.beth_total()),
+    /// );
+    ///
+    /// assert_eq!(totals, vec![5, 6, 7]);
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `MatchHistory` of every round played.
+    pub fn play_match_observed(
+        &self,
+        aleph: &mut dyn Strategy,
+        beth: &mut dyn Strategy,
+        rounds: usize,
+        observer: &mut dyn FnMut(&MatchHistory),
+    ) -> MatchHistory {
+        let mut history = MatchHistory::new();
+
+        aleph.reset();
+        beth.reset();
+
+        for _ in 0..rounds {
+            let aleph_choice = aleph.next_choice(history.aleph_choices(), history.beth_choices());
+            let beth_choice = beth.next_choice(history.beth_choices(), history.aleph_choices());
+
+            if let Some(error) = aleph.error() {
+                history.record_forfeit(Player::Aleph, error.clone());
+                observer(&history);
+                break;
+            }
+
+            if let Some(error) = beth.error() {
+                history.record_forfeit(Player::Beth, error.clone());
+                observer(&history);
+                break;
+            }
+
+            self.play_round(&mut history, aleph_choice, beth_choice);
+
+            observer(&history);
+        }
+
+        history
+    }
 }
@@ -3,9 +3,21 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
 use crate::{
     BuilderError,
     GameOptions,
+    GamePresets,
     NumberPair,
 };
 
@@ -22,6 +34,54 @@ pub enum GameOptionsBuilderTypes {
     Customized,
 }
 
+impl fmt::Display for GameOptionsBuilderTypes {
+    /// Formats the builder type using the name of its builder path, e.g.
+    /// `RandomizedBuilder`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}Builder")
+    }
+}
+
+impl FromStr for GameOptionsBuilderTypes {
+    type Err = BuilderError;
+
+    /// Parses a builder type from its name.
+    ///
+    /// The names `randomized`, `seeded` and `customized` are accepted in any
+    /// letter case. Unlike [`GameOptions::builder()`](crate::GameOptions::builder),
+    /// any other name is an error rather than a silent fallback.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptionsBuilderTypes,
+    /// };
+    ///
+    /// let builder_type: GameOptionsBuilderTypes = "Seeded".parse()?;
+    ///
+    /// assert_eq!(builder_type, GameOptionsBuilderTypes::Seeded);
+    ///
+    /// assert_eq!(
+    ///     "random".parse::<GameOptionsBuilderTypes>(),
+    ///     Err(BuilderError::UnknownBuilderType("random".to_string()))
+    /// );
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("randomized") {
+            Ok(Self::Randomized)
+        } else if s.eq_ignore_ascii_case("seeded") {
+            Ok(Self::Seeded)
+        } else if s.eq_ignore_ascii_case("customized") {
+            Ok(Self::Customized)
+        } else {
+            Err(BuilderError::UnknownBuilderType(s.to_string()))
+        }
+    }
+}
+
 /// A builder struct to create a [`GameOptions`](crate::GameOptions).
 ///
 /// This struct is designed to encapsulate different ways of generating a new
@@ -46,8 +106,8 @@ pub enum GameOptionsBuilderTypes {
 ///
 /// | Field | Randomized | Seeded | Customized | Type | Default |
 /// | ----- | ---------- | ------ | ---------- | ---- | ------- |
-/// | `min_value` | Yes | No | Yes | u32 | 1 |
-/// | `max_value` | Yes | No | Yes | u32 | 10 |
+/// | `min_value` | Yes | Yes | No | u32 | 1 |
+/// | `max_value` | Yes | Yes | No | u32 | 10 |
 /// | `choice_atlantis` | Yes | Yes | Yes | &'static str | "cooperate" |
 /// | `choice_olympus` | Yes | Yes | Yes | &'static str | "defect" |
 /// | `atlantis_atlantis` | No | No | Yes | `NumberPair` | `NumberPair::new(4, 4)` |
@@ -55,6 +115,11 @@ pub enum GameOptionsBuilderTypes {
 /// | `olympus_atlantis` | No | No | Yes | `NumberPair` | `NumberPair::new(5, 0)` |
 /// | `olympus_olympus` | No | No | Yes | `NumberPair` | `NumberPair::new(3, 3)` |
 /// | `seed` | No | Yes | No | `u64` | `None` |
+///
+/// The `seed` field is required for the Seeded variant. Building with a
+/// `min_value` greater than the `max_value`, or with two identical choice names,
+/// returns a [`BuilderError`].
+///
 /// # Example
 ///
 /// ## `RandomizedBuilder`
@@ -81,7 +146,7 @@ pub enum GameOptionsBuilderTypes {
 /// let builder = builder?.choice_olympus("defect");
 /// # assert!(builder.is_ok());
 ///
-/// let game_options = builder?.build();
+/// let game_options = builder?.build()?;
 ///
 /// # assert_eq!(game_options.choice_atlantis(), "cooperate");
 /// # assert_eq!(game_options.choice_olympus(), "defect");
@@ -111,7 +176,7 @@ pub enum GameOptionsBuilderTypes {
 /// It then generates a random score for each option within the given
 /// bounds and the given seed.
 ///
-/// ```no_run
+/// ```
 /// use dilemma_tactix_lib::{
 ///     GameOptionsBuilder as Builder,
 ///     GameOptionsBuilderTypes as BuilderTypes,
@@ -154,7 +219,7 @@ pub enum GameOptionsBuilderTypes {
 /// let builder = builder?.olympus_olympus(NumberPair::new(3, 3));
 /// # assert!(builder.is_ok());
 ///
-/// let game_options = builder?.build();
+/// let game_options = builder?.build()?;
 ///
 /// # assert_eq!(game_options.choice_atlantis(), "cooperate");
 /// # assert_eq!(game_options.choice_olympus(), "defect");
@@ -177,16 +242,16 @@ pub enum GameOptionsBuilderTypes {
 /// * [`GameOptions`](crate::GameOptions)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GameOptionsBuilder {
-    builder_type:          GameOptionsBuilderTypes,
-    min_value:             Option<u32>,
-    max_value:             Option<u32>,
-    pub choice_atlantis:   Option<&'static str>,
-    pub choice_olympus:    Option<&'static str>,
-    pub atlantis_atlantis: Option<NumberPair>,
-    pub atlantis_olympus:  Option<NumberPair>,
-    pub olympus_atlantis:  Option<NumberPair>,
-    pub olympus_olympus:   Option<NumberPair>,
-    seed:                  Option<u64>,
+    pub(crate) builder_type: GameOptionsBuilderTypes,
+    pub(crate) min_value:    Option<u32>,
+    pub(crate) max_value:    Option<u32>,
+    pub choice_atlantis:     Option<&'static str>,
+    pub choice_olympus:      Option<&'static str>,
+    pub atlantis_atlantis:   Option<NumberPair>,
+    pub atlantis_olympus:    Option<NumberPair>,
+    pub olympus_atlantis:    Option<NumberPair>,
+    pub olympus_olympus:     Option<NumberPair>,
+    pub(crate) seed:         Option<u64>,
 }
 
 impl GameOptionsBuilder {
@@ -237,7 +302,7 @@ impl GameOptionsBuilder {
     /// It then generates a random score for each option within the given
     /// bounds and the given seed.
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
@@ -307,6 +372,71 @@ impl GameOptionsBuilder {
         }
     }
 
+    /// Creates a new `GameOptionsBuilder` struct from a named preset.
+    ///
+    /// This associated function looks up the preset with the given name in
+    /// [`GamePresets`](crate::GamePresets) and returns a builder of type
+    /// [`GameOptionsBuilderTypes::Customized`](GameOptionsBuilderTypes::Customized)
+    /// with the choice names and all four scores already set. Any of these can
+    /// be overridden before the builder is built.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the preset, e.g. `"chicken"` or `"stag_hunt"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptionsBuilder,
+    ///     NumberPair,
+    /// # BuilderError,
+    /// };
+    ///
+    /// let game_options = GameOptionsBuilder::from_preset("chicken")?.build()?;
+    ///
+    /// assert_eq!(game_options.choice_atlantis(), "swerve");
+    /// assert_eq!(game_options.choice_olympus(), "straight");
+    /// assert_eq!(game_options.atlantis_olympus(), NumberPair::new(1, 4));
+    ///
+    /// let game_options = GameOptionsBuilder::from_preset("chicken")?
+    ///     .olympus_olympus(NumberPair::new(1, 1))?
+    ///     .build()?;
+    ///
+    /// assert_eq!(game_options.olympus_olympus(), NumberPair::new(1, 1));
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there is no preset with the
+    /// given name.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptionsBuilder` struct pre-filled with the preset values.
+    ///
+    /// # See Also
+    ///
+    /// * [`GamePresets`](crate::GamePresets)
+    /// * [`GameOptionsBuilder::new()`](GameOptionsBuilder::new())
+    /// * [`GameOptionsBuilder::build()`](GameOptionsBuilder::build())
+    pub fn from_preset(name: &str) -> Result<Self, BuilderError> {
+        let Some(preset) = GamePresets::get_preset(name) else {
+            return Err(BuilderError::UnknownPreset(name.to_string()));
+        };
+
+        Ok(Self {
+            choice_atlantis: Some(preset.choice_atlantis),
+            choice_olympus: Some(preset.choice_olympus),
+            atlantis_atlantis: Some(preset.atlantis_atlantis),
+            atlantis_olympus: Some(preset.atlantis_olympus),
+            olympus_atlantis: Some(preset.olympus_atlantis),
+            olympus_olympus: Some(preset.olympus_olympus),
+            ..Self::new(GameOptionsBuilderTypes::Customized)
+        })
+    }
+
     /// Sets the minimum value for the `GameOptions`.
     ///
     /// This function sets the minimum value for the `GameOptions` struct that
@@ -336,7 +466,7 @@ impl GameOptionsBuilder {
     ///
     /// ## `SeededBuilder`
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
@@ -390,9 +520,10 @@ impl GameOptionsBuilder {
                 self.min_value = Some(min_value);
                 Ok(self)
             }
-            GameOptionsBuilderTypes::Customized => Err(BuilderError::InvalidOptionSpecified(
-                "min_value must not be set when using CusotmizedBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Customized => Err(BuilderError::InvalidBuilderType {
+                field:        "min_value",
+                builder_type: self.builder_type,
+            }),
         }
     }
 
@@ -425,7 +556,7 @@ impl GameOptionsBuilder {
     ///
     /// ## `SeededBuilder`
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
@@ -479,9 +610,10 @@ impl GameOptionsBuilder {
                 self.max_value = Some(max_value);
                 Ok(self)
             }
-            GameOptionsBuilderTypes::Customized => Err(BuilderError::InvalidOptionSpecified(
-                "max_value must not be set when using CustomizedBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Customized => Err(BuilderError::InvalidBuilderType {
+                field:        "max_value",
+                builder_type: self.builder_type,
+            }),
         }
     }
 
@@ -510,26 +642,30 @@ impl GameOptionsBuilder {
     ///         .choice_atlantis("cooperate");
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.choice_atlantis(), "cooperate");
     /// ```
     ///
     /// ## `SeededBuilder`
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
     /// };
-    /// use rand::SeedableRng;
     ///
     /// let game_options_builder =
     ///     GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded)
     ///         .choice_atlantis("cooperate");
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder
+    ///     .unwrap()
+    ///     .seed(2024)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
     ///
     /// # assert_eq!(game_options.choice_atlantis(), "cooperate");
     /// ```
@@ -547,7 +683,7 @@ impl GameOptionsBuilder {
     ///         .choice_atlantis("cooperate");
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.choice_atlantis(), "cooperate");
     /// ```
@@ -575,9 +711,7 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::seed()`](GameOptionsBuilder::seed())
     pub fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Result<Self, BuilderError> {
         if choice_atlantis.is_empty() {
-            return Err(BuilderError::InvalidOptionValueSpecified(
-                "choice_atlantis must not be empty".to_string(),
-            ));
+            return Err(BuilderError::EmptyChoiceName("choice_atlantis"));
         }
         self.choice_atlantis = Some(choice_atlantis);
         Ok(self)
@@ -608,14 +742,14 @@ impl GameOptionsBuilder {
     ///         .choice_olympus("defect");
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.choice_olympus(), "defect");
     /// ```
     ///
     /// ## `SeededBuilder`
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
@@ -626,7 +760,12 @@ impl GameOptionsBuilder {
     ///         .choice_olympus("defect");
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder
+    ///     .unwrap()
+    ///     .seed(2024)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
     ///
     /// # assert_eq!(game_options.choice_olympus(), "defect");
     /// ```
@@ -644,7 +783,7 @@ impl GameOptionsBuilder {
     ///         .choice_olympus("defect");
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.choice_olympus(), "defect");
     /// ```
@@ -672,9 +811,7 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::seed()`](GameOptionsBuilder::seed())
     pub fn choice_olympus(mut self, choice_olympus: &'static str) -> Result<Self, BuilderError> {
         if choice_olympus.is_empty() {
-            return Err(BuilderError::InvalidOptionValueSpecified(
-                "choice_olympus must not be empty".to_string(),
-            ));
+            return Err(BuilderError::EmptyChoiceName("choice_olympus"));
         }
         self.choice_olympus = Some(choice_olympus);
         Ok(self)
@@ -711,7 +848,7 @@ impl GameOptionsBuilder {
     ///
     /// ## `SeededBuilder`
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
@@ -739,7 +876,7 @@ impl GameOptionsBuilder {
     ///
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(4, 4));
     /// ```
@@ -771,12 +908,12 @@ impl GameOptionsBuilder {
         atlantis_atlantis: NumberPair,
     ) -> Result<Self, BuilderError> {
         match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidOptionSpecified(
-                "Field atlantis_atlantis can not be set when using RandomizedBuilder".to_string(),
-            )),
-            GameOptionsBuilderTypes::Seeded => Err(BuilderError::InvalidOptionSpecified(
-                "Field atlantis_atlantis can not be set when using SeededBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Randomized | GameOptionsBuilderTypes::Seeded => {
+                Err(BuilderError::InvalidBuilderType {
+                    field:        "atlantis_atlantis",
+                    builder_type: self.builder_type,
+                })
+            }
             GameOptionsBuilderTypes::Customized => {
                 self.atlantis_atlantis = Some(atlantis_atlantis);
                 Ok(self)
@@ -844,7 +981,7 @@ impl GameOptionsBuilder {
     ///         .atlantis_olympus(NumberPair::new(0, 5));
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.atlantis_olympus(), NumberPair::new(0, 5));
     /// ```
@@ -874,12 +1011,12 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::seed()`](GameOptionsBuilder::seed())
     pub fn atlantis_olympus(mut self, atlantis_olympus: NumberPair) -> Result<Self, BuilderError> {
         match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidOptionSpecified(
-                "Field atlantis_olympus can not be set when using RandomizedBuilder".to_string(),
-            )),
-            GameOptionsBuilderTypes::Seeded => Err(BuilderError::InvalidOptionSpecified(
-                "Field atlantis_olympus can not be set when using SeededBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Randomized | GameOptionsBuilderTypes::Seeded => {
+                Err(BuilderError::InvalidBuilderType {
+                    field:        "atlantis_olympus",
+                    builder_type: self.builder_type,
+                })
+            }
             GameOptionsBuilderTypes::Customized => {
                 self.atlantis_olympus = Some(atlantis_olympus);
                 Ok(self)
@@ -949,7 +1086,7 @@ impl GameOptionsBuilder {
     ///
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.olympus_atlantis(), NumberPair::new(5, 0));
     /// ```
@@ -979,12 +1116,12 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::seed()`](GameOptionsBuilder::seed())
     pub fn olympus_atlantis(mut self, olympus_atlantis: NumberPair) -> Result<Self, BuilderError> {
         match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidOptionSpecified(
-                "Field olympus_atlantis can not be set when using RandomizedBuilder".to_string(),
-            )),
-            GameOptionsBuilderTypes::Seeded => Err(BuilderError::InvalidOptionSpecified(
-                "Field olympus_atlantis can not be set when using SeededBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Randomized | GameOptionsBuilderTypes::Seeded => {
+                Err(BuilderError::InvalidBuilderType {
+                    field:        "olympus_atlantis",
+                    builder_type: self.builder_type,
+                })
+            }
             GameOptionsBuilderTypes::Customized => {
                 self.olympus_atlantis = Some(olympus_atlantis);
                 Ok(self)
@@ -1053,7 +1190,7 @@ impl GameOptionsBuilder {
     ///
     /// # assert!(game_options_builder.is_ok());
     ///
-    /// let game_options = game_options_builder.unwrap().build();
+    /// let game_options = game_options_builder.unwrap().build().unwrap();
     ///
     /// # assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 3));
     /// ```
@@ -1083,12 +1220,12 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::seed()`](GameOptionsBuilder::seed())
     pub fn olympus_olympus(mut self, olympus_olympus: NumberPair) -> Result<Self, BuilderError> {
         match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidOptionSpecified(
-                "Field olympus_olympus can not be set when using RandomizedBuilder".to_string(),
-            )),
-            GameOptionsBuilderTypes::Seeded => Err(BuilderError::InvalidOptionSpecified(
-                "Field olympus_olympus can not be set when using SeededBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Randomized | GameOptionsBuilderTypes::Seeded => {
+                Err(BuilderError::InvalidBuilderType {
+                    field:        "olympus_olympus",
+                    builder_type: self.builder_type,
+                })
+            }
             GameOptionsBuilderTypes::Customized => {
                 self.olympus_olympus = Some(olympus_olympus);
                 Ok(self)
@@ -1126,7 +1263,7 @@ impl GameOptionsBuilder {
     ///
     /// ## `SeededBuilder`
     ///
-    /// ```no_run
+    /// ```
     /// use dilemma_tactix_lib::{
     ///     GameOptionsBuilder,
     ///     GameOptionsBuilderTypes,
@@ -1178,28 +1315,64 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::olympus_olympus()`](GameOptionsBuilder::olympus_olympus())
     pub fn seed(mut self, seed: u64) -> Result<Self, BuilderError> {
         match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidOptionSpecified(
-                "Field seed can not be set when using RandomizedBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidBuilderType {
+                field:        "seed",
+                builder_type: self.builder_type,
+            }),
             GameOptionsBuilderTypes::Seeded => {
                 self.seed = Some(seed);
                 Ok(self)
             }
-            GameOptionsBuilderTypes::Customized => Err(BuilderError::InvalidOptionSpecified(
-                "Field seed can not be set when using CustomizedBuilder".to_string(),
-            )),
+            GameOptionsBuilderTypes::Customized => Err(BuilderError::InvalidBuilderType {
+                field:        "seed",
+                builder_type: self.builder_type,
+            }),
         }
     }
 
     /// Builds the `GameOptions` struct.
     ///
-    /// # Returns
+    /// Any optional field that has not been set falls back to the default
+    /// listed in the table on [`GameOptionsBuilder`].
     ///
-    /// A new `GameOptions` struct.
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptionsBuilder,
+    ///     GameOptionsBuilderTypes,
+    /// };
     ///
-    /// # Panics
+    /// let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Randomized)
+    ///     .min_value(10)?
+    ///     .max_value(1)?;
+    ///
+    /// assert_eq!(
+    ///     builder.build(),
+    ///     Err(BuilderError::InvalidBounds {
+    ///         min_value: 10,
+    ///         max_value: 1,
+    ///     })
+    /// );
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
     ///
-    /// This function will panic if any of the required fields are not set.
+    /// This function will return an error if:
+    ///
+    /// * either choice name is empty,
+    /// * both choices have the same name,
+    /// * `min_value` is greater than `max_value` for a
+    ///   [`GameOptionsBuilderTypes::Randomized`](GameOptionsBuilderTypes::Randomized) or
+    ///   [`GameOptionsBuilderTypes::Seeded`](GameOptionsBuilderTypes::Seeded) builder,
+    /// * `seed` has not been set for a
+    ///   [`GameOptionsBuilderTypes::Seeded`](GameOptionsBuilderTypes::Seeded) builder.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct.
     ///
     /// # See Also
     ///
@@ -1212,18 +1385,38 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::atlantis_olympus()`](GameOptionsBuilder::atlantis_olympus())
     /// * [`GameOptionsBuilder::olympus_atlantis()`](GameOptionsBuilder::olympus_atlantis())
     /// * [`GameOptionsBuilder::olympus_olympus()`](GameOptionsBuilder::olympus_olympus())
-    #[must_use]
-    pub fn build(self) -> GameOptions {
+    pub fn build(self) -> Result<GameOptions, BuilderError> {
+        let choice_atlantis = self.choice_atlantis.unwrap_or("cooperate");
+        let choice_olympus = self.choice_olympus.unwrap_or("defect");
+
+        if choice_atlantis.is_empty() {
+            return Err(BuilderError::EmptyChoiceName("choice_atlantis"));
+        }
+
+        if choice_olympus.is_empty() {
+            return Err(BuilderError::EmptyChoiceName("choice_olympus"));
+        }
+
+        if choice_atlantis == choice_olympus {
+            return Err(BuilderError::DuplicateChoiceNames(choice_atlantis));
+        }
+
         match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => self.build_randomized(),
-            GameOptionsBuilderTypes::Seeded => self.build_seeded(),
-            GameOptionsBuilderTypes::Customized => self.build_customized(),
+            GameOptionsBuilderTypes::Randomized => {
+                self.build_randomized(choice_atlantis, choice_olympus)
+            }
+            GameOptionsBuilderTypes::Seeded => self.build_seeded(choice_atlantis, choice_olympus),
+            GameOptionsBuilderTypes::Customized => {
+                Ok(self.build_customized(choice_atlantis, choice_olympus))
+            }
         }
     }
 
-    fn build_customized(&self) -> GameOptions {
-        let choice_atlantis = self.choice_atlantis.unwrap_or("cooperate");
-        let choice_olympus = self.choice_olympus.unwrap_or("defect");
+    fn build_customized(
+        &self,
+        choice_atlantis: &'static str,
+        choice_olympus: &'static str,
+    ) -> GameOptions {
         let atlantis_atlantis = self.atlantis_atlantis.unwrap_or(NumberPair::new(4, 4));
         let atlantis_olympus = self.atlantis_olympus.unwrap_or(NumberPair::new(5, 0));
         let olympus_atlantis = self.olympus_atlantis.unwrap_or(NumberPair::new(0, 5));
@@ -1239,30 +1432,52 @@ impl GameOptionsBuilder {
         }
     }
 
-    fn build_seeded(&self) -> GameOptions {
-        todo!()
+    fn build_seeded(
+        &self,
+        choice_atlantis: &'static str,
+        choice_olympus: &'static str,
+    ) -> Result<GameOptions, BuilderError> {
+        let Some(seed) = self.seed else {
+            return Err(BuilderError::MissingOption("seed"));
+        };
+
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+
+        self.build_random(choice_atlantis, choice_olympus, &mut rng)
     }
 
-    fn build_randomized(&self) -> GameOptions {
+    fn build_randomized(
+        &self,
+        choice_atlantis: &'static str,
+        choice_olympus: &'static str,
+    ) -> Result<GameOptions, BuilderError> {
+        let mut rng = ChaCha12Rng::from_os_rng();
+
+        self.build_random(choice_atlantis, choice_olympus, &mut rng)
+    }
+
+    fn build_random<R: Rng>(
+        &self,
+        choice_atlantis: &'static str,
+        choice_olympus: &'static str,
+        rng: &mut R,
+    ) -> Result<GameOptions, BuilderError> {
         let min_value = self.min_value.unwrap_or(1);
         let max_value = self.max_value.unwrap_or(10);
 
-        let choice_atlantis = self.choice_atlantis.unwrap_or("cooperate");
-        let choice_olympus = self.choice_olympus.unwrap_or("defect");
-
-        let atlantis_atlantis = NumberPair::random(min_value, max_value);
-        let atlantis_olympus = NumberPair::random(min_value, max_value);
-        let olympus_atlantis = NumberPair::random(min_value, max_value);
-        let olympus_olympus = NumberPair::random(min_value, max_value);
+        let atlantis_atlantis = NumberPair::try_random_with_rng(min_value, max_value, rng)?;
+        let atlantis_olympus = NumberPair::try_random_with_rng(min_value, max_value, rng)?;
+        let olympus_atlantis = NumberPair::try_random_with_rng(min_value, max_value, rng)?;
+        let olympus_olympus = NumberPair::try_random_with_rng(min_value, max_value, rng)?;
 
-        GameOptions {
+        Ok(GameOptions {
             choice_atlantis,
             choice_olympus,
             atlantis_atlantis,
             atlantis_olympus,
             olympus_atlantis,
             olympus_olympus,
-        }
+        })
     }
 }
 
@@ -1429,7 +1644,7 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder?.choice_olympus("defect");
         assert!(builder.is_ok());
-        let game_options = builder?.build();
+        let game_options = builder?.build()?;
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
@@ -1438,7 +1653,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded() -> Result<(), BuilderError> {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1451,7 +1665,7 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder?.seed(123456789);
         assert!(builder.is_ok());
-        let game_options = builder?.build();
+        let game_options = builder?.build()?;
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
@@ -1474,7 +1688,7 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder?.olympus_olympus(NumberPair::new(1, 1));
         assert!(builder.is_ok());
-        let game_options = builder?.build();
+        let game_options = builder?.build()?;
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
@@ -1491,7 +1705,7 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().choice_olympus("defect");
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
@@ -1506,7 +1720,7 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().choice_olympus("defect");
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
@@ -1521,7 +1735,7 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().choice_olympus("defect");
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
@@ -1536,14 +1750,13 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().choice_atlantis("cooperate");
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
     }
 
     #[test]
-    #[ignore = "Seeded build is not yet implemented"]
     fn test_build_seeded_missing_min_value() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.max_value(10);
@@ -1554,14 +1767,13 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().seed(123456789);
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_max_value() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1572,14 +1784,13 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().seed(123456789);
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_choice_atlantis() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1590,14 +1801,13 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().seed(123456789);
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_choice_olympus() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1608,14 +1818,13 @@ mod tests {
         assert!(builder.is_ok());
         let builder = builder.unwrap().seed(123456789);
         assert!(builder.is_ok());
-        let game_options = builder.unwrap().build();
+        let game_options = builder.unwrap().build().unwrap();
 
         assert_eq!(game_options.choice_atlantis(), "cooperate");
         assert_eq!(game_options.choice_olympus(), "defect");
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_seed() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1628,8 +1837,111 @@ mod tests {
         assert!(builder.is_ok());
         let game_options = builder.unwrap().build();
 
-        assert_eq!(game_options.choice_atlantis(), "cooperate");
-        assert_eq!(game_options.choice_olympus(), "defect");
+        assert_eq!(game_options, Err(BuilderError::MissingOption("seed")));
+    }
+
+    #[test]
+    fn test_build_seeded_repeatable() -> Result<(), BuilderError> {
+        let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded)
+            .min_value(1)?
+            .max_value(10)?
+            .seed(123456789)?;
+
+        let game_options_a = builder.build()?;
+        let game_options_b = builder.build()?;
+
+        assert_eq!(game_options_a, game_options_b);
+
+        let game_options_c = builder.seed(987654321)?.build()?;
+
+        assert_ne!(game_options_a, game_options_c);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_invalid_bounds() -> Result<(), BuilderError> {
+        let randomized = GameOptionsBuilder::new(GameOptionsBuilderTypes::Randomized)
+            .min_value(10)?
+            .max_value(1)?;
+
+        assert_eq!(
+            randomized.build(),
+            Err(BuilderError::InvalidBounds {
+                min_value: 10,
+                max_value: 1,
+            })
+        );
+
+        let seeded = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded)
+            .min_value(20)?
+            .seed(123456789)?;
+
+        assert_eq!(
+            seeded.build(),
+            Err(BuilderError::InvalidBounds {
+                min_value: 20,
+                max_value: 10,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_equal_bounds() -> Result<(), BuilderError> {
+        let game_options = GameOptionsBuilder::new(GameOptionsBuilderTypes::Randomized)
+            .min_value(5)?
+            .max_value(5)?
+            .build()?;
+
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(5, 5));
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(5, 5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_duplicate_choice_names() -> Result<(), BuilderError> {
+        let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized)
+            .choice_atlantis("defect")?;
+
+        assert_eq!(
+            builder.build(),
+            Err(BuilderError::DuplicateChoiceNames("defect"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_choice_name() {
+        let builder =
+            GameOptionsBuilder::new(GameOptionsBuilderTypes::Randomized).choice_atlantis("");
+
+        assert_eq!(
+            builder,
+            Err(BuilderError::EmptyChoiceName("choice_atlantis"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_builder_type_error() {
+        let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded)
+            .olympus_olympus(NumberPair::new(1, 1));
+
+        assert_eq!(
+            builder,
+            Err(BuilderError::InvalidBuilderType {
+                field:        "olympus_olympus",
+                builder_type: GameOptionsBuilderTypes::Seeded,
+            })
+        );
+
+        assert_eq!(
+            builder.unwrap_err().to_string(),
+            "Field olympus_olympus can not be set when using SeededBuilder"
+        );
     }
 
     #[test]
@@ -1739,4 +2051,64 @@ mod tests {
         assert!(builder.is_err());
         builder.unwrap();
     }
+
+    #[test]
+    fn test_from_preset() -> Result<(), BuilderError> {
+        let builder = GameOptionsBuilder::from_preset("stag_hunt");
+        assert!(builder.is_ok());
+        let builder = builder?;
+        assert_eq!(builder.builder_type, GameOptionsBuilderTypes::Customized);
+        let game_options = builder.build()?;
+
+        assert_eq!(game_options.choice_atlantis(), "peace");
+        assert_eq!(game_options.choice_olympus(), "war");
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(4, 4));
+        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(0, 3));
+        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(3, 0));
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(2, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_preset_unknown() {
+        let builder = GameOptionsBuilder::from_preset("rock_paper_scissors");
+        assert_eq!(
+            builder,
+            Err(BuilderError::UnknownPreset(
+                "rock_paper_scissors".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_builder_types_from_str() {
+        assert_eq!(
+            "randomized".parse::<GameOptionsBuilderTypes>(),
+            Ok(GameOptionsBuilderTypes::Randomized)
+        );
+        assert_eq!(
+            "SEEDED".parse::<GameOptionsBuilderTypes>(),
+            Ok(GameOptionsBuilderTypes::Seeded)
+        );
+        assert_eq!(
+            "Customized".parse::<GameOptionsBuilderTypes>(),
+            Ok(GameOptionsBuilderTypes::Customized)
+        );
+        assert_eq!(
+            "custom".parse::<GameOptionsBuilderTypes>(),
+            Err(BuilderError::UnknownBuilderType("custom".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_build_empty_choice_name_field() {
+        let mut builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized);
+        builder.choice_olympus = Some("");
+
+        assert_eq!(
+            builder.build(),
+            Err(BuilderError::EmptyChoiceName("choice_olympus"))
+        );
+    }
 }
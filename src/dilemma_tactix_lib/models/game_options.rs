@@ -5,12 +5,20 @@
 
 use std::fmt::Display;
 
+use serde::Serialize;
+
 #[cfg(test)]
 use crate::RANDOM_SEED;
 use crate::{
+    BuilderError,
+    Choice,
     ChoiceNameOptions,
+    CustomizedBuilder,
+    GameClass,
     GameOptionsBuilder,
     NumberPair,
+    RandomizedBuilder,
+    SeededBuilder,
 };
 
 /// This is a struct that holds the options for a game.
@@ -45,9 +53,13 @@ use crate::{
 /// ## Builder
 ///
 /// ```
-/// use dilemma_tactix_lib::GameOptions;
+/// use dilemma_tactix_lib::{
+///     GameOptions,
+/// # BuilderError,
+/// };
 ///
-/// let game_options = GameOptions::builder("customized").build();
+/// let game_options = GameOptions::builder("customized").build()?;
+/// # Ok::<(), BuilderError>(())
 /// ```
 ///
 /// # Notes
@@ -60,7 +72,7 @@ use crate::{
 /// * [`GameOptions::new()`](#method.new)
 /// * [`GameOptions::default()`](#method.default)
 /// * [`GameOptions::builder()`](#method.builder)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct GameOptions {
     /// The label for the first choice that can be made
     pub choice_atlantis:   &'static str,
@@ -94,13 +106,64 @@ impl GameOptions {
     ///
     /// # Panics
     ///
-    /// Panics if `min_value` is greater than `max_value`.
+    /// Panics if `min_value` is greater than `max_value`. Use
+    /// [`GameOptions::try_new()`](#method.try_new) if the bounds come from user
+    /// input.
     ///
     /// # See Also
     ///
     /// * [`GameOptions::default()`](#method.default)
+    /// * [`GameOptions::try_new()`](#method.try_new)
     #[must_use]
     pub fn new(min_value: u32, max_value: u32) -> Self {
+        match Self::try_new(min_value, max_value) {
+            Ok(game_options) => game_options,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Creates a new `GameOptions` struct, checking the bounds first.
+    ///
+    /// This is the non-panicking version of
+    /// [`GameOptions::new()`](#method.new).
+    ///
+    /// # Arguments
+    ///
+    /// * `min_value` - The minimum score for that can be assigned to a choice.
+    /// * `max_value` - The maximum score for that can be assigned to a choice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_options = GameOptions::try_new(1, 10)?;
+    ///
+    /// assert!(game_options.atlantis_atlantis().first() <= 10);
+    ///
+    /// assert!(GameOptions::try_new(10, 1).is_err());
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError::InvalidBounds`](crate::BuilderError::InvalidBounds)
+    /// if `min_value` is greater than `max_value`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::new()`](#method.new)
+    pub fn try_new(min_value: u32, max_value: u32) -> Result<Self, BuilderError> {
+        if min_value > max_value {
+            return Err(BuilderError::InvalidBounds {
+                min_value,
+                max_value,
+            });
+        }
+
         #[cfg(test)]
         let (choice_atlantis, choice_olympus) =
             ChoiceNameOptions::get_random_pair_seeded(RANDOM_SEED.0);
@@ -124,14 +187,14 @@ impl GameOptions {
             NumberPair::random(min_value, max_value),
         );
 
-        Self {
+        Ok(Self {
             choice_atlantis,
             choice_olympus,
             atlantis_atlantis,
             atlantis_olympus,
             olympus_atlantis,
             olympus_olympus,
-        }
+        })
     }
 
     /// Returns the value of `choice_atlantis`.
@@ -170,6 +233,111 @@ impl GameOptions {
         self.choice_olympus
     }
 
+    /// Returns the display name of the given choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `choice` - The choice to look up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameOptions,
+    /// # BuilderError,
+    /// };
+    ///
+    /// let game_options = GameOptions::customized().build()?;
+    ///
+    /// assert_eq!(game_options.choice_name(Choice::Olympus), "defect");
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::choice_atlantis()`](#method.choice_atlantis)
+    /// * [`GameOptions::choice_olympus()`](#method.choice_olympus)
+    #[must_use]
+    pub const fn choice_name(&self, choice: Choice) -> &str {
+        match choice {
+            Choice::Atlantis => self.choice_atlantis,
+            Choice::Olympus => self.choice_olympus,
+        }
+    }
+
+    /// Returns the choice named by `name`.
+    ///
+    /// A choice can be named by its letter (`A` or `B`), by its variant
+    /// (`Atlantis` or `Olympus`), or by the name it has in this game. Letter
+    /// case is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameOptions,
+    /// # BuilderError,
+    /// };
+    ///
+    /// let game_options = GameOptions::customized().build()?;
+    ///
+    /// assert_eq!(game_options.parse_choice("b"), Some(Choice::Olympus));
+    /// assert_eq!(
+    ///     game_options.parse_choice("Cooperate"),
+    ///     Some(Choice::Atlantis)
+    /// );
+    /// assert_eq!(game_options.parse_choice("maybe"), None);
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Choice` with that name, or `None` if neither choice has it.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::choice_name()`](#method.choice_name)
+    #[must_use]
+    pub fn parse_choice(&self, name: &str) -> Option<Choice> {
+        [Choice::Atlantis, Choice::Olympus]
+            .into_iter()
+            .find(|&choice| {
+                let letter = match choice {
+                    Choice::Atlantis => "A",
+                    Choice::Olympus => "B",
+                };
+
+                [letter, &choice.to_string(), self.choice_name(choice)]
+                    .iter()
+                    .any(|candidate| candidate.eq_ignore_ascii_case(name))
+            })
+    }
+
+    /// Classifies the game by the ordering of its payoffs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameClass,
+    ///     GamePresets,
+    /// };
+    ///
+    /// let game_options = GamePresets::get_preset("stag_hunt").unwrap();
+    ///
+    /// assert_eq!(game_options.classify(), GameClass::StagHunt);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The [`GameClass`] of the game.
+    #[must_use]
+    pub fn classify(&self) -> GameClass {
+        GameClass::from(self)
+    }
+
     /// Returns the value of `atlantis_atlantis`.
     ///
     /// This function returns the value of `atlantis_atlantis`, which is the
@@ -281,9 +449,17 @@ impl GameOptions {
     /// Unlike the `default` method. a `default` builder is guaranteed to
     /// be the same each time it is called.
     ///
+    /// Any unrecognized `builder_type` produces a randomized builder. Use
+    /// [`GameOptions::try_builder()`](#method.try_builder) to reject unknown
+    /// names, or one of [`GameOptions::randomized()`](#method.randomized),
+    /// [`GameOptions::seeded()`](#method.seeded) and
+    /// [`GameOptions::customized()`](#method.customized) to have the compiler
+    /// check which fields can be set.
+    ///
     /// # See Also
     ///
     /// * [`GameOptionsBuilder`](#struct.GameOptionsBuilder)
+    /// * [`GameOptions::try_builder()`](#method.try_builder)
     /// * [`GameOptions::new()`](#method.new)
     /// * [`GameOptions::default()`](#method.default)
     /// * [`GameOptionsBuilder::build()`](#method.build)
@@ -301,6 +477,124 @@ impl GameOptions {
             ),
         }
     }
+
+    /// Create a builder for a `GameOptions` struct, rejecting unknown builder
+    /// types.
+    ///
+    /// This is the strict version of [`GameOptions::builder()`](#method.builder).
+    /// The builder type is parsed with the [`FromStr`](std::str::FromStr)
+    /// implementation of [`GameOptionsBuilderTypes`](crate::GameOptionsBuilderTypes),
+    /// so a misspelled name is reported instead of silently producing a
+    /// randomized builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_type` - The type of builder to create. Valid values are `randomized`, `seeded`,
+    ///   and `customized`, in any letter case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let builder = GameOptions::try_builder("customized")?;
+    ///
+    /// assert!(GameOptions::try_builder("customised").is_err());
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError::UnknownBuilderType`](crate::BuilderError::UnknownBuilderType)
+    /// if the builder type is not recognized.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::builder()`](#method.builder)
+    pub fn try_builder(builder_type: &str) -> Result<GameOptionsBuilder, BuilderError> {
+        Ok(GameOptionsBuilder::new(builder_type.parse()?))
+    }
+
+    /// Create a compile-time checked builder for randomized `GameOptions`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_options = GameOptions::randomized()
+    ///     .min_value(1)
+    ///     .max_value(5)
+    ///     .build()?;
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// * [`RandomizedBuilder`](crate::RandomizedBuilder)
+    #[must_use]
+    pub const fn randomized() -> RandomizedBuilder {
+        RandomizedBuilder::new()
+    }
+
+    /// Create a compile-time checked builder for seeded `GameOptions`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for the random number generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_options = GameOptions::seeded(2024).build()?;
+    ///
+    /// assert_eq!(game_options, GameOptions::seeded(2024).build()?);
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// * [`SeededBuilder`](crate::SeededBuilder)
+    #[must_use]
+    pub const fn seeded(seed: u64) -> SeededBuilder {
+        SeededBuilder::new(seed)
+    }
+
+    /// Create a compile-time checked builder for customized `GameOptions`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_options = GameOptions::customized()
+    ///     .olympus_olympus(NumberPair::new(1, 1))
+    ///     .build()?;
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// * [`CustomizedBuilder`](crate::CustomizedBuilder)
+    #[must_use]
+    pub const fn customized() -> CustomizedBuilder {
+        CustomizedBuilder::new()
+    }
 }
 
 impl Default for GameOptions {
@@ -381,15 +675,15 @@ mod tests {
 
         assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(6, 9));
 
-        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(3, 8));
+        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(2, 3));
 
-        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(6, 7));
+        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(8, 6));
 
-        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 6));
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(10, 3));
 
-        assert_eq!(game_options.choice_atlantis(), "discrete");
+        assert_eq!(game_options.choice_atlantis(), "particle");
 
-        assert_eq!(game_options.choice_olympus(), "continuous");
+        assert_eq!(game_options.choice_olympus(), "wave");
 
         assert!(choice_atlantis_options.contains(&game_options.choice_atlantis()));
 
@@ -402,15 +696,15 @@ mod tests {
 
         assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(6, 9));
 
-        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(3, 8));
+        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(2, 3));
 
-        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(6, 7));
+        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(8, 6));
 
-        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 6));
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(10, 3));
 
-        assert_eq!(game_options.choice_atlantis(), "discrete");
+        assert_eq!(game_options.choice_atlantis(), "particle");
 
-        assert_eq!(game_options.choice_olympus(), "continuous");
+        assert_eq!(game_options.choice_olympus(), "wave");
     }
 
     #[test]
@@ -419,8 +713,8 @@ mod tests {
 
         assert_eq!(
             format!("{}", game_options),
-            "choice_atlantis: discrete, choice_olympus: continuous, atlantis_atlantis: (6, 9), \
-             atlantis_olympus: (3, 8), olympus_atlantis: (6, 7), olympus_olympus: (3, 6)"
+            "choice_atlantis: particle, choice_olympus: wave, atlantis_atlantis: (6, 9), \
+             atlantis_olympus: (2, 3), olympus_atlantis: (8, 6), olympus_olympus: (10, 3)"
         );
     }
 
@@ -0,0 +1,384 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::HashSet,
+    sync::{
+        Mutex,
+        OnceLock,
+    },
+};
+
+use serde::Deserialize;
+
+use crate::{
+    BuilderError,
+    ChoiceNameOptions,
+    GameOptions,
+    GameOptionsBuilder,
+    GameOptionsBuilderTypes,
+    NumberPair,
+};
+
+/// The parameters a game is built from, as plain data.
+///
+/// `GameParams` mirrors the methods of [`GameOptionsBuilder`] so that a game
+/// can be described in JSON, for example by a client of a server or of a
+/// child process, and then built with [`GameParams::build()`].
+///
+/// Every field is optional. The builder type is taken from `builder` if it is
+/// given, otherwise a preset or any score makes a customized game, a seed
+/// makes a seeded game, and anything else a randomized game.
+///
+/// The JSON form of a built [`GameOptions`] is also valid `GameParams`, and
+/// builds the same game again.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     GameParams,
+///     NumberPair,
+/// };
+///
+/// let params: GameParams = serde_json::from_str(
+///     r#"{"preset": "chicken", "olympus_olympus": {"first": 1, "second": 1}}"#,
+/// )?;
+///
+/// let game_options = params.build()?;
+///
+/// assert_eq!(game_options.choice_atlantis(), "swerve");
+/// assert_eq!(game_options.olympus_olympus(), NumberPair::new(1, 1));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GameParams {
+    /// The builder type, by the names accepted by
+    /// [`GameOptionsBuilderTypes`].
+    pub builder:           Option<String>,
+    /// The [`GamePresets`](crate::GamePresets) preset to start from.
+    pub preset:            Option<String>,
+    /// The seed of a seeded game.
+    pub seed:              Option<u64>,
+    /// The lowest score a randomized game can award.
+    pub min_value:         Option<u32>,
+    /// The highest score a randomized game can award.
+    pub max_value:         Option<u32>,
+    /// The name of choice Atlantis.
+    pub choice_atlantis:   Option<String>,
+    /// The name of choice Olympus.
+    pub choice_olympus:    Option<String>,
+    /// The scores when both players choose Atlantis.
+    pub atlantis_atlantis: Option<NumberPair>,
+    /// The scores when Aleph chooses Atlantis and Beth chooses Olympus.
+    pub atlantis_olympus:  Option<NumberPair>,
+    /// The scores when Aleph chooses Olympus and Beth chooses Atlantis.
+    pub olympus_atlantis:  Option<NumberPair>,
+    /// The scores when both players choose Olympus.
+    pub olympus_olympus:   Option<NumberPair>,
+}
+
+/// The most distinct choice names that are ever leaked by
+/// [`GameParams::intern_name()`].
+const MAX_INTERNED_NAMES: usize = 1024;
+
+/// Returns the copy of `name` in `names`, leaking and adding one if there is
+/// none yet and `names` holds fewer than `limit` names.
+fn intern_in(
+    names: &mut HashSet<&'static str>,
+    limit: usize,
+    name: &str,
+) -> Result<&'static str, BuilderError> {
+    if let Some(&interned) = names.get(name) {
+        return Ok(interned);
+    }
+
+    if names.len() >= limit {
+        return Err(BuilderError::TooManyChoiceNames(limit));
+    }
+
+    let interned: &'static str = name.to_string().leak();
+
+    names.insert(interned);
+
+    Ok(interned)
+}
+
+impl GameParams {
+    /// The most characters a choice name may have.
+    pub const MAX_NAME_LENGTH: usize = 32;
+
+    const fn has_scores(&self) -> bool {
+        self.atlantis_atlantis.is_some()
+            || self.atlantis_olympus.is_some()
+            || self.olympus_atlantis.is_some()
+            || self.olympus_olympus.is_some()
+    }
+
+    fn builder_type(&self) -> Result<GameOptionsBuilderTypes, BuilderError> {
+        if let Some(builder) = &self.builder {
+            builder.parse()
+        } else if self.preset.is_some() || self.has_scores() {
+            Ok(GameOptionsBuilderTypes::Customized)
+        } else if self.seed.is_some() {
+            Ok(GameOptionsBuilderTypes::Seeded)
+        } else {
+            Ok(GameOptionsBuilderTypes::Randomized)
+        }
+    }
+
+    /// Builds the `GameOptions` described by the parameters.
+    ///
+    /// Choice names are leaked, as `GameOptions` only holds `'static` names,
+    /// but each distinct name is only leaked once, names are at most
+    /// [`Self::MAX_NAME_LENGTH`] characters long, and only so many distinct
+    /// names are ever leaked, so a long-running program building games for
+    /// its clients can only leak so much.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuilderError`] if the builder type or preset is unknown, a
+    /// preset is combined with a builder type other than `customized`, a
+    /// choice name is too long or one too many, or the builder rejects any of
+    /// the parameters.
+    pub fn build(&self) -> Result<GameOptions, BuilderError> {
+        let builder_type = self.builder_type()?;
+
+        let mut builder = match &self.preset {
+            Some(preset) if builder_type == GameOptionsBuilderTypes::Customized => {
+                GameOptionsBuilder::from_preset(preset)?
+            }
+            Some(_) => {
+                return Err(BuilderError::InvalidBuilderType {
+                    field: "preset",
+                    builder_type,
+                })
+            }
+            None => GameOptionsBuilder::new(builder_type),
+        };
+
+        if let Some(min_value) = self.min_value {
+            builder = builder.min_value(min_value)?;
+        }
+
+        if let Some(max_value) = self.max_value {
+            builder = builder.max_value(max_value)?;
+        }
+
+        if let Some(seed) = self.seed {
+            builder = builder.seed(seed)?;
+        }
+
+        if let Some(name) = &self.choice_atlantis {
+            builder = builder.choice_atlantis(Self::intern_name("choice_atlantis", name)?)?;
+        }
+
+        if let Some(name) = &self.choice_olympus {
+            builder = builder.choice_olympus(Self::intern_name("choice_olympus", name)?)?;
+        }
+
+        if let Some(score) = self.atlantis_atlantis {
+            builder = builder.atlantis_atlantis(score)?;
+        }
+
+        if let Some(score) = self.atlantis_olympus {
+            builder = builder.atlantis_olympus(score)?;
+        }
+
+        if let Some(score) = self.olympus_atlantis {
+            builder = builder.olympus_atlantis(score)?;
+        }
+
+        if let Some(score) = self.olympus_olympus {
+            builder = builder.olympus_olympus(score)?;
+        }
+
+        builder.build()
+    }
+
+    /// Returns a `'static` copy of `name`, the value of the choice name
+    /// `field`, for setting on a [`GameOptionsBuilder`] when the name is
+    /// only borrowed, e.g. from a request or another language.
+    ///
+    /// Each distinct name is leaked once, which keeps long-running programs
+    /// that build many games with the same names from growing without
+    /// bound. The names in [`ChoiceNameOptions`] are already `'static` and
+    /// are never leaked, so they can always be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuilderError`] if `name` is longer than
+    /// [`Self::MAX_NAME_LENGTH`] characters, or is new once the most
+    /// distinct names that are ever leaked have been. The limit is shared by
+    /// the whole process and never resets, so a program that takes names
+    /// from its clients should tell them when it has been reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameParams;
+    ///
+    /// let first = GameParams::intern_name("choice_atlantis", "share")?;
+    /// let second =
+    ///     GameParams::intern_name("choice_olympus", &String::from("share"))?;
+    ///
+    /// assert!(std::ptr::eq(first, second));
+    /// # Ok::<(), dilemma_tactix_lib::BuilderError>(())
+    /// ```
+    pub fn intern_name(field: &'static str, name: &str) -> Result<&'static str, BuilderError> {
+        static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(BuilderError::ChoiceNameTooLong {
+                field,
+                max_length: Self::MAX_NAME_LENGTH,
+            });
+        }
+
+        if let Some(standard) = ChoiceNameOptions::choice_pairs()
+            .into_iter()
+            .flat_map(|(atlantis, olympus)| [atlantis, olympus])
+            .find(|&standard| standard == name)
+        {
+            return Ok(standard);
+        }
+
+        let mut names = NAMES
+            .get_or_init(Mutex::default)
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        intern_in(&mut names, MAX_INTERNED_NAMES, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn params(json: &str) -> GameParams {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[rstest]
+    #[case(r#"{}"#, GameOptionsBuilderTypes::Randomized)]
+    #[case(r#"{"seed": 3}"#, GameOptionsBuilderTypes::Seeded)]
+    #[case(r#"{"preset": "chicken"}"#, GameOptionsBuilderTypes::Customized)]
+    #[case(
+        r#"{"olympus_olympus": {"first": 1, "second": 1}}"#,
+        GameOptionsBuilderTypes::Customized
+    )]
+    #[case(r#"{"builder": "seeded", "seed": 3}"#, GameOptionsBuilderTypes::Seeded)]
+    fn test_builder_type(#[case] json: &str, #[case] expected: GameOptionsBuilderTypes) {
+        assert_eq!(params(json).builder_type(), Ok(expected));
+    }
+
+    #[test]
+    fn test_build_preset_with_overrides() {
+        let game_options = params(
+            r#"{"preset": "prisoners_dilemma", "choice_atlantis": "silent", "olympus_olympus": {"first": 2, "second": 2}}"#,
+        )
+        .build()
+        .unwrap();
+
+        assert_eq!(game_options.choice_atlantis(), "silent");
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(2, 2));
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(3, 3));
+    }
+
+    #[rstest]
+    #[case(r#"{"builder": "fancy"}"#)]
+    #[case(r#"{"preset": "nonexistent"}"#)]
+    #[case(r#"{"builder": "randomized", "preset": "chicken"}"#)]
+    #[case(r#"{"min_value": 10, "max_value": 1}"#)]
+    #[case(r#"{"choice_atlantis": "same", "choice_olympus": "same", "seed": 1}"#)]
+    fn test_build_errors(#[case] json: &str) {
+        assert!(params(json).build().is_err());
+    }
+
+    #[test]
+    fn test_seeded_games_repeat() {
+        let first = params(r#"{"seed": 42}"#).build().unwrap();
+        let second = params(r#"{"seed": 42}"#).build().unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_built_game_round_trips() {
+        let game_options = params(r#"{"seed": 9, "choice_atlantis": "left"}"#)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&game_options).unwrap();
+
+        assert_eq!(params(&json).build(), Ok(game_options));
+    }
+
+    #[test]
+    fn test_intern_leaks_each_name_once() {
+        let first = GameParams::intern_name("choice_atlantis", "interned-name").unwrap();
+        let second =
+            GameParams::intern_name("choice_olympus", &String::from("interned-name")).unwrap();
+
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn test_interning_stops_at_the_limit() {
+        let mut names = HashSet::new();
+
+        let first = intern_in(&mut names, 2, "first").unwrap();
+
+        intern_in(&mut names, 2, "second").unwrap();
+
+        assert_eq!(
+            intern_in(&mut names, 2, "third"),
+            Err(BuilderError::TooManyChoiceNames(2))
+        );
+        assert!(std::ptr::eq(
+            intern_in(&mut names, 2, "first").unwrap(),
+            first
+        ));
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn test_standard_names_are_not_leaked() {
+        let (atlantis, olympus) = ChoiceNameOptions::get_choice_pair(0);
+
+        assert!(std::ptr::eq(
+            GameParams::intern_name("choice_atlantis", &String::from(atlantis)).unwrap(),
+            atlantis
+        ));
+        assert!(std::ptr::eq(
+            GameParams::intern_name("choice_olympus", &String::from(olympus)).unwrap(),
+            olympus
+        ));
+    }
+
+    #[test]
+    fn test_long_names_are_refused() {
+        let name = "x".repeat(GameParams::MAX_NAME_LENGTH + 1);
+
+        let error = GameParams {
+            choice_olympus: Some(name),
+            ..GameParams::default()
+        }
+        .build()
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            BuilderError::ChoiceNameTooLong {
+                field:      "choice_olympus",
+                max_length: GameParams::MAX_NAME_LENGTH,
+            }
+        );
+    }
+}
@@ -0,0 +1,381 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    ChoiceNameOptions,
+    GameOptions,
+    NumberPair,
+};
+
+/// A catalogue of canonical two-player games.
+///
+/// This is a simple data struct that pairs some of the choice names in
+/// [`ChoiceNameOptions`] with the payoff structure of the classic game that
+/// those names evoke. A game built from a preset therefore reads sensibly:
+/// `swerve`/`straight` is scored as the game of Chicken, `opera`/`football` as
+/// the Battle of the Sexes, and so on.
+///
+/// The presets can be looked up by name using
+/// [`get_preset`](GamePresets::get_preset), or turned into a builder using
+/// [`GameOptionsBuilder::from_preset`](crate::GameOptionsBuilder::from_preset).
+///
+/// The complete list of available presets can be found in the table
+/// [below](#available-presets).
+///
+/// # Examples
+///
+/// ## Get a specific preset
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     GamePresets,
+///     NumberPair,
+/// };
+///
+/// let game_options = GamePresets::get_preset("chicken").unwrap();
+///
+/// assert_eq!(game_options.choice_atlantis(), "swerve");
+/// assert_eq!(game_options.choice_olympus(), "straight");
+/// assert_eq!(game_options.olympus_olympus(), NumberPair::new(0, 0));
+/// ```
+///
+/// ## List the available presets
+///
+/// ```
+/// use dilemma_tactix_lib::GamePresets;
+///
+/// let names = GamePresets::preset_names();
+///
+/// assert!(names.contains(&"prisoners_dilemma"));
+/// ```
+///
+/// # Notes
+///
+/// Scores are given as `(Aleph, Beth)` pairs, with Aleph choosing the row and
+/// Beth choosing the column. Since scores are unsigned, games that are usually
+/// written with negative payoffs (such as Hawk-Dove) have been shifted so that
+/// the lowest payoff is zero. This does not change the strategic structure of
+/// the game.
+///
+/// # Available Presets
+///
+/// | Name | Choice Atlantis | Choice Olympus | AA | AO | OA | OO |
+/// | ---- | --------------- | -------------- | -- | -- | -- | -- |
+/// | `prisoners_dilemma` | cooperate | defect | (3, 3) | (0, 5) | (5, 0) | (1, 1) |
+/// | `chicken` | swerve | straight | (3, 3) | (1, 4) | (4, 1) | (0, 0) |
+/// | `hawk_dove` | fight | back_down | (0, 0) | (5, 1) | (1, 5) | (3, 3) |
+/// | `price_war` | raise_price | lower_price | (4, 4) | (1, 6) | (6, 1) | (2, 2) |
+/// | `battle_of_the_sexes` | opera | football | (3, 2) | (0, 0) | (0, 0) | (2, 3) |
+/// | `coordination` | go | stay | (2, 2) | (0, 0) | (0, 0) | (1, 1) |
+/// | `matching_pennies` | heads | tails | (2, 0) | (0, 2) | (0, 2) | (2, 0) |
+/// | `stag_hunt` | peace | war | (4, 4) | (0, 3) | (3, 0) | (2, 2) |
+/// | `leader` | lead | follow | (1, 1) | (4, 3) | (3, 4) | (2, 2) |
+///
+/// # See Also
+///
+/// * [`ChoiceNameOptions`]
+/// * [`GameOptionsBuilder::from_preset`](crate::GameOptionsBuilder::from_preset)
+#[derive(Debug, Clone, Copy)]
+pub struct GamePresets {}
+
+impl GamePresets {
+    /// The array of available presets.
+    const PRESETS: [(&'static str, GameOptions); 9] = [
+        (
+            "prisoners_dilemma",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(0),
+                [(3, 3), (0, 5), (5, 0), (1, 1)],
+            ),
+        ),
+        (
+            "chicken",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(1),
+                [(3, 3), (1, 4), (4, 1), (0, 0)],
+            ),
+        ),
+        (
+            "hawk_dove",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(3),
+                [(0, 0), (5, 1), (1, 5), (3, 3)],
+            ),
+        ),
+        (
+            "price_war",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(5),
+                [(4, 4), (1, 6), (6, 1), (2, 2)],
+            ),
+        ),
+        (
+            "battle_of_the_sexes",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(6),
+                [(3, 2), (0, 0), (0, 0), (2, 3)],
+            ),
+        ),
+        (
+            "coordination",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(7),
+                [(2, 2), (0, 0), (0, 0), (1, 1)],
+            ),
+        ),
+        (
+            "matching_pennies",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(8),
+                [(2, 0), (0, 2), (0, 2), (2, 0)],
+            ),
+        ),
+        (
+            "stag_hunt",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(11),
+                [(4, 4), (0, 3), (3, 0), (2, 2)],
+            ),
+        ),
+        (
+            "leader",
+            Self::preset(
+                ChoiceNameOptions::get_choice_pair(13),
+                [(1, 1), (4, 3), (3, 4), (2, 2)],
+            ),
+        ),
+    ];
+    const PRESETS_LENGTH: usize = Self::PRESETS.len();
+
+    /// Assemble a `GameOptions` from a choice pair and the four payoffs.
+    ///
+    /// The payoffs are given in the order `atlantis_atlantis`,
+    /// `atlantis_olympus`, `olympus_atlantis` and `olympus_olympus`.
+    const fn preset(
+        (choice_atlantis, choice_olympus): (&'static str, &'static str),
+        payoffs: [(u32, u32); 4],
+    ) -> GameOptions {
+        GameOptions {
+            choice_atlantis,
+            choice_olympus,
+            atlantis_atlantis: NumberPair::new(payoffs[0].0, payoffs[0].1),
+            atlantis_olympus: NumberPair::new(payoffs[1].0, payoffs[1].1),
+            olympus_atlantis: NumberPair::new(payoffs[2].0, payoffs[2].1),
+            olympus_olympus: NumberPair::new(payoffs[3].0, payoffs[3].1),
+        }
+    }
+
+    /// Get a preset by name.
+    ///
+    /// This function looks up a preset by its name. The lookup is case
+    /// insensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the preset to return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GamePresets,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_options = GamePresets::get_preset("Battle_Of_The_Sexes").unwrap();
+    ///
+    /// assert_eq!(game_options.choice_atlantis(), "opera");
+    /// assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(3, 2));
+    ///
+    /// assert!(GamePresets::get_preset("tic_tac_toe").is_none());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `GameOptions` for the preset, or `None` if there is no preset with
+    /// the given name.
+    ///
+    /// # See Also
+    ///
+    /// * [`preset_names`](GamePresets::preset_names)
+    /// * [`GameOptionsBuilder::from_preset`](crate::GameOptionsBuilder::from_preset)
+    #[must_use]
+    pub fn get_preset(name: &str) -> Option<GameOptions> {
+        Self::PRESETS
+            .iter()
+            .find(|(preset_name, _)| preset_name.eq_ignore_ascii_case(name))
+            .map(|(_, game_options)| *game_options)
+    }
+
+    /// Returns the list of presets.
+    ///
+    /// Each tuple in the array contains the name of the preset and the
+    /// `GameOptions` that it describes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GamePresets;
+    ///
+    /// let presets = GamePresets::presets();
+    ///
+    /// assert_eq!(presets[0].0, "prisoners_dilemma");
+    /// assert_eq!(presets[0].1.choice_atlantis(), "cooperate");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// This function returns an array of 9 tuples, one for each preset.
+    #[must_use]
+    pub const fn presets() -> [(&'static str, GameOptions); 9] {
+        Self::PRESETS
+    }
+
+    /// Returns the number of available presets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GamePresets;
+    ///
+    /// assert_eq!(GamePresets::presets_length(), 9);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `usize` which represents the length of the
+    /// `PRESETS` array.
+    #[must_use]
+    pub const fn presets_length() -> usize {
+        Self::PRESETS_LENGTH
+    }
+
+    /// Returns the names of the available presets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GamePresets;
+    ///
+    /// let names = GamePresets::preset_names();
+    ///
+    /// assert_eq!(names[1], "chicken");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// This function returns an array of 9 `&'static str` elements, one for
+    /// the name of each preset.
+    #[must_use]
+    pub fn preset_names() -> [&'static str; 9] {
+        Self::PRESETS.map(|preset| preset.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rstest::rstest;
+
+    use super::*;
+
+    // This test checks that every preset uses one of the known choice pairs
+    #[test]
+    fn test_presets_use_choice_name_options() {
+        let choice_pairs = ChoiceNameOptions::choice_pairs();
+
+        for (name, game_options) in GamePresets::presets() {
+            assert!(
+                choice_pairs.contains(&(
+                    game_options.choice_atlantis(),
+                    game_options.choice_olympus()
+                )),
+                "preset {name} does not use a known choice pair"
+            );
+        }
+    }
+
+    // This test checks that the preset names are unique
+    #[test]
+    fn test_preset_names_unique() {
+        let names = GamePresets::preset_names();
+
+        for (index, name) in names.iter().enumerate() {
+            assert!(!names[index + 1..].contains(name));
+        }
+    }
+
+    // This test checks that the lookup is case insensitive and returns the
+    // expected choice names
+    #[rstest]
+    #[case("prisoners_dilemma", "cooperate", "defect")]
+    #[case("Chicken", "swerve", "straight")]
+    #[case("HAWK_DOVE", "fight", "back_down")]
+    #[case("battle_of_the_sexes", "opera", "football")]
+    #[case("stag_hunt", "peace", "war")]
+    fn test_get_preset(#[case] name: &str, #[case] atlantis: &str, #[case] olympus: &str) {
+        let game_options = GamePresets::get_preset(name);
+
+        assert!(game_options.is_some());
+
+        let game_options = game_options.unwrap();
+
+        assert_eq!(game_options.choice_atlantis(), atlantis);
+
+        assert_eq!(game_options.choice_olympus(), olympus);
+    }
+
+    // This test checks that an unknown preset is not found
+    #[test]
+    fn test_get_preset_unknown() {
+        assert!(GamePresets::get_preset("rock_paper_scissors").is_none());
+    }
+
+    // This test checks that the Prisoner's Dilemma preset satisfies the
+    // T > R > P > S and 2R > T + S conditions for Aleph
+    #[test]
+    fn test_prisoners_dilemma_ordering() {
+        let game_options = GamePresets::get_preset("prisoners_dilemma").unwrap();
+
+        let reward = game_options.atlantis_atlantis().first();
+        let sucker = game_options.atlantis_olympus().first();
+        let temptation = game_options.olympus_atlantis().first();
+        let punishment = game_options.olympus_olympus().first();
+
+        assert!(temptation > reward);
+        assert!(reward > punishment);
+        assert!(punishment > sucker);
+        assert!(2 * reward > temptation + sucker);
+    }
+
+    // This test checks that every preset is symmetric except for the
+    // Battle of the Sexes and Matching Pennies, which are asymmetric by design
+    #[test]
+    fn test_presets_symmetric() {
+        for (name, game_options) in GamePresets::presets() {
+            if name == "battle_of_the_sexes" || name == "matching_pennies" {
+                continue;
+            }
+
+            assert_eq!(
+                game_options.atlantis_olympus().first(),
+                game_options.olympus_atlantis().second(),
+                "preset {name} is not symmetric"
+            );
+
+            assert_eq!(
+                game_options.atlantis_atlantis().first(),
+                game_options.atlantis_atlantis().second(),
+                "preset {name} is not symmetric"
+            );
+
+            assert_eq!(
+                game_options.olympus_olympus().first(),
+                game_options.olympus_olympus().second(),
+                "preset {name} is not symmetric"
+            );
+        }
+    }
+}
@@ -0,0 +1,327 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    Choice,
+    GameGrid,
+};
+
+/// A spatial game, played on a grid of cells that each make one choice.
+///
+/// In every step each cell plays the game against its eight neighbours and
+/// itself, then takes on the choice of whichever of them scored the most, as
+/// in the spatial prisoner's dilemma of Nowak and May. The grid wraps around
+/// at its edges.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     CustomizedBuilder,
+///     GameGrid,
+///     Lattice,
+/// # BuilderError,
+/// };
+///
+/// let game_grid = GameGrid::new(
+///     CustomizedBuilder::from_preset("prisoners_dilemma")?.build()?,
+/// );
+///
+/// let mut lattice = Lattice::new(9, 9, Choice::Atlantis);
+///
+/// lattice.set(4, 4, Choice::Olympus);
+/// lattice.step(&game_grid);
+///
+/// // The defector's neighbours follow it.
+/// assert_eq!(lattice.get(3, 5), Choice::Olympus);
+/// assert!(lattice.switched(3, 5));
+/// assert_eq!(lattice.get(2, 4), Choice::Atlantis);
+/// assert_eq!(lattice.generation(), 1);
+/// # Ok::<(), BuilderError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lattice {
+    width:      usize,
+    height:     usize,
+    cells:      Vec<Choice>,
+    previous:   Vec<Choice>,
+    generation: usize,
+}
+
+impl Lattice {
+    /// Creates a lattice in which every cell makes the same choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of cells in each row.
+    /// * `height` - The number of rows.
+    /// * `choice` - The choice of every cell.
+    #[must_use]
+    pub fn new(width: usize, height: usize, choice: Choice) -> Self {
+        Self::from_cells(width, height, vec![choice; width * height])
+    }
+
+    /// Creates a lattice in which each cell cooperates, choosing
+    /// [`Choice::Atlantis`], with the given probability.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of cells in each row.
+    /// * `height` - The number of rows.
+    /// * `cooperation` - The probability of a cell cooperating, from `0.0` to `1.0`.
+    /// * `seed` - The seed of the random choices, or `None` to seed from the operating system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cooperation` is not between `0.0` and `1.0`.
+    #[must_use]
+    pub fn random(width: usize, height: usize, cooperation: f64, seed: Option<u64>) -> Self {
+        let mut rng = seed.map_or_else(ChaCha12Rng::from_os_rng, ChaCha12Rng::seed_from_u64);
+
+        let cells = (0..width * height)
+            .map(|_| {
+                if rng.random_bool(cooperation) {
+                    Choice::Atlantis
+                } else {
+                    Choice::Olympus
+                }
+            })
+            .collect();
+
+        Self::from_cells(width, height, cells)
+    }
+
+    fn from_cells(width: usize, height: usize, cells: Vec<Choice>) -> Self {
+        Self {
+            width,
+            height,
+            previous: cells.clone(),
+            cells,
+            generation: 0,
+        }
+    }
+
+    /// Returns the number of cells in each row.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of steps taken.
+    #[must_use]
+    pub const fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "cell ({x}, {y}) is outside of a {}x{} lattice",
+            self.width,
+            self.height
+        );
+
+        y * self.width + x
+    }
+
+    /// Returns the choice of the cell in column `x` of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the lattice.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Choice {
+        self.cells[self.index(x, y)]
+    }
+
+    /// Returns the choice the cell made before the last step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the lattice.
+    #[must_use]
+    pub fn previous(&self, x: usize, y: usize) -> Choice {
+        self.previous[self.index(x, y)]
+    }
+
+    /// Returns whether the cell changed its choice in the last step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the lattice.
+    #[must_use]
+    pub fn switched(&self, x: usize, y: usize) -> bool {
+        self.get(x, y) != self.previous(x, y)
+    }
+
+    /// Sets the choice of a cell, as if it had always made it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the lattice.
+    pub fn set(&mut self, x: usize, y: usize, choice: Choice) {
+        let index = self.index(x, y);
+
+        self.cells[index] = choice;
+        self.previous[index] = choice;
+    }
+
+    /// Returns the share of cells that cooperate, from `0.0` to `1.0`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cooperation_rate(&self) -> f64 {
+        if self.cells.is_empty() {
+            return 0.0;
+        }
+
+        let cooperating = self
+            .cells
+            .iter()
+            .filter(|&&choice| choice == Choice::Atlantis)
+            .count();
+
+        cooperating as f64 / self.cells.len() as f64
+    }
+
+    /// Returns the indices of a cell and its eight neighbours.
+    fn neighbourhood(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = (index % self.width, index / self.width);
+
+        [self.height - 1, 0, 1].into_iter().flat_map(move |dy| {
+            [self.width - 1, 0, 1]
+                .into_iter()
+                .map(move |dx| ((y + dy) % self.height) * self.width + (x + dx) % self.width)
+        })
+    }
+
+    /// Plays one step of the game.
+    ///
+    /// Every cell scores the sum of its payoffs as Aleph against its
+    /// neighbours and itself, then copies the choice of the best scoring cell
+    /// around it, keeping its own choice on a tie.
+    pub fn step(&mut self, game_grid: &GameGrid) {
+        let scores: Vec<u64> = (0..self.cells.len())
+            .map(|index| {
+                self.neighbourhood(index)
+                    .map(|other| {
+                        u64::from(
+                            game_grid
+                                .return_score(self.cells[index], self.cells[other])
+                                .first(),
+                        )
+                    })
+                    .sum()
+            })
+            .collect();
+
+        let next = (0..self.cells.len())
+            .map(|index| {
+                let best = self.neighbourhood(index).fold(index, |best, other| {
+                    if scores[other] > scores[best] {
+                        other
+                    } else {
+                        best
+                    }
+                });
+
+                self.cells[best]
+            })
+            .collect();
+
+        self.previous = std::mem::replace(&mut self.cells, next);
+        self.generation += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        CustomizedBuilder,
+        RANDOM_SEED,
+    };
+
+    fn game_grid() -> GameGrid {
+        GameGrid::new(
+            CustomizedBuilder::from_preset("prisoners_dilemma")
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case(Choice::Atlantis)]
+    #[case(Choice::Olympus)]
+    fn test_uniform_lattice_stays(#[case] choice: Choice) {
+        let mut lattice = Lattice::new(5, 4, choice);
+
+        lattice.step(&game_grid());
+
+        assert_eq!(lattice, {
+            let mut expected = Lattice::new(5, 4, choice);
+            expected.generation = 1;
+            expected
+        });
+    }
+
+    #[test]
+    fn test_defector_spreads() {
+        let mut lattice = Lattice::new(7, 7, Choice::Atlantis);
+
+        lattice.set(0, 0, Choice::Olympus);
+        lattice.step(&game_grid());
+
+        // The block wraps around the corner of the grid.
+        for (x, y) in [
+            (6, 6),
+            (0, 6),
+            (1, 6),
+            (6, 0),
+            (0, 0),
+            (1, 0),
+            (6, 1),
+            (0, 1),
+            (1, 1),
+        ] {
+            assert_eq!(lattice.get(x, y), Choice::Olympus);
+        }
+
+        assert!(!lattice.switched(0, 0));
+        assert!(lattice.switched(6, 6));
+        assert_eq!(lattice.get(2, 2), Choice::Atlantis);
+        assert!((lattice.cooperation_rate() - 40.0 / 49.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_random() {
+        let lattice = Lattice::random(20, 10, 0.5, Some(RANDOM_SEED.0));
+
+        assert_eq!(lattice, Lattice::random(20, 10, 0.5, Some(RANDOM_SEED.0)));
+        assert!(lattice.cooperation_rate() > 0.3 && lattice.cooperation_rate() < 0.7);
+        assert_eq!(Lattice::random(3, 3, 0.0, None).cooperation_rate(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 0) is outside of a 3x2 lattice")]
+    fn test_outside() {
+        let _ = Lattice::new(3, 2, Choice::Atlantis).get(3, 0);
+    }
+}
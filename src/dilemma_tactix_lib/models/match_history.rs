@@ -0,0 +1,430 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::cmp::Ordering;
+
+use prettytable::{
+    Cell,
+    Row,
+    Table,
+};
+use serde::Serialize;
+
+use crate::{
+    Choice,
+    GameOptions,
+    NumberPair,
+    StrategyError,
+};
+
+/// One of the two players in a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Player {
+    /// Player Aleph, whose score is the first of each pair.
+    Aleph,
+    /// Player Beth, whose score is the second of each pair.
+    Beth,
+}
+
+/// The record of a player giving up a match.
+///
+/// A match is forfeited when a strategy fails, for example when an external
+/// strategy crashes or does not answer in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forfeit {
+    /// The player who forfeited.
+    pub player: Player,
+    /// The round in which the player forfeited.
+    pub round:  usize,
+    /// Why the player forfeited.
+    pub reason: StrategyError,
+}
+
+/// A single round of an iterated game.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     NumberPair,
+///     Round,
+/// };
+///
+/// let round = Round {
+///     aleph_choice: Choice::Atlantis,
+///     beth_choice:  Choice::Olympus,
+///     score:        NumberPair::new(0, 5),
+/// };
+///
+/// assert_eq!(round.score.second(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Round {
+    /// The choice made by Player Aleph.
+    pub aleph_choice: Choice,
+    /// The choice made by Player Beth.
+    pub beth_choice:  Choice,
+    /// The scores awarded to Aleph and Beth for this round.
+    pub score:        NumberPair,
+}
+
+/// The record of every round played in an iterated game.
+///
+/// The `MatchHistory` keeps the choices of each player in separate lists so
+/// that a [`Strategy`](crate::Strategy) can be handed its own history and its
+/// opponent's history without copying.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     MatchHistory,
+///     NumberPair,
+/// };
+///
+/// let mut history = MatchHistory::new();
+///
+/// history.record(Choice::Atlantis, Choice::Olympus, NumberPair::new(0, 5));
+/// history.record(Choice::Olympus, Choice::Olympus, NumberPair::new(1, 1));
+///
+/// assert_eq!(history.len(), 2);
+/// assert_eq!(history.aleph_total(), 1);
+/// assert_eq!(history.beth_total(), 6);
+/// assert_eq!(history.beth_choices(), &[Choice::Olympus, Choice::Olympus]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchHistory {
+    aleph_choices: Vec<Choice>,
+    beth_choices:  Vec<Choice>,
+    scores:        Vec<NumberPair>,
+    forfeit:       Option<Forfeit>,
+}
+
+impl MatchHistory {
+    /// Creates a new, empty `MatchHistory`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            aleph_choices: Vec::new(),
+            beth_choices:  Vec::new(),
+            scores:        Vec::new(),
+            forfeit:       None,
+        }
+    }
+
+    /// Records that a player forfeited the match.
+    ///
+    /// No more rounds should be recorded after a forfeit.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player who forfeited.
+    /// * `reason` - Why the player forfeited.
+    pub fn record_forfeit(&mut self, player: Player, reason: StrategyError) {
+        self.forfeit = Some(Forfeit {
+            player,
+            round: self.len() + 1,
+            reason,
+        });
+    }
+
+    /// Returns the forfeit that ended the match, if any.
+    #[must_use]
+    pub const fn forfeit(&self) -> Option<&Forfeit> {
+        self.forfeit.as_ref()
+    }
+
+    /// Returns the winner of the match, or `None` for a tie.
+    ///
+    /// A player who forfeited loses regardless of the scores.
+    #[must_use]
+    pub fn winner(&self) -> Option<Player> {
+        if let Some(forfeit) = &self.forfeit {
+            return Some(match forfeit.player {
+                Player::Aleph => Player::Beth,
+                Player::Beth => Player::Aleph,
+            });
+        }
+
+        match self.aleph_total().cmp(&self.beth_total()) {
+            Ordering::Greater => Some(Player::Aleph),
+            Ordering::Less => Some(Player::Beth),
+            Ordering::Equal => None,
+        }
+    }
+
+    /// Records a round.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph_choice` - The choice made by Player Aleph.
+    /// * `beth_choice` - The choice made by Player Beth.
+    /// * `score` - The scores awarded for the round.
+    pub fn record(&mut self, aleph_choice: Choice, beth_choice: Choice, score: NumberPair) {
+        self.aleph_choices.push(aleph_choice);
+        self.beth_choices.push(beth_choice);
+        self.scores.push(score);
+    }
+
+    /// Returns the number of rounds played.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Returns `true` if no rounds have been played.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    /// Returns the choices made by Player Aleph, in order.
+    #[must_use]
+    pub fn aleph_choices(&self) -> &[Choice] {
+        &self.aleph_choices
+    }
+
+    /// Returns the choices made by Player Beth, in order.
+    #[must_use]
+    pub fn beth_choices(&self) -> &[Choice] {
+        &self.beth_choices
+    }
+
+    /// Returns the scores awarded in each round, in order.
+    #[must_use]
+    pub fn scores(&self) -> &[NumberPair] {
+        &self.scores
+    }
+
+    /// Returns the round with the given index, if it has been played.
+    #[must_use]
+    pub fn round(&self, index: usize) -> Option<Round> {
+        Some(Round {
+            aleph_choice: *self.aleph_choices.get(index)?,
+            beth_choice:  *self.beth_choices.get(index)?,
+            score:        *self.scores.get(index)?,
+        })
+    }
+
+    /// Returns the most recent round, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<Round> {
+        self.len()
+            .checked_sub(1)
+            .and_then(|index| self.round(index))
+    }
+
+    /// Returns an iterator over the rounds played.
+    pub fn rounds(&self) -> impl Iterator<Item = Round> + '_ {
+        (0..self.len()).filter_map(|index| self.round(index))
+    }
+
+    /// Returns the cumulative score of Player Aleph.
+    #[must_use]
+    pub fn aleph_total(&self) -> u64 {
+        self.scores
+            .iter()
+            .map(|score| u64::from(score.first()))
+            .sum()
+    }
+
+    /// Returns the cumulative score of Player Beth.
+    #[must_use]
+    pub fn beth_total(&self) -> u64 {
+        self.scores
+            .iter()
+            .map(|score| u64::from(score.second()))
+            .sum()
+    }
+
+    /// Returns the choices made by a player, in order.
+    #[must_use]
+    pub fn choices(&self, player: Player) -> &[Choice] {
+        match player {
+            Player::Aleph => &self.aleph_choices,
+            Player::Beth => &self.beth_choices,
+        }
+    }
+
+    /// Returns the share of rounds in which a player cooperated, from `0.0`
+    /// to `1.0`, or `None` if no rounds have been played.
+    ///
+    /// Atlantis counts as cooperating, as it does in the classic games.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cooperation_rate(&self, player: Player) -> Option<f64> {
+        let choices = self.choices(player);
+
+        if choices.is_empty() {
+            return None;
+        }
+
+        let cooperated = choices
+            .iter()
+            .filter(|&&choice| choice == Choice::Atlantis)
+            .count();
+
+        Some(cooperated as f64 / choices.len() as f64)
+    }
+
+    /// Returns a player's latest choice and how many rounds in a row, up to
+    /// and including the latest one, they have made it.
+    ///
+    /// Returns `None` if no rounds have been played.
+    #[must_use]
+    pub fn streak(&self, player: Player) -> Option<(Choice, usize)> {
+        let choices = self.choices(player);
+        let &last = choices.last()?;
+
+        let length = choices
+            .iter()
+            .rev()
+            .take_while(|&&choice| choice == last)
+            .count();
+
+        Some((last, length))
+    }
+
+    /// Format the `MatchHistory` into a `Table`.
+    ///
+    /// Each row shows the round number, both choices using the names from
+    /// `game_options`, the scores for that round and the running totals.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_options` - The options the match is being played with.
+    ///
+    /// # Returns
+    ///
+    /// A `Table` representation of the `MatchHistory`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::make_grid()`](crate::GameGrid::make_grid)
+    #[must_use]
+    pub fn make_table(&self, game_options: &GameOptions) -> Table {
+        let mut table = Table::new();
+
+        table.set_titles(Row::new(vec![
+            Cell::new("Round"),
+            Cell::new("Aleph"),
+            Cell::new("Beth"),
+            Cell::new("Score"),
+            Cell::new("Total"),
+        ]));
+
+        let (mut aleph_total, mut beth_total) = (0_u64, 0_u64);
+
+        for (index, round) in self.rounds().enumerate() {
+            aleph_total += u64::from(round.score.first());
+            beth_total += u64::from(round.score.second());
+
+            table.add_row(Row::new(vec![
+                Cell::new(&(index + 1).to_string()),
+                Cell::new(game_options.choice_name(round.aleph_choice)),
+                Cell::new(game_options.choice_name(round.beth_choice)),
+                Cell::new(&round.score.to_string()),
+                Cell::new(&format!("({aleph_total}, {beth_total})")),
+            ]));
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> MatchHistory {
+        let mut history = MatchHistory::new();
+
+        history.record(Choice::Atlantis, Choice::Atlantis, NumberPair::new(3, 3));
+        history.record(Choice::Atlantis, Choice::Olympus, NumberPair::new(0, 5));
+        history.record(Choice::Olympus, Choice::Olympus, NumberPair::new(1, 1));
+
+        history
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let history = MatchHistory::new();
+
+        assert!(history.is_empty());
+        assert_eq!(history.last(), None);
+        assert_eq!(history.aleph_total(), 0);
+    }
+
+    #[test]
+    fn test_totals() {
+        let history = history();
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.aleph_total(), 4);
+        assert_eq!(history.beth_total(), 9);
+    }
+
+    #[test]
+    fn test_rounds() {
+        let history = history();
+
+        assert_eq!(
+            history.last(),
+            Some(Round {
+                aleph_choice: Choice::Olympus,
+                beth_choice:  Choice::Olympus,
+                score:        NumberPair::new(1, 1),
+            })
+        );
+
+        assert_eq!(history.rounds().count(), 3);
+        assert_eq!(history.round(3), None);
+    }
+
+    #[test]
+    fn test_winner() {
+        let mut history = history();
+
+        assert_eq!(history.winner(), Some(Player::Beth));
+
+        history.record_forfeit(Player::Beth, StrategyError::Timeout { round: 4 });
+
+        assert_eq!(history.winner(), Some(Player::Aleph));
+        assert_eq!(history.forfeit().map(|forfeit| forfeit.round), Some(4));
+        assert_eq!(MatchHistory::new().winner(), None);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut history = history();
+
+        assert_eq!(history.cooperation_rate(Player::Aleph), Some(2.0 / 3.0));
+        assert_eq!(history.cooperation_rate(Player::Beth), Some(1.0 / 3.0));
+        assert_eq!(history.streak(Player::Aleph), Some((Choice::Olympus, 1)));
+        assert_eq!(history.streak(Player::Beth), Some((Choice::Olympus, 2)));
+
+        history.record(Choice::Olympus, Choice::Atlantis, NumberPair::new(5, 0));
+
+        assert_eq!(history.streak(Player::Aleph), Some((Choice::Olympus, 2)));
+        assert_eq!(history.streak(Player::Beth), Some((Choice::Atlantis, 1)));
+        assert_eq!(history.choices(Player::Beth), history.beth_choices());
+
+        assert_eq!(MatchHistory::new().cooperation_rate(Player::Aleph), None);
+        assert_eq!(MatchHistory::new().streak(Player::Beth), None);
+    }
+
+    #[test]
+    fn test_make_table() {
+        let game_options = GameOptions::customized().build().unwrap();
+
+        let table = history().make_table(&game_options);
+
+        assert_eq!(table.len(), 3);
+        assert!(table.to_string().contains("(4, 9)"));
+        assert!(table.to_string().contains("defect"));
+    }
+}
@@ -5,17 +5,46 @@
 
 mod choice;
 mod choice_name_options;
+mod game_class;
 mod game_grid;
 mod game_option_builder;
 mod game_options;
+mod game_params;
+mod game_presets;
+mod lattice;
+mod match_history;
 mod number_pair;
+mod population;
+mod tournament;
+mod typed_builders;
 
 pub use choice::Choice;
 pub use choice_name_options::ChoiceNameOptions;
+pub use game_class::GameClass;
 pub use game_grid::GameGrid;
 pub use game_option_builder::{
     GameOptionsBuilder,
     GameOptionsBuilderTypes,
 };
 pub use game_options::GameOptions;
+pub use game_params::GameParams;
+pub use game_presets::GamePresets;
+pub use lattice::Lattice;
+pub use match_history::{
+    Forfeit,
+    MatchHistory,
+    Player,
+    Round,
+};
 pub use number_pair::NumberPair;
+pub use population::Population;
+pub use tournament::{
+    Pairing,
+    Standing,
+    Tournament,
+};
+pub use typed_builders::{
+    CustomizedBuilder,
+    RandomizedBuilder,
+    SeededBuilder,
+};
@@ -14,6 +14,12 @@ use rand::{
     SeedableRng,
 };
 use rand_chacha::ChaCha12Rng;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::BuilderError;
 
 /// A convenience struct to represent a pair of numbers.
 ///
@@ -51,7 +57,7 @@ use rand_chacha::ChaCha12Rng;
 ///
 /// assert!(number_pair.second() < 10);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct NumberPair {
     /// The first number in the pair.
     first:  u32,
@@ -112,7 +118,9 @@ impl NumberPair {
     ///
     /// # Panics
     ///
-    /// Panics if `min_value` is greater than `max_value`.
+    /// Panics if `min_value` is greater than `max_value`. Use
+    /// [`try_random`](NumberPair::try_random) if the bounds come from user
+    /// input.
     ///
     /// # Returns
     ///
@@ -120,16 +128,79 @@ impl NumberPair {
     /// `max_value` for each of `first` and `second`.
     #[must_use]
     pub fn random(min_value: u32, max_value: u32) -> Self {
+        match Self::try_random(min_value, max_value) {
+            Ok(number_pair) => number_pair,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Creates a new `NumberPair` struct with random values, checking the
+    /// bounds first.
+    ///
+    /// This is the non-panicking version of
+    /// [`random`](NumberPair::random).
+    ///
+    /// # Arguments
+    ///
+    /// * `min_value` - The minimum value for that can be assigned to a choice.
+    /// * `max_value` - The maximum value for that can be assigned to a choice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let number_pair = NumberPair::try_random(1, 10)?;
+    ///
+    /// assert!(number_pair.first() >= 1);
+    ///
+    /// assert!(number_pair.second() <= 10);
+    ///
+    /// assert!(NumberPair::try_random(10, 1).is_err());
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError::InvalidBounds`] if `min_value` is greater than
+    /// `max_value`.
+    ///
+    /// # Returns
+    ///
+    /// A new `NumberPair` struct with random values between `min_value` and
+    /// `max_value` for each of `first` and `second`.
+    pub fn try_random(min_value: u32, max_value: u32) -> Result<Self, BuilderError> {
         let mut rng = ChaCha12Rng::from_os_rng();
 
-        if min_value < max_value {
-            Self::new(
-                rng.random_range(min_value..=max_value),
-                rng.random_range(min_value..=max_value),
-            )
-        } else {
-            panic!("min_value must be less than max_value");
+        Self::try_random_with_rng(min_value, max_value, &mut rng)
+    }
+
+    /// Creates a new `NumberPair` struct with random values drawn from the
+    /// given random number generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError::InvalidBounds`] if `min_value` is greater than
+    /// `max_value`.
+    pub(crate) fn try_random_with_rng<R: Rng>(
+        min_value: u32,
+        max_value: u32,
+        rng: &mut R,
+    ) -> Result<Self, BuilderError> {
+        if min_value > max_value {
+            return Err(BuilderError::InvalidBounds {
+                min_value,
+                max_value,
+            });
         }
+
+        Ok(Self::new(
+            rng.random_range(min_value..=max_value),
+            rng.random_range(min_value..=max_value),
+        ))
     }
 
     /// Creates a new `NumberPair` struct with random values with a given seed.
@@ -170,13 +241,9 @@ impl NumberPair {
         // Create a new RNG seeded with the given seed.
         let mut rng = ChaCha12Rng::seed_from_u64(seed);
 
-        if min_value < max_value {
-            Self::new(
-                rng.random_range(min_value..=max_value),
-                rng.random_range(min_value..=max_value),
-            )
-        } else {
-            panic!("min_value must be less than max_value");
+        match Self::try_random_with_rng(min_value, max_value, &mut rng) {
+            Ok(number_pair) => number_pair,
+            Err(e) => panic!("{e}"),
         }
     }
 
@@ -469,4 +536,32 @@ mod tests {
         // Given a specific seed, the generated number should always be the same
         assert_eq!(number_pair_1, number_pair_2);
     }
+
+    #[rstest]
+    fn test_number_pair_try_random_equal_bounds() {
+        // Equal bounds are allowed and always produce the same value
+        let number_pair = NumberPair::try_random(5, 5);
+
+        assert_eq!(number_pair, Ok(NumberPair::new(5, 5)));
+    }
+
+    #[rstest]
+    fn test_number_pair_try_random_invalid_bounds() {
+        // A lower bound above the upper bound is reported instead of panicking
+        let number_pair = NumberPair::try_random(10, 1);
+
+        assert_eq!(
+            number_pair,
+            Err(BuilderError::InvalidBounds {
+                min_value: 10,
+                max_value: 1,
+            })
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "must not be greater than max_value")]
+    fn test_number_pair_random_invalid_bounds() {
+        let _ = NumberPair::random(10, 1);
+    }
 }
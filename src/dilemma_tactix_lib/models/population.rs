@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    GameGrid,
+    StrategyKind,
+};
+
+/// A population of strategies in which the better scoring ones spread.
+///
+/// Each strategy plays a match against every strategy, itself included, and
+/// its average score per round in each match makes up the payoff matrix of
+/// the population. The population then evolves by the discrete replicator
+/// dynamics: in each generation the share of every strategy grows in
+/// proportion to how well it scores against the population as it is.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     CustomizedBuilder,
+///     GameGrid,
+///     Population,
+///     StrategyKind,
+/// # BuilderError,
+/// };
+///
+/// let game_options =
+///     CustomizedBuilder::from_preset("prisoners_dilemma")?.build()?;
+///
+/// let population = Population::new(
+///     &GameGrid::new(game_options),
+///     &[StrategyKind::AlwaysCooperate, StrategyKind::AlwaysDefect],
+///     10,
+///     None,
+/// );
+///
+/// assert_eq!(population.payoff(1, 0), 5.0);
+///
+/// let generations = population.evolve(&[0.9, 0.1], 50);
+///
+/// assert_eq!(generations.len(), 51);
+/// assert!(generations[50][1] > 0.99);
+/// # Ok::<(), BuilderError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Population {
+    names:   Vec<String>,
+    payoffs: Vec<Vec<f64>>,
+}
+
+impl Population {
+    /// Plays every strategy against every strategy to find the payoffs of
+    /// the population.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The game to play.
+    /// * `kinds` - The strategies making up the population.
+    /// * `rounds` - The number of rounds in each match.
+    /// * `seed` - The seed of any random strategy.
+    ///
+    /// # Returns
+    ///
+    /// The `Population` of the given strategies.
+    #[must_use]
+    pub fn new(
+        game_grid: &GameGrid,
+        kinds: &[StrategyKind],
+        rounds: usize,
+        seed: Option<u64>,
+    ) -> Self {
+        let names = kinds.iter().map(|kind| kind.name().to_string()).collect();
+
+        let payoffs = kinds
+            .iter()
+            .map(|player| {
+                kinds
+                    .iter()
+                    .map(|opponent| {
+                        let history = game_grid.play_match(
+                            player.strategy(seed).as_mut(),
+                            opponent.strategy(seed).as_mut(),
+                            rounds,
+                        );
+
+                        average(history.aleph_total(), history.len())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { names, payoffs }
+    }
+
+    /// Returns the names of the strategies, in the order they were given.
+    #[must_use]
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the average score per round of `player` against `opponent`,
+    /// both given as indices into [`Population::names()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    #[must_use]
+    pub fn payoff(&self, player: usize, opponent: usize) -> f64 {
+        self.payoffs[player][opponent]
+    }
+
+    /// Returns the expected score per round of each strategy against a
+    /// population made up of the given shares.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer shares than strategies.
+    #[must_use]
+    pub fn fitness(&self, shares: &[f64]) -> Vec<f64> {
+        self.payoffs
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(shares)
+                    .map(|(payoff, share)| payoff * share)
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Returns the shares of the next generation.
+    ///
+    /// The shares are kept as they are if no strategy scores anything.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer shares than strategies.
+    #[must_use]
+    pub fn step(&self, shares: &[f64]) -> Vec<f64> {
+        let fitness = self.fitness(shares);
+
+        let mean: f64 = fitness.iter().zip(shares).map(|(f, share)| f * share).sum();
+
+        if mean <= 0.0 {
+            return shares.to_vec();
+        }
+
+        fitness
+            .iter()
+            .zip(shares)
+            .map(|(f, share)| share * f / mean)
+            .collect()
+    }
+
+    /// Returns the shares of every generation, starting with the given
+    /// ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `shares` - The share of each strategy in the first generation, summing to `1.0`.
+    /// * `generations` - The number of generations to evolve for.
+    ///
+    /// # Returns
+    ///
+    /// `generations + 1` lists of shares.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer shares than strategies.
+    #[must_use]
+    pub fn evolve(&self, shares: &[f64], generations: usize) -> Vec<Vec<f64>> {
+        let mut history = Vec::with_capacity(generations + 1);
+
+        history.push(shares.to_vec());
+
+        for _ in 0..generations {
+            let next = self.step(&history[history.len() - 1]);
+
+            history.push(next);
+        }
+
+        history
+    }
+}
+
+/// The average of `total` over `rounds`, or `0.0` without any rounds.
+#[allow(clippy::cast_precision_loss)]
+fn average(total: u64, rounds: usize) -> f64 {
+    if rounds == 0 {
+        0.0
+    } else {
+        total as f64 / rounds as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        CustomizedBuilder,
+        NumberPair,
+    };
+
+    fn population(kinds: &[StrategyKind]) -> Population {
+        let game_options = CustomizedBuilder::from_preset("prisoners_dilemma")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        Population::new(&GameGrid::new(game_options), kinds, 10, Some(7))
+    }
+
+    #[test]
+    fn test_payoffs() {
+        let population = population(&[
+            StrategyKind::AlwaysCooperate,
+            StrategyKind::AlwaysDefect,
+            StrategyKind::TitForTat,
+        ]);
+
+        assert_eq!(
+            population.names(),
+            ["always-cooperate", "always-defect", "tit-for-tat"]
+        );
+        assert_eq!(population.payoff(0, 0), 3.0);
+        assert_eq!(population.payoff(0, 1), 0.0);
+        assert_eq!(population.payoff(1, 1), 1.0);
+        assert_eq!(population.payoff(1, 2), 1.4);
+        assert_eq!(population.payoff(2, 1), 0.9);
+
+        let fitness = population.fitness(&[0.5, 0.5, 0.0]);
+
+        for (actual, expected) in fitness.into_iter().zip([1.5, 3.0, 1.95]) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+
+    #[rstest]
+    #[case(&[1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0])]
+    #[case(&[0.8, 0.1, 0.1])]
+    #[case(&[0.0, 0.5, 0.5])]
+    fn test_shares_sum_to_one(#[case] shares: &[f64]) {
+        let population = population(&[
+            StrategyKind::AlwaysCooperate,
+            StrategyKind::AlwaysDefect,
+            StrategyKind::TitForTat,
+        ]);
+
+        for generation in population.evolve(shares, 20) {
+            assert!((generation.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_evolve() {
+        let population = population(&[StrategyKind::AlwaysDefect, StrategyKind::TitForTat]);
+
+        // Enough tit for tat keeps defectors out, while too little is
+        // overrun by them.
+        let thriving = population.evolve(&[0.5, 0.5], 100);
+        let failing = population.evolve(&[0.99, 0.01], 100);
+
+        assert_eq!(thriving.len(), 101);
+        assert_eq!(thriving[0], vec![0.5, 0.5]);
+        assert!(thriving[100][1] > 0.99);
+        assert!(failing[100][0] > 0.99);
+    }
+
+    #[test]
+    fn test_no_payoffs() {
+        let game_options = CustomizedBuilder::new()
+            .atlantis_atlantis(NumberPair::new(0, 0))
+            .atlantis_olympus(NumberPair::new(0, 0))
+            .olympus_atlantis(NumberPair::new(0, 0))
+            .olympus_olympus(NumberPair::new(0, 0))
+            .build()
+            .unwrap();
+
+        let population = Population::new(
+            &GameGrid::new(game_options),
+            &[StrategyKind::AlwaysDefect, StrategyKind::TitForTat],
+            5,
+            None,
+        );
+
+        assert_eq!(population.step(&[0.3, 0.7]), vec![0.3, 0.7]);
+    }
+}
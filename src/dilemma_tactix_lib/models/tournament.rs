@@ -0,0 +1,386 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::cmp::Ordering;
+
+use serde::Serialize;
+
+use crate::{
+    GameGrid,
+    MatchHistory,
+    Player,
+    Strategy,
+};
+
+/// One match played in a [`Tournament`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pairing {
+    /// The index of the entrant who played as Aleph.
+    pub aleph:   usize,
+    /// The index of the entrant who played as Beth.
+    pub beth:    usize,
+    /// The rounds of the match.
+    pub history: MatchHistory,
+}
+
+/// How one entrant fared over a whole [`Tournament`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Standing {
+    /// The name of the entrant.
+    pub name:   String,
+    /// The entrant's score summed over every match it played.
+    pub total:  u64,
+    /// The number of matches the entrant won.
+    pub wins:   usize,
+    /// The number of matches that ended in a tie.
+    pub ties:   usize,
+    /// The number of matches the entrant lost.
+    pub losses: usize,
+}
+
+/// The results of a round-robin tournament between strategies.
+///
+/// Every entrant plays one match against every other entrant, with the entrant
+/// listed first playing as Aleph. Strategies are reset before each match, so
+/// the order of the matches does not matter.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     CustomizedBuilder,
+///     GameGrid,
+///     StrategyKind,
+///     Tournament,
+/// # BuilderError,
+/// };
+///
+/// let game_options =
+///     CustomizedBuilder::from_preset("prisoners_dilemma")?.build()?;
+///
+/// let mut entrants = vec![
+///     StrategyKind::TitForTat.strategy(None),
+///     StrategyKind::AlwaysDefect.strategy(None),
+///     StrategyKind::AlwaysCooperate.strategy(None),
+/// ];
+///
+/// let tournament = Tournament::round_robin(
+///     &GameGrid::new(game_options),
+///     &mut entrants,
+///     10,
+/// );
+///
+/// assert_eq!(tournament.pairings().len(), 3);
+///
+/// let standings = tournament.standings();
+///
+/// assert_eq!(standings[0].name, "always-defect");
+/// assert_eq!(standings[0].wins, 2);
+/// # Ok::<(), BuilderError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tournament {
+    names:    Vec<String>,
+    pairings: Vec<Pairing>,
+}
+
+impl Tournament {
+    /// Plays every entrant against every other entrant.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The game to play.
+    /// * `entrants` - The strategies taking part.
+    /// * `rounds` - The number of rounds in each match.
+    ///
+    /// # Returns
+    ///
+    /// The `Tournament` holding every match played.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::play_match()`](GameGrid::play_match)
+    pub fn round_robin(
+        game_grid: &GameGrid,
+        entrants: &mut [Box<dyn Strategy + Send>],
+        rounds: usize,
+    ) -> Self {
+        Self::round_robin_observed(game_grid, entrants, rounds, &mut |_, _, _| {})
+    }
+
+    /// Plays every entrant against every other entrant, reporting each round
+    /// as it is played.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The game to play.
+    /// * `entrants` - The strategies taking part.
+    /// * `rounds` - The number of rounds in each match.
+    /// * `observer` - Called with the indices of the entrants playing as Aleph and Beth and the
+    ///   history of their match so far, as described for [`GameGrid::play_match_observed()`].
+    ///
+    /// # Returns
+    ///
+    /// The `Tournament` holding every match played.
+    pub fn round_robin_observed(
+        game_grid: &GameGrid,
+        entrants: &mut [Box<dyn Strategy + Send>],
+        rounds: usize,
+        observer: &mut dyn FnMut(usize, usize, &MatchHistory),
+    ) -> Self {
+        let names = entrants
+            .iter()
+            .map(|entrant| entrant.name().to_string())
+            .collect();
+
+        let mut pairings = Vec::new();
+
+        for aleph in 0..entrants.len() {
+            for beth in aleph + 1..entrants.len() {
+                let (first, second) = entrants.split_at_mut(beth);
+
+                let history = game_grid.play_match_observed(
+                    first[aleph].as_mut(),
+                    second[0].as_mut(),
+                    rounds,
+                    &mut |history| observer(aleph, beth, history),
+                );
+
+                pairings.push(Pairing {
+                    aleph,
+                    beth,
+                    history,
+                });
+            }
+        }
+
+        Self { names, pairings }
+    }
+
+    /// Collects matches that were played elsewhere into a `Tournament`.
+    ///
+    /// This allows the standings of a tournament to be followed while it is
+    /// played, for example by collecting each match as
+    /// [`Tournament::round_robin_observed()`] reports it.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names of the entrants.
+    /// * `pairings` - The matches played so far, holding indices into `names`.
+    ///
+    /// # Returns
+    ///
+    /// The `Tournament` holding the given matches.
+    ///
+    /// # Panics
+    ///
+    /// [`Tournament::standings()`] panics if a pairing holds an index outside
+    /// of `names`.
+    #[must_use]
+    pub const fn from_pairings(names: Vec<String>, pairings: Vec<Pairing>) -> Self {
+        Self { names, pairings }
+    }
+
+    /// Returns the names of the entrants, in the order they were given.
+    #[must_use]
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns every match played, in the order they were played.
+    #[must_use]
+    pub fn pairings(&self) -> &[Pairing] {
+        &self.pairings
+    }
+
+    /// Returns the standings, best first.
+    ///
+    /// Entrants are ranked by their total score, then by the number of
+    /// matches they won, keeping the order they were given in when tied.
+    #[must_use]
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = self
+            .names
+            .iter()
+            .map(|name| Standing {
+                name:   name.clone(),
+                total:  0,
+                wins:   0,
+                ties:   0,
+                losses: 0,
+            })
+            .collect();
+
+        for pairing in &self.pairings {
+            standings[pairing.aleph].total += pairing.history.aleph_total();
+            standings[pairing.beth].total += pairing.history.beth_total();
+
+            match pairing.history.winner() {
+                Some(Player::Aleph) => {
+                    standings[pairing.aleph].wins += 1;
+                    standings[pairing.beth].losses += 1;
+                }
+                Some(Player::Beth) => {
+                    standings[pairing.beth].wins += 1;
+                    standings[pairing.aleph].losses += 1;
+                }
+                None => {
+                    standings[pairing.aleph].ties += 1;
+                    standings[pairing.beth].ties += 1;
+                }
+            }
+        }
+
+        standings.sort_by(|a, b| match b.total.cmp(&a.total) {
+            Ordering::Equal => b.wins.cmp(&a.wins),
+            ordering => ordering,
+        });
+
+        standings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        CustomizedBuilder,
+        StrategyKind,
+    };
+
+    fn game_grid() -> GameGrid {
+        GameGrid::new(
+            CustomizedBuilder::from_preset("prisoners_dilemma")
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn entrants(kinds: &[StrategyKind]) -> Vec<Box<dyn Strategy + Send>> {
+        kinds.iter().map(|kind| kind.strategy(Some(7))).collect()
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 0)]
+    #[case(2, 1)]
+    #[case(5, 10)]
+    fn test_every_pair_plays_once(#[case] count: usize, #[case] expected: usize) {
+        let kinds: Vec<StrategyKind> = StrategyKind::ALL
+            .iter()
+            .copied()
+            .cycle()
+            .take(count)
+            .collect();
+
+        let tournament = Tournament::round_robin(&game_grid(), &mut entrants(&kinds), 3);
+
+        assert_eq!(tournament.names().len(), count);
+        assert_eq!(tournament.pairings().len(), expected);
+        assert!(tournament
+            .pairings()
+            .iter()
+            .all(|pairing| pairing.aleph < pairing.beth && pairing.history.len() == 3));
+    }
+
+    #[test]
+    fn test_observer_sees_every_round() {
+        let mut seen = Vec::new();
+
+        let tournament = Tournament::round_robin_observed(
+            &game_grid(),
+            &mut entrants(&[
+                StrategyKind::TitForTat,
+                StrategyKind::Pavlov,
+                StrategyKind::Grudger,
+            ]),
+            4,
+            &mut |aleph, beth, history| seen.push((aleph, beth, history.len())),
+        );
+
+        assert_eq!(seen.len(), 12);
+        assert_eq!(seen[0], (0, 1, 1));
+        assert_eq!(seen[11], (1, 2, 4));
+        assert_eq!(tournament.pairings()[2].history.len(), 4);
+    }
+
+    #[test]
+    fn test_standings() {
+        let tournament = Tournament::round_robin(
+            &game_grid(),
+            &mut entrants(&[
+                StrategyKind::TitForTat,
+                StrategyKind::TitForTat,
+                StrategyKind::AlwaysDefect,
+            ]),
+            5,
+        );
+
+        let standings = tournament.standings();
+
+        // Tit for tat scores 15 against itself and 4 against always defect,
+        // which scores 9 against each tit for tat.
+        assert_eq!(
+            standings,
+            vec![
+                Standing {
+                    name:   "tit-for-tat".to_string(),
+                    total:  19,
+                    wins:   0,
+                    ties:   1,
+                    losses: 1,
+                },
+                Standing {
+                    name:   "tit-for-tat".to_string(),
+                    total:  19,
+                    wins:   0,
+                    ties:   1,
+                    losses: 1,
+                },
+                Standing {
+                    name:   "always-defect".to_string(),
+                    total:  18,
+                    wins:   2,
+                    ties:   0,
+                    losses: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_pairings() {
+        let tournament = Tournament::round_robin(
+            &game_grid(),
+            &mut entrants(&[
+                StrategyKind::TitForTat,
+                StrategyKind::AlwaysDefect,
+                StrategyKind::AlwaysCooperate,
+            ]),
+            5,
+        );
+
+        let rebuilt =
+            Tournament::from_pairings(tournament.names().to_vec(), tournament.pairings().to_vec());
+
+        assert_eq!(rebuilt, tournament);
+
+        // Only always defect against always cooperate has been played.
+        let partial = Tournament::from_pairings(
+            tournament.names().to_vec(),
+            tournament.pairings()[2..].to_vec(),
+        );
+
+        let standings = partial.standings();
+
+        assert_eq!(standings[0].name, "always-defect");
+        assert_eq!(standings[0].total, 25);
+        assert_eq!(standings[1].name, "tit-for-tat");
+        assert_eq!(standings[1].total, 0);
+    }
+}
@@ -0,0 +1,484 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    BuilderError,
+    GameOptions,
+    GameOptionsBuilder,
+    GameOptionsBuilderTypes,
+    NumberPair,
+};
+
+/// A compile-time checked builder for a randomized [`GameOptions`].
+///
+/// This builder only exposes the fields that are valid for the
+/// [`GameOptionsBuilderTypes::Randomized`] path of [`GameOptionsBuilder`], so
+/// setting a field that does not apply is a compile error instead of a runtime
+/// [`BuilderError`].
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     BuilderError,
+///     GameOptions,
+/// };
+///
+/// let game_options = GameOptions::randomized()
+///     .min_value(1)
+///     .max_value(10)
+///     .choice_atlantis("cooperate")
+///     .choice_olympus("defect")
+///     .build()?;
+///
+/// assert_eq!(game_options.choice_atlantis(), "cooperate");
+/// assert!(game_options.atlantis_atlantis().first() <= 10);
+/// # Ok::<(), BuilderError>(())
+/// ```
+///
+/// Scores can not be set on a randomized builder:
+///
+/// ```compile_fail
+/// use dilemma_tactix_lib::{
+///     GameOptions,
+///     NumberPair,
+/// };
+///
+/// let builder = GameOptions::randomized().atlantis_atlantis(NumberPair::new(1, 1));
+/// ```
+///
+/// # See Also
+///
+/// * [`GameOptions::randomized()`](crate::GameOptions::randomized)
+/// * [`SeededBuilder`]
+/// * [`CustomizedBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomizedBuilder {
+    inner: GameOptionsBuilder,
+}
+
+impl RandomizedBuilder {
+    /// Creates a new `RandomizedBuilder`.
+    ///
+    /// # Returns
+    ///
+    /// A new `RandomizedBuilder` with no fields set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: GameOptionsBuilder::new(GameOptionsBuilderTypes::Randomized),
+        }
+    }
+
+    /// Sets the minimum value for the random scores.
+    #[must_use]
+    pub const fn min_value(mut self, min_value: u32) -> Self {
+        self.inner.min_value = Some(min_value);
+        self
+    }
+
+    /// Sets the maximum value for the random scores.
+    #[must_use]
+    pub const fn max_value(mut self, max_value: u32) -> Self {
+        self.inner.max_value = Some(max_value);
+        self
+    }
+
+    /// Sets the first choice available to players.
+    #[must_use]
+    pub const fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Self {
+        self.inner.choice_atlantis = Some(choice_atlantis);
+        self
+    }
+
+    /// Sets the second choice available to players.
+    #[must_use]
+    pub const fn choice_olympus(mut self, choice_olympus: &'static str) -> Self {
+        self.inner.choice_olympus = Some(choice_olympus);
+        self
+    }
+
+    /// Builds the `GameOptions` struct.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the bounds or the choice names
+    /// are invalid. See [`GameOptionsBuilder::build()`].
+    pub fn build(self) -> Result<GameOptions, BuilderError> {
+        self.inner.build()
+    }
+}
+
+impl Default for RandomizedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<RandomizedBuilder> for GameOptionsBuilder {
+    fn from(builder: RandomizedBuilder) -> Self {
+        builder.inner
+    }
+}
+
+/// A compile-time checked builder for a seeded [`GameOptions`].
+///
+/// The seed is taken when the builder is created, so a `SeededBuilder` can
+/// never be built without one. Building the same builder twice gives the same
+/// `GameOptions`.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     BuilderError,
+///     GameOptions,
+/// };
+///
+/// let builder = GameOptions::seeded(2024).min_value(1).max_value(10);
+///
+/// assert_eq!(builder.build()?, builder.build()?);
+/// # Ok::<(), BuilderError>(())
+/// ```
+///
+/// Scores can not be set on a seeded builder:
+///
+/// ```compile_fail
+/// use dilemma_tactix_lib::{
+///     GameOptions,
+///     NumberPair,
+/// };
+///
+/// let builder = GameOptions::seeded(2024).olympus_olympus(NumberPair::new(1, 1));
+/// ```
+///
+/// # See Also
+///
+/// * [`GameOptions::seeded()`](crate::GameOptions::seeded)
+/// * [`RandomizedBuilder`]
+/// * [`CustomizedBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeededBuilder {
+    inner: GameOptionsBuilder,
+}
+
+impl SeededBuilder {
+    /// Creates a new `SeededBuilder` with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A new `SeededBuilder` with only the seed set.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        let mut inner = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
+        inner.seed = Some(seed);
+
+        Self { inner }
+    }
+
+    /// Replaces the seed for the random number generator.
+    #[must_use]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.inner.seed = Some(seed);
+        self
+    }
+
+    /// Sets the minimum value for the random scores.
+    #[must_use]
+    pub const fn min_value(mut self, min_value: u32) -> Self {
+        self.inner.min_value = Some(min_value);
+        self
+    }
+
+    /// Sets the maximum value for the random scores.
+    #[must_use]
+    pub const fn max_value(mut self, max_value: u32) -> Self {
+        self.inner.max_value = Some(max_value);
+        self
+    }
+
+    /// Sets the first choice available to players.
+    #[must_use]
+    pub const fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Self {
+        self.inner.choice_atlantis = Some(choice_atlantis);
+        self
+    }
+
+    /// Sets the second choice available to players.
+    #[must_use]
+    pub const fn choice_olympus(mut self, choice_olympus: &'static str) -> Self {
+        self.inner.choice_olympus = Some(choice_olympus);
+        self
+    }
+
+    /// Builds the `GameOptions` struct.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the bounds or the choice names
+    /// are invalid. See [`GameOptionsBuilder::build()`].
+    pub fn build(self) -> Result<GameOptions, BuilderError> {
+        self.inner.build()
+    }
+}
+
+impl From<SeededBuilder> for GameOptionsBuilder {
+    fn from(builder: SeededBuilder) -> Self {
+        builder.inner
+    }
+}
+
+/// A compile-time checked builder for a fully customized [`GameOptions`].
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     BuilderError,
+///     GameOptions,
+///     NumberPair,
+/// };
+///
+/// let game_options = GameOptions::customized()
+///     .choice_atlantis("cooperate")
+///     .choice_olympus("defect")
+///     .atlantis_atlantis(NumberPair::new(3, 3))
+///     .atlantis_olympus(NumberPair::new(0, 5))
+///     .olympus_atlantis(NumberPair::new(5, 0))
+///     .olympus_olympus(NumberPair::new(1, 1))
+///     .build()?;
+///
+/// assert_eq!(game_options.olympus_atlantis(), NumberPair::new(5, 0));
+/// # Ok::<(), BuilderError>(())
+/// ```
+///
+/// Random bounds can not be set on a customized builder:
+///
+/// ```compile_fail
+/// use dilemma_tactix_lib::GameOptions;
+///
+/// let builder = GameOptions::customized().min_value(1);
+/// ```
+///
+/// # See Also
+///
+/// * [`GameOptions::customized()`](crate::GameOptions::customized)
+/// * [`RandomizedBuilder`]
+/// * [`SeededBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomizedBuilder {
+    inner: GameOptionsBuilder,
+}
+
+impl CustomizedBuilder {
+    /// Creates a new `CustomizedBuilder`.
+    ///
+    /// # Returns
+    ///
+    /// A new `CustomizedBuilder` with no fields set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized),
+        }
+    }
+
+    /// Creates a new `CustomizedBuilder` from a named preset.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the preset, e.g. `"chicken"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     BuilderError,
+    ///     CustomizedBuilder,
+    /// };
+    ///
+    /// let game_options = CustomizedBuilder::from_preset("stag_hunt")?.build()?;
+    ///
+    /// assert_eq!(game_options.choice_atlantis(), "peace");
+    /// # Ok::<(), BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError::UnknownPreset`] if there is no preset with the
+    /// given name.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptionsBuilder::from_preset()`]
+    pub fn from_preset(name: &str) -> Result<Self, BuilderError> {
+        Ok(Self {
+            inner: GameOptionsBuilder::from_preset(name)?,
+        })
+    }
+
+    /// Sets the first choice available to players.
+    #[must_use]
+    pub const fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Self {
+        self.inner.choice_atlantis = Some(choice_atlantis);
+        self
+    }
+
+    /// Sets the second choice available to players.
+    #[must_use]
+    pub const fn choice_olympus(mut self, choice_olympus: &'static str) -> Self {
+        self.inner.choice_olympus = Some(choice_olympus);
+        self
+    }
+
+    /// Sets the score for when both players choose Atlantis.
+    #[must_use]
+    pub const fn atlantis_atlantis(mut self, atlantis_atlantis: NumberPair) -> Self {
+        self.inner.atlantis_atlantis = Some(atlantis_atlantis);
+        self
+    }
+
+    /// Sets the score for when Aleph chooses Atlantis and Beth chooses
+    /// Olympus.
+    #[must_use]
+    pub const fn atlantis_olympus(mut self, atlantis_olympus: NumberPair) -> Self {
+        self.inner.atlantis_olympus = Some(atlantis_olympus);
+        self
+    }
+
+    /// Sets the score for when Aleph chooses Olympus and Beth chooses
+    /// Atlantis.
+    #[must_use]
+    pub const fn olympus_atlantis(mut self, olympus_atlantis: NumberPair) -> Self {
+        self.inner.olympus_atlantis = Some(olympus_atlantis);
+        self
+    }
+
+    /// Sets the score for when both players choose Olympus.
+    #[must_use]
+    pub const fn olympus_olympus(mut self, olympus_olympus: NumberPair) -> Self {
+        self.inner.olympus_olympus = Some(olympus_olympus);
+        self
+    }
+
+    /// Builds the `GameOptions` struct.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the choice names are empty or
+    /// identical. See [`GameOptionsBuilder::build()`].
+    pub fn build(self) -> Result<GameOptions, BuilderError> {
+        self.inner.build()
+    }
+}
+
+impl Default for CustomizedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<CustomizedBuilder> for GameOptionsBuilder {
+    fn from(builder: CustomizedBuilder) -> Self {
+        builder.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_randomized_builder() -> Result<(), BuilderError> {
+        let game_options = RandomizedBuilder::new()
+            .min_value(2)
+            .max_value(4)
+            .choice_atlantis("go")
+            .choice_olympus("stay")
+            .build()?;
+
+        assert_eq!(game_options.choice_atlantis(), "go");
+        assert_eq!(game_options.choice_olympus(), "stay");
+        assert!(game_options.atlantis_atlantis().first() >= 2);
+        assert!(game_options.olympus_olympus().second() <= 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_randomized_builder_invalid_bounds() {
+        let game_options = RandomizedBuilder::new().min_value(4).max_value(2).build();
+
+        assert_eq!(
+            game_options,
+            Err(BuilderError::InvalidBounds {
+                min_value: 4,
+                max_value: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_seeded_builder_matches_untyped() -> Result<(), BuilderError> {
+        let typed = SeededBuilder::new(42).min_value(1).max_value(10).build()?;
+        let untyped = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded)
+            .seed(42)?
+            .min_value(1)?
+            .max_value(10)?
+            .build()?;
+
+        assert_eq!(typed, untyped);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seeded_builder_reseed() -> Result<(), BuilderError> {
+        let builder = SeededBuilder::new(42);
+
+        assert_eq!(builder.build()?, SeededBuilder::new(7).seed(42).build()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_customized_builder() -> Result<(), BuilderError> {
+        let game_options = CustomizedBuilder::new()
+            .atlantis_atlantis(NumberPair::new(1, 2))
+            .olympus_olympus(NumberPair::new(3, 4))
+            .build()?;
+
+        assert_eq!(game_options.choice_atlantis(), "cooperate");
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(1, 2));
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_customized_builder_empty_choice() {
+        let game_options = CustomizedBuilder::new().choice_atlantis("").build();
+
+        assert_eq!(
+            game_options,
+            Err(BuilderError::EmptyChoiceName("choice_atlantis"))
+        );
+    }
+
+    #[test]
+    fn test_into_untyped_builder() {
+        let builder: GameOptionsBuilder = CustomizedBuilder::new().into();
+
+        assert_eq!(builder.builder_type, GameOptionsBuilderTypes::Customized);
+
+        let builder: GameOptionsBuilder = SeededBuilder::new(1).into();
+
+        assert_eq!(builder.seed, Some(1));
+    }
+}
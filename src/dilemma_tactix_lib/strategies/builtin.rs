@@ -0,0 +1,260 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    Choice,
+    Strategy,
+};
+
+/// A strategy that always chooses [`Choice::Atlantis`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlwaysCooperate;
+
+impl Strategy for AlwaysCooperate {
+    fn name(&self) -> &str {
+        "always-cooperate"
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], _opponent_history: &[Choice]) -> Choice {
+        Choice::Atlantis
+    }
+}
+
+/// A strategy that always chooses [`Choice::Olympus`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlwaysDefect;
+
+impl Strategy for AlwaysDefect {
+    fn name(&self) -> &str {
+        "always-defect"
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], _opponent_history: &[Choice]) -> Choice {
+        Choice::Olympus
+    }
+}
+
+/// A strategy that chooses either move with equal probability.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     RandomStrategy,
+///     Strategy,
+/// };
+///
+/// let mut first = RandomStrategy::seeded(2024);
+/// let mut second = RandomStrategy::seeded(2024);
+///
+/// assert_eq!(first.next_choice(&[], &[]), second.next_choice(&[], &[]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomStrategy {
+    rng: ChaCha12Rng,
+}
+
+impl RandomStrategy {
+    /// Creates a new `RandomStrategy` seeded from the operating system.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rng: ChaCha12Rng::from_os_rng(),
+        }
+    }
+
+    /// Creates a new `RandomStrategy` with a fixed seed, so that the same
+    /// sequence of moves is produced every time.
+    #[must_use]
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for RandomStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Strategy for RandomStrategy {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], _opponent_history: &[Choice]) -> Choice {
+        if self.rng.random_bool(0.5) {
+            Choice::Atlantis
+        } else {
+            Choice::Olympus
+        }
+    }
+}
+
+/// A strategy that cooperates first and then copies the opponent's last move.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TitForTat;
+
+impl Strategy for TitForTat {
+    fn name(&self) -> &str {
+        "tit-for-tat"
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        opponent_history.last().copied().unwrap_or(Choice::Atlantis)
+    }
+}
+
+/// A strategy that only defects after the opponent has defected twice in a
+/// row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TitForTwoTats;
+
+impl Strategy for TitForTwoTats {
+    fn name(&self) -> &str {
+        "tit-for-two-tats"
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        match opponent_history {
+            [.., Choice::Olympus, Choice::Olympus] => Choice::Olympus,
+            _ => Choice::Atlantis,
+        }
+    }
+}
+
+/// A strategy that cooperates until the opponent defects once, and then
+/// defects for the rest of the match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Grudger {
+    betrayed: bool,
+}
+
+impl Grudger {
+    /// Creates a new `Grudger` that has not been betrayed yet.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { betrayed: false }
+    }
+}
+
+impl Strategy for Grudger {
+    fn name(&self) -> &str {
+        "grudger"
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        self.betrayed = self.betrayed || opponent_history.contains(&Choice::Olympus);
+
+        if self.betrayed {
+            Choice::Olympus
+        } else {
+            Choice::Atlantis
+        }
+    }
+
+    fn reset(&mut self) {
+        self.betrayed = false;
+    }
+}
+
+/// A win-stay, lose-shift strategy.
+///
+/// Cooperates first. Afterwards, it repeats its previous move if both players
+/// made the same choice in the last round, and switches otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Pavlov;
+
+impl Strategy for Pavlov {
+    fn name(&self) -> &str {
+        "pavlov"
+    }
+
+    fn next_choice(&mut self, own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        match (own_history.last(), opponent_history.last()) {
+            (Some(own), Some(opponent)) if own == opponent => Choice::Atlantis,
+            (Some(_), Some(_)) => Choice::Olympus,
+            _ => Choice::Atlantis,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const A: Choice = Choice::Atlantis;
+    const O: Choice = Choice::Olympus;
+
+    #[rstest]
+    #[case(&[], A)]
+    #[case(&[O], O)]
+    #[case(&[O, A], A)]
+    fn test_tit_for_tat(#[case] opponent: &[Choice], #[case] expected: Choice) {
+        assert_eq!(TitForTat.next_choice(&[], opponent), expected);
+    }
+
+    #[rstest]
+    #[case(&[], A)]
+    #[case(&[O], A)]
+    #[case(&[O, O], O)]
+    #[case(&[O, O, A], A)]
+    fn test_tit_for_two_tats(#[case] opponent: &[Choice], #[case] expected: Choice) {
+        assert_eq!(TitForTwoTats.next_choice(&[], opponent), expected);
+    }
+
+    #[rstest]
+    #[case(&[], &[], A)]
+    #[case(&[A], &[A], A)]
+    #[case(&[A], &[O], O)]
+    #[case(&[O], &[O], A)]
+    #[case(&[O], &[A], O)]
+    fn test_pavlov(#[case] own: &[Choice], #[case] opponent: &[Choice], #[case] expected: Choice) {
+        assert_eq!(Pavlov.next_choice(own, opponent), expected);
+    }
+
+    #[test]
+    fn test_grudger_holds_grudge() {
+        let mut grudger = Grudger::new();
+
+        assert_eq!(grudger.next_choice(&[], &[]), A);
+        assert_eq!(grudger.next_choice(&[A], &[O]), O);
+        // The grudge is kept even if it is handed a clean history
+        assert_eq!(grudger.next_choice(&[], &[A]), O);
+
+        grudger.reset();
+
+        assert_eq!(grudger.next_choice(&[], &[A]), A);
+    }
+
+    #[test]
+    fn test_always() {
+        assert_eq!(AlwaysCooperate.next_choice(&[O], &[O]), A);
+        assert_eq!(AlwaysDefect.next_choice(&[A], &[A]), O);
+    }
+
+    #[test]
+    fn test_random_seeded_is_repeatable() {
+        let mut first = RandomStrategy::seeded(crate::RANDOM_SEED.0);
+        let mut second = RandomStrategy::seeded(crate::RANDOM_SEED.0);
+
+        let first: Vec<Choice> = (0..20).map(|_| first.next_choice(&[], &[])).collect();
+        let second: Vec<Choice> = (0..20).map(|_| second.next_choice(&[], &[])).collect();
+
+        assert_eq!(first, second);
+        assert!(first.contains(&A));
+        assert!(first.contains(&O));
+    }
+}
@@ -0,0 +1,454 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io::{
+        BufRead,
+        BufReader,
+        Write,
+    },
+    process::{
+        Child,
+        ChildStdin,
+        Command,
+        Stdio,
+    },
+    sync::mpsc::{
+        self,
+        Receiver,
+        RecvTimeoutError,
+    },
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    Choice,
+    GameOptions,
+    Strategy,
+    StrategyError,
+};
+
+/// A message sent to an external strategy, one per line.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Request<'a> {
+    Start {
+        game: &'a GameOptions,
+    },
+    Move {
+        round:         usize,
+        opponent_last: Option<Choice>,
+    },
+}
+
+/// The answer an external strategy gives to a `move` request.
+#[derive(Debug, Deserialize)]
+struct Response {
+    choice: Choice,
+}
+
+/// A strategy played by a separate program.
+///
+/// The program is started once and talks to the engine over its standard
+/// input and output, one JSON object per line:
+///
+/// * When a match starts, it is sent `{"type":"start","game":{...}}` with the payoffs and choice
+///   names of the game.
+/// * Every round, it is sent `{"type":"move","round":1,"opponent_last":null}`, where
+///   `opponent_last` is the opponent's previous choice (`"Atlantis"` or `"Olympus"`), and must
+///   answer with `{"choice":"Atlantis"}` or `{"choice":"Olympus"}`.
+///
+/// Anything the program writes to standard error is passed through.
+///
+/// If the program does not answer within the move timeout, exits, or answers
+/// with something other than a valid move, the strategy fails. It then keeps
+/// choosing [`Choice::Atlantis`] and reports the failure through
+/// [`Strategy::error`], which forfeits the match when played with
+/// [`GameGrid::play_match`](crate::GameGrid::play_match).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use dilemma_tactix_lib::{
+///     ExternalStrategy,
+///     GameGrid,
+///     GameOptions,
+///     TitForTat,
+/// };
+///
+/// let game_options = GameOptions::customized().build()?;
+///
+/// let mut bot = ExternalStrategy::spawn(
+///     "python3",
+///     &["my_bot.py"],
+///     game_options,
+///     Duration::from_secs(1),
+/// )?;
+///
+/// let history =
+///     GameGrid::new(game_options).play_match(&mut TitForTat, &mut bot, 10);
+///
+/// if let Some(forfeit) = history.forfeit() {
+///     println!("The bot forfeited: {}", forfeit.reason);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ExternalStrategy {
+    name:         String,
+    game_options: GameOptions,
+    timeout:      Duration,
+    child:        Child,
+    stdin:        Option<ChildStdin>,
+    responses:    Receiver<String>,
+    round:        usize,
+    error:        Option<StrategyError>,
+}
+
+impl ExternalStrategy {
+    /// The move timeout used when none is given.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Starts `program` with `args` and sends it the game definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The program to run.
+    /// * `args` - The arguments to pass to the program.
+    /// * `game_options` - The game the program will be playing.
+    /// * `timeout` - How long the program may take to answer each move.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::Spawn`] if the program could not be started,
+    /// or [`StrategyError::Io`] if the game definition could not be sent.
+    pub fn spawn(
+        program: &str,
+        args: &[&str],
+        game_options: GameOptions,
+        timeout: Duration,
+    ) -> Result<Self, StrategyError> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| StrategyError::Spawn(format!("{program}: {e}")))?;
+
+        let stdin = child.stdin.take();
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| StrategyError::Spawn(format!("{program}: no stdout")))?;
+
+        // Reading happens on its own thread so that a silent program can be
+        // timed out instead of blocking the match forever.
+        let (sender, responses) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut strategy = Self {
+            name: program.to_string(),
+            game_options,
+            timeout,
+            child,
+            stdin,
+            responses,
+            round: 0,
+            error: None,
+        };
+
+        strategy.start()?;
+
+        Ok(strategy)
+    }
+
+    fn start(&mut self) -> Result<(), StrategyError> {
+        let game_options = self.game_options;
+
+        self.round = 0;
+
+        self.send(&Request::Start {
+            game: &game_options,
+        })
+    }
+
+    fn send(&mut self, request: &Request) -> Result<(), StrategyError> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .ok_or_else(|| StrategyError::Io("stdin is closed".to_string()))?;
+
+        let line = serde_json::to_string(request).map_err(|e| StrategyError::Io(e.to_string()))?;
+
+        writeln!(stdin, "{line}")
+            .and_then(|()| stdin.flush())
+            .map_err(|e| StrategyError::Io(e.to_string()))
+    }
+
+    fn receive(&mut self) -> Result<Choice, StrategyError> {
+        let round = self.round;
+        let deadline = Instant::now() + self.timeout;
+
+        let line = match self.responses.recv_timeout(self.timeout) {
+            Ok(line) => line,
+            Err(RecvTimeoutError::Timeout) => return Err(StrategyError::Timeout { round }),
+            Err(RecvTimeoutError::Disconnected) => {
+                let status = self.exit_status(deadline);
+
+                return Err(StrategyError::Crashed { round, status });
+            }
+        };
+
+        serde_json::from_str::<Response>(&line)
+            .map(|response| response.choice)
+            .map_err(|_| StrategyError::InvalidResponse {
+                round,
+                response: line,
+            })
+    }
+
+    /// Describes how the program exited, after it closed its output.
+    ///
+    /// A program can close its output and carry on running, so it is only
+    /// waited for until `deadline`, then killed.
+    fn exit_status(&mut self, deadline: Instant) -> String {
+        loop {
+            match self.child.try_wait() {
+                Ok(Some(status)) => return status.to_string(),
+                Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(10)),
+                Ok(None) => break,
+                Err(e) => return e.to_string(),
+            }
+        }
+
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+
+        "closed its output without exiting, and was killed".to_string()
+    }
+
+    fn play(&mut self, opponent_last: Option<Choice>) -> Result<Choice, StrategyError> {
+        self.round += 1;
+
+        let sent = self.send(&Request::Move {
+            round: self.round,
+            opponent_last,
+        });
+
+        // Writing to a program that has exited fails, but the exit is the
+        // more useful thing to report.
+        if let (Err(e), Ok(Some(status))) = (&sent, self.child.try_wait()) {
+            return Err(StrategyError::Crashed {
+                round:  self.round,
+                status: format!("{status}; {e}"),
+            });
+        }
+
+        sent?;
+
+        self.receive()
+    }
+}
+
+impl Strategy for ExternalStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_choice(&mut self, _own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        if self.error.is_some() {
+            return Choice::Atlantis;
+        }
+
+        match self.play(opponent_history.last().copied()) {
+            Ok(choice) => choice,
+            Err(e) => {
+                self.error = Some(e);
+
+                Choice::Atlantis
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        if self.round == 0 || self.error.is_some() {
+            return;
+        }
+
+        if let Err(e) = self.start() {
+            self.error = Some(e);
+        }
+    }
+
+    fn error(&self) -> Option<&StrategyError> {
+        self.error.as_ref()
+    }
+}
+
+impl Drop for ExternalStrategy {
+    fn drop(&mut self) {
+        // Closing stdin tells a well-behaved program the match is over.
+        drop(self.stdin.take());
+
+        if !matches!(self.child.try_wait(), Ok(Some(_))) {
+            thread::sleep(Duration::from_millis(50));
+
+            if !matches!(self.child.try_wait(), Ok(Some(_))) {
+                let _ = self.child.kill();
+            }
+        }
+
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::{
+        AlwaysDefect,
+        CustomizedBuilder,
+        GameGrid,
+        Player,
+        TitForTat,
+    };
+
+    /// The reference echo bot: plays the opponent's last move back at them,
+    /// cooperating in the first round.
+    const ECHO_BOT: &str = r#"
+        while IFS= read -r line; do
+            case "$line" in
+                *'"type":"move"'*'"opponent_last":"Olympus"'*) echo '{"choice":"Olympus"}' ;;
+                *'"type":"move"'*) echo '{"choice":"Atlantis"}' ;;
+            esac
+        done
+    "#;
+
+    fn game_grid() -> GameGrid {
+        GameGrid::new(
+            CustomizedBuilder::from_preset("prisoners_dilemma")
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn bot(script: &str, timeout: Duration) -> ExternalStrategy {
+        ExternalStrategy::spawn("sh", &["-c", script], game_grid().game_options, timeout).unwrap()
+    }
+
+    #[test]
+    fn test_echo_bot_plays_tit_for_tat() {
+        let game_grid = game_grid();
+
+        let mut echo = bot(ECHO_BOT, ExternalStrategy::DEFAULT_TIMEOUT);
+        let external = game_grid.play_match(&mut AlwaysDefect, &mut echo, 5);
+        let builtin = game_grid.play_match(&mut AlwaysDefect, &mut TitForTat, 5);
+
+        assert_eq!(external.forfeit(), None);
+        assert_eq!(external, builtin);
+
+        // A second match restarts the bot's view of the game
+        let again = game_grid.play_match(&mut AlwaysDefect, &mut echo, 5);
+
+        assert_eq!(again, builtin);
+    }
+
+    #[test]
+    fn test_timeout_forfeits() {
+        let mut silent = bot("while read line; do :; done", Duration::from_millis(100));
+
+        let history = game_grid().play_match(&mut TitForTat, &mut silent, 3);
+        let forfeit = history.forfeit().unwrap();
+
+        assert!(history.is_empty());
+        assert_eq!(forfeit.player, Player::Beth);
+        assert_eq!(forfeit.reason, StrategyError::Timeout { round: 1 });
+        assert_eq!(history.winner(), Some(Player::Aleph));
+    }
+
+    #[test]
+    fn test_crash_forfeits() {
+        let script = r#"read line; read line; echo '{"choice":"Olympus"}'; read line; exit 3"#;
+        let mut crashing = bot(script, ExternalStrategy::DEFAULT_TIMEOUT);
+
+        let history = game_grid().play_match(&mut crashing, &mut TitForTat, 5);
+        let forfeit = history.forfeit().unwrap();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(forfeit.player, Player::Aleph);
+        assert_eq!(forfeit.round, 2);
+        assert!(matches!(
+            forfeit.reason,
+            StrategyError::Crashed { round: 2, ref status } if status.contains('3')
+        ));
+    }
+
+    #[test]
+    fn test_closed_output_forfeits_in_time() {
+        let mut lingering = bot("exec >&-; sleep 10", Duration::from_millis(100));
+
+        let start = Instant::now();
+        let history = game_grid().play_match(&mut TitForTat, &mut lingering, 3);
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(
+            history.forfeit().map(|forfeit| &forfeit.reason),
+            Some(StrategyError::Crashed { round: 1, status }) if status.contains("killed")
+        ));
+    }
+
+    #[test]
+    fn test_invalid_response_forfeits() {
+        let mut confused = bot(
+            "while read line; do echo nonsense; done",
+            ExternalStrategy::DEFAULT_TIMEOUT,
+        );
+
+        let history = game_grid().play_match(&mut TitForTat, &mut confused, 3);
+
+        assert_eq!(
+            history.forfeit().map(|forfeit| &forfeit.reason),
+            Some(&StrategyError::InvalidResponse {
+                round:    1,
+                response: "nonsense".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_program() {
+        let result = ExternalStrategy::spawn(
+            "/nonexistent/tactix-bot",
+            &[],
+            GameOptions::default(),
+            ExternalStrategy::DEFAULT_TIMEOUT,
+        );
+
+        assert!(matches!(result, Err(StrategyError::Spawn(_))));
+    }
+}
@@ -0,0 +1,429 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+mod builtin;
+mod external;
+#[cfg(feature = "plugins")]
+mod plugin;
+#[cfg(feature = "scripting")]
+mod scripted;
+
+#[cfg(any(feature = "scripting", feature = "plugins"))]
+use std::path::PathBuf;
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+pub use builtin::{
+    AlwaysCooperate,
+    AlwaysDefect,
+    Grudger,
+    Pavlov,
+    RandomStrategy,
+    TitForTat,
+    TitForTwoTats,
+};
+pub use external::ExternalStrategy;
+#[cfg(feature = "plugins")]
+pub use plugin::PluginStrategy;
+#[cfg(feature = "scripting")]
+pub use scripted::ScriptedStrategy;
+
+use crate::{
+    BuilderError,
+    Choice,
+    GameOptions,
+    StrategyError,
+};
+
+/// A policy for choosing moves in an iterated game.
+///
+/// A strategy is asked for its next choice once per round. It is given its own
+/// previous choices and its opponent's previous choices, oldest first, so the
+/// same strategy can play as either Aleph or Beth.
+///
+/// By convention the built-in strategies treat [`Choice::Atlantis`] as the
+/// cooperative move and [`Choice::Olympus`] as defection, matching the default
+/// `cooperate`/`defect` choice names.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     Strategy,
+///     TitForTat,
+/// };
+///
+/// let mut strategy = TitForTat;
+///
+/// assert_eq!(strategy.next_choice(&[], &[]), Choice::Atlantis);
+///
+/// assert_eq!(
+///     strategy.next_choice(&[Choice::Atlantis], &[Choice::Olympus]),
+///     Choice::Olympus
+/// );
+/// ```
+pub trait Strategy {
+    /// Returns the name of the strategy.
+    fn name(&self) -> &str;
+
+    /// Returns the choice for the next round.
+    ///
+    /// # Arguments
+    ///
+    /// * `own_history` - The choices this strategy has made so far.
+    /// * `opponent_history` - The choices the opponent has made so far.
+    fn next_choice(&mut self, own_history: &[Choice], opponent_history: &[Choice]) -> Choice;
+
+    /// Clears any state kept between rounds, ready for a new match.
+    ///
+    /// The default implementation does nothing, which is correct for
+    /// strategies that only look at the history they are given.
+    fn reset(&mut self) {}
+
+    /// Returns the reason this strategy can no longer play, if it has failed.
+    ///
+    /// A strategy that has failed still has to return a choice from
+    /// [`next_choice`](Strategy::next_choice), but the match it is playing
+    /// is forfeited. The built-in strategies never fail.
+    fn error(&self) -> Option<&StrategyError> {
+        None
+    }
+}
+
+/// The built-in strategies, selectable by name.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     BuilderError,
+///     StrategyKind,
+/// };
+///
+/// let kind: StrategyKind = "tit-for-tat".parse()?;
+///
+/// assert_eq!(kind, StrategyKind::TitForTat);
+/// assert_eq!(kind.to_string(), "tit-for-tat");
+///
+/// let strategy = kind.strategy(None);
+///
+/// assert_eq!(strategy.name(), "tit-for-tat");
+/// # Ok::<(), BuilderError>(())
+/// ```
+///
+/// # Available Strategies
+///
+/// | Name | Behavior |
+/// | ---- | -------- |
+/// | `always-cooperate` | Always chooses Atlantis. |
+/// | `always-defect` | Always chooses Olympus. |
+/// | `random` | Chooses either move with equal probability. |
+/// | `tit-for-tat` | Cooperates first, then copies the opponent's last move. |
+/// | `tit-for-two-tats` | Defects only after two defections in a row. |
+/// | `grudger` | Cooperates until the opponent defects once, then always defects. |
+/// | `pavlov` | Repeats its last move after a matching round, switches otherwise. |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrategyKind {
+    /// See [`AlwaysCooperate`].
+    AlwaysCooperate,
+    /// See [`AlwaysDefect`].
+    AlwaysDefect,
+    /// See [`RandomStrategy`].
+    Random,
+    /// See [`TitForTat`].
+    TitForTat,
+    /// See [`TitForTwoTats`].
+    TitForTwoTats,
+    /// See [`Grudger`].
+    Grudger,
+    /// See [`Pavlov`].
+    Pavlov,
+}
+
+impl StrategyKind {
+    /// Every built-in strategy, in the order they are listed in the
+    /// documentation.
+    pub const ALL: [Self; 7] = [
+        Self::AlwaysCooperate,
+        Self::AlwaysDefect,
+        Self::Random,
+        Self::TitForTat,
+        Self::TitForTwoTats,
+        Self::Grudger,
+        Self::Pavlov,
+    ];
+
+    /// Returns the name used to select this strategy.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::AlwaysCooperate => "always-cooperate",
+            Self::AlwaysDefect => "always-defect",
+            Self::Random => "random",
+            Self::TitForTat => "tit-for-tat",
+            Self::TitForTwoTats => "tit-for-two-tats",
+            Self::Grudger => "grudger",
+            Self::Pavlov => "pavlov",
+        }
+    }
+
+    /// Creates a new instance of this strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed used by the [`Random`](StrategyKind::Random) strategy. Ignored by the
+    ///   deterministic strategies. If `None`, the random strategy is seeded from the operating
+    ///   system.
+    #[must_use]
+    pub fn strategy(&self, seed: Option<u64>) -> Box<dyn Strategy + Send> {
+        match self {
+            Self::AlwaysCooperate => Box::new(AlwaysCooperate),
+            Self::AlwaysDefect => Box::new(AlwaysDefect),
+            Self::Random => Box::new(seed.map_or_else(RandomStrategy::new, RandomStrategy::seeded)),
+            Self::TitForTat => Box::new(TitForTat),
+            Self::TitForTwoTats => Box::new(TitForTwoTats),
+            Self::Grudger => Box::new(Grudger::new()),
+            Self::Pavlov => Box::new(Pavlov),
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = BuilderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| BuilderError::UnknownStrategy(s.to_string()))
+    }
+}
+
+/// Where to find a strategy: one of the built-in strategies, or one loaded
+/// from a file when it is needed.
+///
+/// A source is written as the name of a built-in strategy, as
+/// `script:<path>` for a [`ScriptedStrategy`] or as `plugin:<path>` for a
+/// [`PluginStrategy`], so that tournaments can be entered by the strategies
+/// people submit as well as the built-in ones.
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     GameOptions,
+///     StrategyKind,
+///     StrategySource,
+/// };
+///
+/// let source: StrategySource = "grudger".parse()?;
+///
+/// assert_eq!(source, StrategySource::Builtin(StrategyKind::Grudger));
+///
+/// let strategy = source.strategy(GameOptions::customized().build()?, None)?;
+///
+/// assert_eq!(strategy.name(), "grudger");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StrategySource {
+    /// One of the built-in strategies.
+    Builtin(StrategyKind),
+    /// A script, loaded with [`ScriptedStrategy::from_file`].
+    #[cfg(feature = "scripting")]
+    Script(PathBuf),
+    /// A WebAssembly plugin, loaded with [`PluginStrategy::from_file`] and
+    /// given [`PluginStrategy::DEFAULT_FUEL`].
+    #[cfg(feature = "plugins")]
+    Plugin(PathBuf),
+}
+
+impl StrategySource {
+    /// Creates a new instance of the strategy, loading it if it is kept in a
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_options` - The game the strategy will be playing.
+    /// * `seed` - The seed passed to [`StrategyKind::strategy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a script or plugin can not be read or is not
+    /// valid.
+    #[cfg_attr(
+        not(any(feature = "scripting", feature = "plugins")),
+        allow(unused_variables)
+    )]
+    pub fn strategy(
+        &self,
+        game_options: GameOptions,
+        seed: Option<u64>,
+    ) -> Result<Box<dyn Strategy + Send>, StrategyError> {
+        match self {
+            Self::Builtin(kind) => Ok(kind.strategy(seed)),
+            #[cfg(feature = "scripting")]
+            Self::Script(path) => Ok(Box::new(ScriptedStrategy::from_file(path, game_options)?)),
+            #[cfg(feature = "plugins")]
+            Self::Plugin(path) => Ok(Box::new(PluginStrategy::from_file(
+                path,
+                game_options,
+                PluginStrategy::DEFAULT_FUEL,
+            )?)),
+        }
+    }
+}
+
+impl From<StrategyKind> for StrategySource {
+    fn from(kind: StrategyKind) -> Self {
+        Self::Builtin(kind)
+    }
+}
+
+impl fmt::Display for StrategySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Builtin(kind) => write!(f, "{kind}"),
+            #[cfg(feature = "scripting")]
+            Self::Script(path) => write!(f, "script:{}", path.display()),
+            #[cfg(feature = "plugins")]
+            Self::Plugin(path) => write!(f, "plugin:{}", path.display()),
+        }
+    }
+}
+
+impl FromStr for StrategySource {
+    type Err = BuilderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "scripting")]
+        if let Some(path) = s.strip_prefix("script:") {
+            return Ok(Self::Script(PathBuf::from(path)));
+        }
+
+        #[cfg(feature = "plugins")]
+        if let Some(path) = s.strip_prefix("plugin:") {
+            return Ok(Self::Plugin(PathBuf::from(path)));
+        }
+
+        s.parse().map(Self::Builtin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_kind_round_trip() {
+        for kind in StrategyKind::ALL {
+            assert_eq!(kind.to_string().parse::<StrategyKind>(), Ok(kind));
+            assert_eq!(kind.strategy(Some(1)).name(), kind.name());
+        }
+    }
+
+    #[test]
+    fn test_strategy_source_round_trip() {
+        for kind in StrategyKind::ALL {
+            let source = StrategySource::from(kind);
+
+            assert_eq!(source.to_string().parse::<StrategySource>(), Ok(source));
+        }
+
+        assert_eq!(
+            "tit-for-three-tats".parse::<StrategySource>(),
+            Err(BuilderError::UnknownStrategy(
+                "tit-for-three-tats".to_string()
+            ))
+        );
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn test_strategy_source_loads_script() {
+        let path = std::env::temp_dir().join(format!("tactix-source-{}.rhai", std::process::id()));
+
+        std::fs::write(
+            &path,
+            r#"fn next_choice(own, opponent, round, game) { "B" }"#,
+        )
+        .unwrap();
+
+        let source: StrategySource = format!("script:{}", path.display()).parse().unwrap();
+        let game_options = GameOptions::customized().build().unwrap();
+
+        let mut strategy = source.strategy(game_options, None).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(source, StrategySource::Script(path));
+        assert_eq!(
+            strategy.name(),
+            format!("tactix-source-{}", std::process::id())
+        );
+        assert_eq!(strategy.next_choice(&[], &[]), Choice::Olympus);
+
+        assert!(matches!(
+            "script:/no/such/script.rhai"
+                .parse::<StrategySource>()
+                .unwrap()
+                .strategy(game_options, None),
+            Err(StrategyError::InvalidScript(_))
+        ));
+    }
+
+    #[cfg(feature = "plugins")]
+    #[test]
+    fn test_strategy_source_loads_plugin() {
+        let path = std::env::temp_dir().join(format!("tactix-source-{}.wasm", std::process::id()));
+
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "alloc") (param i32) (result i32) i32.const 0)
+                (func (export "next_move") (param i32 i32) (result i32) i32.const 1))"#,
+        )
+        .unwrap();
+
+        std::fs::write(&path, wasm).unwrap();
+
+        let source: StrategySource = format!("plugin:{}", path.display()).parse().unwrap();
+        let game_options = GameOptions::customized().build().unwrap();
+
+        let mut strategy = source.strategy(game_options, None).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(source, StrategySource::Plugin(path));
+        assert_eq!(strategy.next_choice(&[], &[]), Choice::Olympus);
+
+        assert!(matches!(
+            "plugin:/no/such/plugin.wasm"
+                .parse::<StrategySource>()
+                .unwrap()
+                .strategy(game_options, None),
+            Err(StrategyError::InvalidPlugin(_))
+        ));
+    }
+
+    #[test]
+    fn test_strategy_kind_unknown() {
+        assert_eq!(
+            "tit-for-three-tats".parse::<StrategyKind>(),
+            Err(BuilderError::UnknownStrategy(
+                "tit-for-three-tats".to_string()
+            ))
+        );
+    }
+}
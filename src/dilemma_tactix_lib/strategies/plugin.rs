@@ -0,0 +1,476 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use wasmi::{
+    Config,
+    Engine,
+    Instance,
+    Linker,
+    Memory,
+    Module,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+    TypedFunc,
+};
+
+use crate::{
+    Choice,
+    GameOptions,
+    NumberPair,
+    Strategy,
+    StrategyError,
+};
+
+/// The largest linear memory a plugin may use, in bytes.
+const MAX_MEMORY: usize = 16 * 1024 * 1024;
+
+/// A strategy compiled to WebAssembly.
+///
+/// Plugins run in an embedded interpreter with no access to the host: a
+/// plugin that imports anything is rejected, so a plugin can only compute a
+/// move from what it is given, and the same plugin always plays the same way.
+/// Every call is limited to a budget of fuel, roughly one unit per
+/// instruction, and memory is capped at 16 MiB, so a misbehaving plugin fails
+/// instead of hanging a run.
+///
+/// # ABI
+///
+/// A plugin must export:
+///
+/// * `memory` - Its linear memory.
+/// * `alloc(len: i32) -> i32` - Returns the address of at least `len` bytes the host may write
+///   into. It may return the same buffer every time.
+/// * `next_move(history: i32, rounds: i32) -> i32` - Returns `0` to choose Atlantis or `1` to
+///   choose Olympus. `history` is the address of `rounds` pairs of bytes, oldest first, each
+///   holding the plugin's own choice and then the opponent's choice, encoded the same way.
+///
+/// A plugin may also export `init(aa_aleph, aa_beth, ab_aleph, ab_beth, ba_aleph, ba_beth,
+/// bb_aleph, bb_beth: i32)`, which is called with the payoffs of the game before each match. The
+/// first letter of each pair is Aleph's choice and the second is Beth's, with `a` for Atlantis and
+/// `b` for Olympus.
+///
+/// A fresh instance of the plugin is created for every match, so no state
+/// carries over from one match to the next.
+///
+/// A plugin that traps, runs out of fuel or returns anything other than `0`
+/// or `1` forfeits the match, as described for [`Strategy::error`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use dilemma_tactix_lib::{
+///     GameGrid,
+///     GameOptions,
+///     PluginStrategy,
+///     TitForTat,
+/// };
+///
+/// let game_options = GameOptions::customized().build()?;
+///
+/// let mut plugin = PluginStrategy::from_file(
+///     "my_bot.wasm",
+///     game_options,
+///     PluginStrategy::DEFAULT_FUEL,
+/// )?;
+///
+/// let history =
+///     GameGrid::new(game_options).play_match(&mut plugin, &mut TitForTat, 10);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct PluginStrategy {
+    name:         String,
+    engine:       Engine,
+    module:       Module,
+    game_options: GameOptions,
+    fuel:         u64,
+    instance:     PluginInstance,
+    error:        Option<StrategyError>,
+}
+
+/// A running instance of a plugin and the store that owns it.
+struct PluginInstance {
+    store:     Store<StoreLimits>,
+    memory:    Memory,
+    alloc:     TypedFunc<i32, i32>,
+    next_move: TypedFunc<(i32, i32), i32>,
+}
+
+impl PluginInstance {
+    fn new(
+        engine: &Engine,
+        module: &Module,
+        game_options: &GameOptions,
+        fuel: u64,
+    ) -> Result<Self, String> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(MAX_MEMORY)
+            .instances(1)
+            .build();
+
+        let mut store = Store::new(engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(fuel).map_err(|e| e.to_string())?;
+
+        // An empty linker makes any import fail to resolve, which keeps the
+        // plugin from reaching anything outside of its own memory.
+        let instance: Instance = Linker::new(engine)
+            .instantiate(&mut store, module)
+            .and_then(|instance| instance.start(&mut store))
+            .map_err(|e| e.to_string())?;
+
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or("the plugin does not export its memory")?;
+        let alloc = instance
+            .get_typed_func(&store, "alloc")
+            .map_err(|e| format!("alloc: {e}"))?;
+        let next_move = instance
+            .get_typed_func(&store, "next_move")
+            .map_err(|e| format!("next_move: {e}"))?;
+
+        if let Ok(init) =
+            instance.get_typed_func::<(i32, i32, i32, i32, i32, i32, i32, i32), ()>(&store, "init")
+        {
+            let pair = |pair: NumberPair| {
+                (
+                    i32::try_from(pair.first()).unwrap_or(i32::MAX),
+                    i32::try_from(pair.second()).unwrap_or(i32::MAX),
+                )
+            };
+
+            let (aa_aleph, aa_beth) = pair(game_options.atlantis_atlantis());
+            let (ab_aleph, ab_beth) = pair(game_options.atlantis_olympus());
+            let (ba_aleph, ba_beth) = pair(game_options.olympus_atlantis());
+            let (bb_aleph, bb_beth) = pair(game_options.olympus_olympus());
+
+            store.set_fuel(fuel).map_err(|e| e.to_string())?;
+
+            init.call(
+                &mut store,
+                (
+                    aa_aleph, aa_beth, ab_aleph, ab_beth, ba_aleph, ba_beth, bb_aleph, bb_beth,
+                ),
+            )
+            .map_err(|e| format!("init: {e}"))?;
+        }
+
+        Ok(Self {
+            store,
+            memory,
+            alloc,
+            next_move,
+        })
+    }
+
+    fn call(
+        &mut self,
+        fuel: u64,
+        own_history: &[Choice],
+        opponent_history: &[Choice],
+    ) -> Result<i32, String> {
+        let history: Vec<u8> = own_history
+            .iter()
+            .zip(opponent_history)
+            .flat_map(|(&own, &opponent)| [encode(own), encode(opponent)])
+            .collect();
+
+        let length = i32::try_from(history.len()).map_err(|e| e.to_string())?;
+        let rounds = length / 2;
+
+        self.store.set_fuel(fuel).map_err(|e| e.to_string())?;
+
+        let address = self
+            .alloc
+            .call(&mut self.store, length)
+            .map_err(|e| format!("alloc: {e}"))?;
+
+        let offset = usize::try_from(address).map_err(|_| format!("alloc returned {address}"))?;
+
+        self.memory
+            .write(&mut self.store, offset, &history)
+            .map_err(|e| format!("writing history: {e}"))?;
+
+        self.next_move
+            .call(&mut self.store, (address, rounds))
+            .map_err(|e| format!("next_move: {e}"))
+    }
+}
+
+const fn encode(choice: Choice) -> u8 {
+    match choice {
+        Choice::Atlantis => 0,
+        Choice::Olympus => 1,
+    }
+}
+
+impl PluginStrategy {
+    /// The fuel a plugin may use for each call when no other budget is given.
+    pub const DEFAULT_FUEL: u64 = 10_000_000;
+
+    /// Loads a plugin from the bytes of a WebAssembly module.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the strategy.
+    /// * `wasm` - The compiled WebAssembly module.
+    /// * `game_options` - The game the plugin will be playing.
+    /// * `fuel` - The fuel the plugin may use for each call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::InvalidPlugin`] if the module is not valid
+    /// WebAssembly, imports anything, does not export the functions described
+    /// above, or fails while starting.
+    pub fn from_bytes(
+        name: &str,
+        wasm: &[u8],
+        game_options: GameOptions,
+        fuel: u64,
+    ) -> Result<Self, StrategyError> {
+        let invalid = |e: String| StrategyError::InvalidPlugin(format!("{name}: {e}"));
+
+        let mut config = Config::default();
+        config.consume_fuel(true);
+
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm).map_err(|e| invalid(e.to_string()))?;
+
+        let instance =
+            PluginInstance::new(&engine, &module, &game_options, fuel).map_err(invalid)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            module,
+            game_options,
+            fuel,
+            instance,
+            error: None,
+        })
+    }
+
+    /// Loads a plugin from a `.wasm` file.
+    ///
+    /// The strategy is named after the file, without its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::InvalidPlugin`] if the file can not be read,
+    /// or for the reasons given in
+    /// [`from_bytes`](PluginStrategy::from_bytes).
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        game_options: GameOptions,
+        fuel: u64,
+    ) -> Result<Self, StrategyError> {
+        let path = path.as_ref();
+
+        let wasm = std::fs::read(path)
+            .map_err(|e| StrategyError::InvalidPlugin(format!("{}: {e}", path.display())))?;
+
+        let name = path.file_stem().map_or_else(
+            || path.display().to_string(),
+            |stem| stem.to_string_lossy().into_owned(),
+        );
+
+        Self::from_bytes(&name, &wasm, game_options, fuel)
+    }
+}
+
+impl Strategy for PluginStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_choice(&mut self, own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        if self.error.is_some() {
+            return Choice::Atlantis;
+        }
+
+        let round = own_history.len() + 1;
+
+        match self.instance.call(self.fuel, own_history, opponent_history) {
+            Ok(0) => Choice::Atlantis,
+            Ok(1) => Choice::Olympus,
+            Ok(other) => {
+                self.error = Some(StrategyError::InvalidResponse {
+                    round,
+                    response: other.to_string(),
+                });
+
+                Choice::Atlantis
+            }
+            Err(message) => {
+                self.error = Some(StrategyError::PluginFailed { round, message });
+
+                Choice::Atlantis
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.error = None;
+
+        match PluginInstance::new(&self.engine, &self.module, &self.game_options, self.fuel) {
+            Ok(instance) => self.instance = instance,
+            Err(message) => {
+                self.error = Some(StrategyError::PluginFailed { round: 0, message });
+            }
+        }
+    }
+
+    fn error(&self) -> Option<&StrategyError> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        AlwaysDefect,
+        CustomizedBuilder,
+        GameGrid,
+        Player,
+        TitForTat,
+    };
+
+    /// Plays the opponent's last move, cooperating in the first round.
+    const TIT_FOR_TAT: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32)
+                i32.const 1024)
+            (func (export "next_move") (param $history i32) (param $rounds i32) (result i32)
+                (if (result i32) (i32.eqz (local.get $rounds))
+                    (then (i32.const 0))
+                    (else
+                        (i32.load8_u
+                            (i32.add
+                                (local.get $history)
+                                (i32.sub (i32.mul (local.get $rounds) (i32.const 2)) (i32.const 1))))))))
+    "#;
+
+    /// Defects if defecting against a cooperator pays Aleph more than
+    /// cooperating does.
+    const GREEDY: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (global $move (mut i32) (i32.const 0))
+            (func (export "init")
+                (param $aa i32) (param i32) (param i32) (param i32)
+                (param $ba i32) (param i32) (param i32) (param i32)
+                (global.set $move (i32.gt_u (local.get $ba) (local.get $aa))))
+            (func (export "alloc") (param i32) (result i32)
+                i32.const 0)
+            (func (export "next_move") (param i32 i32) (result i32)
+                global.get $move))
+    "#;
+
+    const SPINNER: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32)
+                i32.const 0)
+            (func (export "next_move") (param i32 i32) (result i32)
+                (loop $forever (br $forever))
+                i32.const 0))
+    "#;
+
+    const CONFUSED: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32)
+                i32.const 0)
+            (func (export "next_move") (param i32 i32) (result i32)
+                i32.const 7))
+    "#;
+
+    fn game_grid() -> GameGrid {
+        GameGrid::new(
+            CustomizedBuilder::from_preset("prisoners_dilemma")
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn plugin(wat: &str) -> Result<PluginStrategy, StrategyError> {
+        let wasm = wat::parse_str(wat).unwrap();
+
+        PluginStrategy::from_bytes(
+            "test",
+            &wasm,
+            game_grid().game_options,
+            PluginStrategy::DEFAULT_FUEL,
+        )
+    }
+
+    #[test]
+    fn test_plugin_matches_builtin() {
+        let game_grid = game_grid();
+        let mut plugin = plugin(TIT_FOR_TAT).unwrap();
+
+        for opponent in [&mut TitForTat as &mut dyn Strategy, &mut AlwaysDefect] {
+            let expected = game_grid.play_match(&mut TitForTat, opponent, 8);
+            let actual = game_grid.play_match(&mut plugin, opponent, 8);
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_plugin_sees_payoffs() {
+        let mut plugin = plugin(GREEDY).unwrap();
+
+        assert_eq!(plugin.next_choice(&[], &[]), Choice::Olympus);
+    }
+
+    #[test]
+    fn test_out_of_fuel_forfeits() {
+        let history = game_grid().play_match(&mut TitForTat, &mut plugin(SPINNER).unwrap(), 3);
+        let forfeit = history.forfeit().unwrap();
+
+        assert_eq!(forfeit.player, Player::Beth);
+        assert!(matches!(
+            forfeit.reason,
+            StrategyError::PluginFailed { round: 1, ref message } if message.contains("fuel")
+        ));
+    }
+
+    #[test]
+    fn test_invalid_move_forfeits() {
+        let history = game_grid().play_match(&mut plugin(CONFUSED).unwrap(), &mut TitForTat, 3);
+
+        assert_eq!(
+            history.forfeit().map(|forfeit| &forfeit.reason),
+            Some(&StrategyError::InvalidResponse {
+                round:    1,
+                response: "7".to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(r#"(module (import "env" "clock" (func)) (memory (export "memory") 1))"#)]
+    #[case(r#"(module (memory (export "memory") 1))"#)]
+    #[case(r#"(module (func (export "alloc") (param i32) (result i32) i32.const 0))"#)]
+    fn test_invalid_plugin(#[case] wat: &str) {
+        assert!(matches!(plugin(wat), Err(StrategyError::InvalidPlugin(_))));
+    }
+
+    #[test]
+    fn test_not_wasm() {
+        assert!(matches!(
+            PluginStrategy::from_bytes("junk", b"junk", GameOptions::default(), 1),
+            Err(StrategyError::InvalidPlugin(_))
+        ));
+    }
+}
@@ -0,0 +1,431 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use rhai::{
+    module_resolvers::DummyModuleResolver,
+    Array,
+    CallFnOptions,
+    Dynamic,
+    Engine,
+    Map,
+    Scope,
+    AST,
+};
+
+use crate::{
+    Choice,
+    GameOptions,
+    NumberPair,
+    Strategy,
+    StrategyError,
+};
+
+/// A strategy written as a [Rhai](https://rhai.rs) script.
+///
+/// The script must define a `next_choice` function taking the player's own
+/// previous choices, the opponent's previous choices, the number of the round
+/// being played and the game being played:
+///
+/// ```rhai
+/// // Tit for tat
+/// fn next_choice(own, opponent, round, game) {
+///     if opponent.is_empty() { "A" } else { opponent[-1] }
+/// }
+/// ```
+///
+/// Choices are the strings `"A"` for Atlantis and `"B"` for Olympus, both in
+/// the histories and as the return value. The game is a map with the choice
+/// names as `choice_a` and `choice_b`, and the payoffs as `[aleph, beth]`
+/// arrays under `aa`, `ab`, `ba` and `bb`, where the first letter is
+/// Aleph's choice.
+///
+/// `next_choice` is called as a method on a map that is kept between rounds
+/// and cleared when a new match starts, so a script can remember things in
+/// `this`:
+///
+/// ```rhai
+/// // Grudger
+/// fn next_choice(own, opponent, round, game) {
+///     if round == 1 { this.betrayed = false; }
+///     if opponent.contains("B") { this.betrayed = true; }
+///     if this.betrayed { "B" } else { "A" }
+/// }
+/// ```
+///
+/// Scripts run in a sandbox: they can not import modules, and each call is
+/// limited in the number of operations it may perform, so a runaway script
+/// fails instead of stalling a tournament. A script that fails or returns
+/// anything other than `"A"` or `"B"` forfeits the match, as described for
+/// [`Strategy::error`].
+///
+/// # Examples
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     AlwaysDefect,
+///     GameGrid,
+///     GameOptions,
+///     ScriptedStrategy,
+/// };
+///
+/// let game_options = GameOptions::customized().build()?;
+///
+/// let mut script = ScriptedStrategy::from_source(
+///     "tit-for-tat",
+///     r#"fn next_choice(own, opponent, round, game) {
+///         if opponent.is_empty() { "A" } else { opponent[-1] }
+///     }"#,
+///     game_options,
+/// )?;
+///
+/// let history = GameGrid::new(game_options).play_match(
+///     &mut script,
+///     &mut AlwaysDefect,
+///     3,
+/// );
+///
+/// assert_eq!(history.aleph_choices().len(), 3);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ScriptedStrategy {
+    name:   String,
+    engine: Engine,
+    ast:    AST,
+    game:   Map,
+    state:  Dynamic,
+    error:  Option<StrategyError>,
+}
+
+impl ScriptedStrategy {
+    /// The most operations a script may perform in a single call.
+    pub const MAX_OPERATIONS: u64 = 1_000_000;
+
+    /// Compiles a strategy from the script in `source`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the strategy.
+    /// * `source` - The text of the script.
+    /// * `game_options` - The game the script will be playing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::InvalidScript`] if the script does not compile
+    /// or does not define `next_choice`.
+    pub fn from_source(
+        name: &str,
+        source: &str,
+        game_options: GameOptions,
+    ) -> Result<Self, StrategyError> {
+        let engine = sandboxed_engine();
+
+        let ast = engine
+            .compile(source)
+            .map_err(|e| StrategyError::InvalidScript(format!("{name}: {e}")))?;
+
+        if !ast
+            .iter_functions()
+            .any(|function| function.name == "next_choice" && function.params.len() == 4)
+        {
+            return Err(StrategyError::InvalidScript(format!(
+                "{name}: the script must define fn next_choice(own, opponent, round, game)"
+            )));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            ast,
+            game: game_map(&game_options),
+            state: Map::new().into(),
+            error: None,
+        })
+    }
+
+    /// Loads and compiles a strategy from a script file.
+    ///
+    /// The strategy is named after the file, without its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::InvalidScript`] if the file can not be read,
+    /// or for the reasons given in
+    /// [`from_source`](ScriptedStrategy::from_source).
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        game_options: GameOptions,
+    ) -> Result<Self, StrategyError> {
+        let path = path.as_ref();
+
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| StrategyError::InvalidScript(format!("{}: {e}", path.display())))?;
+
+        let name = path.file_stem().map_or_else(
+            || path.display().to_string(),
+            |stem| stem.to_string_lossy().into_owned(),
+        );
+
+        Self::from_source(&name, &source, game_options)
+    }
+
+    fn call(
+        &mut self,
+        own_history: &[Choice],
+        opponent_history: &[Choice],
+    ) -> Result<Choice, StrategyError> {
+        let round = own_history.len() + 1;
+
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .rewind_scope(true)
+            .bind_this_ptr(&mut self.state);
+
+        let result: Dynamic = self
+            .engine
+            .call_fn_with_options(
+                options,
+                &mut Scope::new(),
+                &self.ast,
+                "next_choice",
+                (
+                    history_array(own_history),
+                    history_array(opponent_history),
+                    i64::try_from(round).unwrap_or(i64::MAX),
+                    self.game.clone(),
+                ),
+            )
+            .map_err(|e| StrategyError::ScriptFailed {
+                round,
+                message: e.to_string(),
+            })?;
+
+        match result.clone().into_string().as_deref() {
+            Ok("A") => Ok(Choice::Atlantis),
+            Ok("B") => Ok(Choice::Olympus),
+            _ => Err(StrategyError::InvalidResponse {
+                round,
+                response: result.to_string(),
+            }),
+        }
+    }
+}
+
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.set_max_operations(ScriptedStrategy::MAX_OPERATIONS);
+    engine.set_max_call_levels(32);
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_string_size(10_000);
+    engine.set_max_array_size(100_000);
+    engine.set_max_map_size(10_000);
+
+    engine
+}
+
+const fn choice_str(choice: Choice) -> &'static str {
+    match choice {
+        Choice::Atlantis => "A",
+        Choice::Olympus => "B",
+    }
+}
+
+fn history_array(history: &[Choice]) -> Array {
+    history
+        .iter()
+        .map(|&choice| Dynamic::from(choice_str(choice).to_string()))
+        .collect()
+}
+
+fn pair_array(pair: NumberPair) -> Dynamic {
+    let pair: Array = vec![
+        Dynamic::from(i64::from(pair.first())),
+        Dynamic::from(i64::from(pair.second())),
+    ];
+
+    pair.into()
+}
+
+fn game_map(game_options: &GameOptions) -> Map {
+    let mut game = Map::new();
+
+    game.insert(
+        "choice_a".into(),
+        game_options.choice_atlantis().to_string().into(),
+    );
+    game.insert(
+        "choice_b".into(),
+        game_options.choice_olympus().to_string().into(),
+    );
+    game.insert("aa".into(), pair_array(game_options.atlantis_atlantis()));
+    game.insert("ab".into(), pair_array(game_options.atlantis_olympus()));
+    game.insert("ba".into(), pair_array(game_options.olympus_atlantis()));
+    game.insert("bb".into(), pair_array(game_options.olympus_olympus()));
+
+    game
+}
+
+impl Strategy for ScriptedStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_choice(&mut self, own_history: &[Choice], opponent_history: &[Choice]) -> Choice {
+        if self.error.is_some() {
+            return Choice::Atlantis;
+        }
+
+        match self.call(own_history, opponent_history) {
+            Ok(choice) => choice,
+            Err(e) => {
+                self.error = Some(e);
+
+                Choice::Atlantis
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.state = Map::new().into();
+        self.error = None;
+    }
+
+    fn error(&self) -> Option<&StrategyError> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        AlwaysDefect,
+        CustomizedBuilder,
+        GameGrid,
+        Grudger,
+        Player,
+        StrategyKind,
+        TitForTat,
+    };
+
+    const TIT_FOR_TAT: &str = r#"
+        fn next_choice(own, opponent, round, game) {
+            if opponent.is_empty() { "A" } else { opponent[-1] }
+        }
+    "#;
+
+    const GRUDGER: &str = r#"
+        fn next_choice(own, opponent, round, game) {
+            if round == 1 { this.betrayed = false; }
+            if opponent.contains("B") { this.betrayed = true; }
+            if this.betrayed { "B" } else { "A" }
+        }
+    "#;
+
+    fn game_grid() -> GameGrid {
+        GameGrid::new(
+            CustomizedBuilder::from_preset("prisoners_dilemma")
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn script(source: &str) -> ScriptedStrategy {
+        ScriptedStrategy::from_source("test", source, game_grid().game_options).unwrap()
+    }
+
+    #[rstest]
+    #[case(TIT_FOR_TAT, StrategyKind::TitForTat)]
+    #[case(GRUDGER, StrategyKind::Grudger)]
+    fn test_script_matches_builtin(#[case] source: &str, #[case] kind: StrategyKind) {
+        let game_grid = game_grid();
+        let mut scripted = script(source);
+        let mut builtin = kind.strategy(None);
+
+        for opponent in [
+            &mut TitForTat as &mut dyn Strategy,
+            &mut AlwaysDefect,
+            &mut Grudger::new(),
+        ] {
+            let expected = game_grid.play_match(builtin.as_mut(), opponent, 6);
+            let actual = game_grid.play_match(&mut scripted, opponent, 6);
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_script_sees_game() {
+        let mut scripted = script(
+            r#"
+            fn next_choice(own, opponent, round, game) {
+                // Defect if it pays more against a cooperator
+                if game.ba[0] > game.aa[0] && game.choice_b == "defect" { "B" } else { "A" }
+            }
+        "#,
+        );
+
+        assert_eq!(scripted.next_choice(&[], &[]), Choice::Olympus);
+    }
+
+    #[rstest]
+    #[case("fn next_choice(own, opponent, round, game) {")]
+    #[case("fn other(own, opponent, round, game) { \"A\" }")]
+    #[case("fn next_choice(own) { \"A\" }")]
+    fn test_invalid_script(#[case] source: &str) {
+        assert!(matches!(
+            ScriptedStrategy::from_source("bad", source, GameOptions::default()),
+            Err(StrategyError::InvalidScript(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(r#"fn next_choice(own, opponent, round, game) { "C" }"#)]
+    #[case(r#"fn next_choice(own, opponent, round, game) { 1 }"#)]
+    fn test_invalid_return_forfeits(#[case] source: &str) {
+        let history = game_grid().play_match(&mut script(source), &mut TitForTat, 3);
+
+        assert_eq!(
+            history.forfeit().map(|forfeit| forfeit.player),
+            Some(Player::Aleph)
+        );
+        assert!(matches!(
+            history.forfeit().map(|forfeit| &forfeit.reason),
+            Some(StrategyError::InvalidResponse { round: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_runaway_script_is_stopped() {
+        let source = r#"fn next_choice(own, opponent, round, game) { loop {} }"#;
+
+        let history = game_grid().play_match(&mut TitForTat, &mut script(source), 3);
+
+        assert!(matches!(
+            history.forfeit().map(|forfeit| &forfeit.reason),
+            Some(StrategyError::ScriptFailed { round: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_imports_are_blocked() {
+        let source = r#"
+            fn next_choice(own, opponent, round, game) {
+                import "secrets" as secrets;
+                "A"
+            }
+        "#;
+
+        let history = game_grid().play_match(&mut script(source), &mut TitForTat, 1);
+
+        assert!(history.forfeit().is_some());
+    }
+}
@@ -3,12 +3,404 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-struct Cli {
-    minimum: Option<usize>,
-    maximum: Option<usize>,
-    score_aa: Option<[usize; 2]>,
-    score_ab: Option<[usize; 2]>,
-    score_ba: Option<[usize; 2]>,
-    score_bb: Option<[usize; 2]>,
-    randomize: bool,
+use std::path::PathBuf;
+
+use clap::{
+    Parser,
+    Subcommand,
+};
+use dilemma_tactix_lib::{
+    BuilderError,
+    GameOptions,
+    GameOptionsBuilder,
+    GameOptionsBuilderTypes,
+    GameParams,
+    NumberPair,
+    PluginStrategy,
+    StrategyKind,
+};
+
+/// Play an iterated two-player dilemma against a computer opponent, against
+/// another person with `--hot-seat`, or over the network with `host` and
+/// `join`.
+///
+/// Without any options a randomized game is generated. Setting any of the
+/// `--score-*` options plays a customized game instead, with the remaining
+/// cells filled in from the defaults. Setting `--seed` generates the same
+/// randomized game every time.
+#[derive(Debug, Clone, Default, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// The lowest score a randomized game can award.
+    #[arg(long, value_name = "SCORE")]
+    pub minimum: Option<u32>,
+
+    /// The highest score a randomized game can award.
+    #[arg(long, value_name = "SCORE")]
+    pub maximum: Option<u32>,
+
+    /// The scores when both players choose A, as ALEPH,BETH.
+    #[arg(long, value_name = "ALEPH,BETH", value_parser = parse_score)]
+    pub score_aa: Option<NumberPair>,
+
+    /// The scores when you choose A and the opponent chooses B, as
+    /// ALEPH,BETH.
+    #[arg(long, value_name = "ALEPH,BETH", value_parser = parse_score)]
+    pub score_ab: Option<NumberPair>,
+
+    /// The scores when you choose B and the opponent chooses A, as
+    /// ALEPH,BETH.
+    #[arg(long, value_name = "ALEPH,BETH", value_parser = parse_score)]
+    pub score_ba: Option<NumberPair>,
+
+    /// The scores when both players choose B, as ALEPH,BETH.
+    #[arg(long, value_name = "ALEPH,BETH", value_parser = parse_score)]
+    pub score_bb: Option<NumberPair>,
+
+    /// Generate random scores even if some scores were given.
+    #[arg(long)]
+    pub randomize: bool,
+
+    /// Generate the same random scores every time, using this seed.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// The name of choice A.
+    #[arg(long, value_name = "NAME")]
+    pub choice_a: Option<String>,
+
+    /// The name of choice B.
+    #[arg(long, value_name = "NAME")]
+    pub choice_b: Option<String>,
+
+    /// The number of rounds to play. Asked for interactively if not given.
+    #[arg(short, long, value_parser = parse_rounds)]
+    pub rounds: Option<usize>,
+
+    /// The opponent strategy. Asked for interactively if not given.
+    #[arg(short, long, value_name = "STRATEGY")]
+    pub opponent: Option<StrategyKind>,
+
+    /// Play against an external program instead of a built-in strategy.
+    ///
+    /// The command is split on whitespace; the program speaks line-delimited
+    /// JSON on stdin and stdout.
+    #[arg(long, value_name = "COMMAND", conflicts_with_all = ["opponent", "hot_seat"])]
+    pub opponent_command: Option<String>,
+
+    /// Play against a strategy written as a Rhai script.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["opponent", "hot_seat", "opponent_command"]
+    )]
+    pub opponent_script: Option<PathBuf>,
+
+    /// Play against a strategy compiled to WebAssembly.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["opponent", "hot_seat", "opponent_command", "opponent_script"]
+    )]
+    pub opponent_plugin: Option<PathBuf>,
+
+    /// The fuel a WebAssembly strategy may use for each move. It forfeits the
+    /// match if it runs out.
+    #[arg(long, default_value_t = PluginStrategy::DEFAULT_FUEL)]
+    pub plugin_fuel: u64,
+
+    /// How long an external program may take to answer each move, in
+    /// milliseconds. It forfeits the match if it takes longer.
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    pub move_timeout: u64,
+
+    /// Let two people play each other on this terminal, hiding each
+    /// player's choice until the round is revealed.
+    #[arg(long, conflicts_with = "opponent")]
+    pub hot_seat: bool,
+
+    /// Play over the network instead of on this terminal.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// The networked modes.
+///
+/// The game options given before the subcommand are used by the host and
+/// sent to the joining player.
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Wait for another player to join and play as Aleph.
+    Host {
+        /// The address to listen on.
+        #[arg(long, default_value = "0.0.0.0:7878")]
+        bind: String,
+
+        /// Let a built-in strategy play for this side.
+        #[arg(long, value_name = "STRATEGY")]
+        bot: Option<StrategyKind>,
+    },
+    /// Join a hosted game and play as Beth.
+    Join {
+        /// The address of the host, e.g. 192.168.1.10:7878.
+        address: String,
+
+        /// Let a built-in strategy play for this side.
+        #[arg(long, value_name = "STRATEGY")]
+        bot: Option<StrategyKind>,
+    },
+}
+
+fn parse_score(value: &str) -> Result<NumberPair, String> {
+    let (aleph, beth) = value
+        .split_once(',')
+        .ok_or_else(|| format!("expected two scores separated by a comma, got {value}"))?;
+
+    let parse = |score: &str| {
+        score
+            .trim()
+            .parse::<u32>()
+            .map_err(|e| format!("invalid score {score}: {e}"))
+    };
+
+    Ok(NumberPair::new(parse(aleph)?, parse(beth)?))
+}
+
+fn parse_rounds(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(0) => Err("at least one round must be played".to_string()),
+        Ok(rounds) => Ok(rounds),
+        Err(e) => Err(format!("invalid number of rounds {value}: {e}")),
+    }
+}
+
+impl Cli {
+    /// Returns the type of builder selected by the given options.
+    pub const fn builder_type(&self) -> GameOptionsBuilderTypes {
+        if self.seed.is_some() {
+            GameOptionsBuilderTypes::Seeded
+        } else if self.randomize || !self.has_scores() {
+            GameOptionsBuilderTypes::Randomized
+        } else {
+            GameOptionsBuilderTypes::Customized
+        }
+    }
+
+    const fn has_scores(&self) -> bool {
+        self.score_aa.is_some()
+            || self.score_ab.is_some()
+            || self.score_ba.is_some()
+            || self.score_bb.is_some()
+    }
+
+    /// Builds the `GameOptions` described by the given options.
+    ///
+    /// Choice names are leaked, as `GameOptions` only holds `'static` names
+    /// and they live for the rest of the program anyway.
+    pub fn game_options(&self) -> Result<GameOptions, BuilderError> {
+        let mut builder = GameOptionsBuilder::new(self.builder_type());
+
+        if let Some(min_value) = self.minimum {
+            builder = builder.min_value(min_value)?;
+        }
+
+        if let Some(max_value) = self.maximum {
+            builder = builder.max_value(max_value)?;
+        }
+
+        if let Some(seed) = self.seed {
+            builder = builder.seed(seed)?;
+        }
+
+        if let Some(name) = &self.choice_a {
+            builder = builder.choice_atlantis(GameParams::intern_name("choice_atlantis", name)?)?;
+        }
+
+        if let Some(name) = &self.choice_b {
+            builder = builder.choice_olympus(GameParams::intern_name("choice_olympus", name)?)?;
+        }
+
+        if let Some(score) = self.score_aa {
+            builder = builder.atlantis_atlantis(score)?;
+        }
+
+        if let Some(score) = self.score_ab {
+            builder = builder.atlantis_olympus(score)?;
+        }
+
+        if let Some(score) = self.score_ba {
+            builder = builder.olympus_atlantis(score)?;
+        }
+
+        if let Some(score) = self.score_bb {
+            builder = builder.olympus_olympus(score)?;
+        }
+
+        builder.build()
+    }
+}
+
+fn flag(field: &str) -> &str {
+    match field {
+        "min_value" => "--minimum",
+        "max_value" => "--maximum",
+        "seed" => "--seed",
+        "choice_atlantis" => "--choice-a",
+        "choice_olympus" => "--choice-b",
+        "atlantis_atlantis" => "--score-aa",
+        "atlantis_olympus" => "--score-ab",
+        "olympus_atlantis" => "--score-ba",
+        "olympus_olympus" => "--score-bb",
+        _ => field,
+    }
+}
+
+/// Describes a `BuilderError` in terms of the command line options.
+pub fn friendly_error(error: &BuilderError) -> String {
+    match *error {
+        BuilderError::InvalidBuilderType {
+            field,
+            builder_type: GameOptionsBuilderTypes::Customized,
+        } => {
+            format!(
+                "{} can only be used with randomized games; add --randomize or remove the \
+                 --score-* options",
+                flag(field)
+            )
+        }
+        BuilderError::InvalidBuilderType { field, .. } => {
+            format!(
+                "{} can not be combined with --randomize or --seed",
+                flag(field)
+            )
+        }
+        BuilderError::InvalidBounds {
+            min_value,
+            max_value,
+        } => {
+            format!("--minimum ({min_value}) must not be greater than --maximum ({max_value})")
+        }
+        BuilderError::EmptyChoiceName(field) => {
+            format!("{} must not be empty", flag(field))
+        }
+        BuilderError::DuplicateChoiceNames(name) => {
+            format!("--choice-a and --choice-b are both {name}; pick two different names")
+        }
+        BuilderError::MissingOption(field) => {
+            format!("{} is required for this game", flag(field))
+        }
+        BuilderError::ChoiceNameTooLong { field, max_length } => {
+            format!(
+                "{} must be at most {max_length} characters long",
+                flag(field)
+            )
+        }
+        _ => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(std::iter::once("tactix").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn test_command() {
+        Cli::command().debug_assert();
+    }
+
+    #[rstest]
+    #[case(&[], GameOptionsBuilderTypes::Randomized)]
+    #[case(&["--minimum", "2"], GameOptionsBuilderTypes::Randomized)]
+    #[case(&["--seed", "7"], GameOptionsBuilderTypes::Seeded)]
+    #[case(&["--score-aa", "3,3"], GameOptionsBuilderTypes::Customized)]
+    #[case(&["--score-aa", "3,3", "--randomize"], GameOptionsBuilderTypes::Randomized)]
+    fn test_builder_type(#[case] args: &[&str], #[case] expected: GameOptionsBuilderTypes) {
+        assert_eq!(parse(args).builder_type(), expected);
+    }
+
+    #[test]
+    fn test_customized_game_options() {
+        let cli = parse(&[
+            "--score-aa",
+            "3, 3",
+            "--score-bb",
+            "1,1",
+            "--choice-a",
+            "share",
+            "--opponent",
+            "grudger",
+            "--rounds",
+            "10",
+        ]);
+
+        let game_options = cli.game_options().unwrap();
+
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(3, 3));
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(1, 1));
+        assert_eq!(game_options.choice_atlantis(), "share");
+        assert_eq!(cli.opponent, Some(StrategyKind::Grudger));
+        assert_eq!(cli.rounds, Some(10));
+    }
+
+    #[test]
+    fn test_network_commands() {
+        let cli = parse(&["--rounds", "3", "host", "--bot", "pavlov"]);
+
+        assert!(matches!(
+            cli.command,
+            Some(Command::Host {
+                bot: Some(StrategyKind::Pavlov),
+                ..
+            })
+        ));
+
+        let cli = parse(&["join", "127.0.0.1:7878"]);
+
+        assert!(matches!(
+            cli.command,
+            Some(Command::Join { ref address, bot: None }) if address == "127.0.0.1:7878"
+        ));
+    }
+
+    #[test]
+    fn test_seeded_game_options_repeat() {
+        let cli = parse(&["--seed", "2024", "--minimum", "1", "--maximum", "9"]);
+
+        assert_eq!(cli.game_options().unwrap(), cli.game_options().unwrap());
+    }
+
+    #[rstest]
+    #[case(&["--score-aa", "3"])]
+    #[case(&["--score-aa", "a,3"])]
+    #[case(&["--rounds", "0"])]
+    #[case(&["--opponent", "nobody"])]
+    #[case(&["--hot-seat", "--opponent", "grudger"])]
+    #[case(&["join"])]
+    #[case(&["--opponent", "grudger", "--opponent-command", "python3 bot.py"])]
+    #[case(&["--opponent-script", "bot.rhai", "--opponent-command", "python3 bot.py"])]
+    #[case(&["--opponent-plugin", "bot.wasm", "--opponent", "grudger"])]
+    fn test_invalid_arguments(#[case] args: &[&str]) {
+        assert!(
+            Cli::try_parse_from(std::iter::once("tactix").chain(args.iter().copied())).is_err()
+        );
+    }
+
+    #[rstest]
+    #[case(&["--minimum", "9", "--maximum", "1"], "--minimum (9) must not be greater than --maximum (1)")]
+    #[case(&["--seed", "1", "--score-ab", "0,5"], "--score-ab can not be combined with --randomize or --seed")]
+    #[case(&["--score-ab", "0,5", "--minimum", "1"], "--minimum can only be used with randomized games; add --randomize or remove the --score-* options")]
+    #[case(&["--choice-a", "x", "--choice-b", "x"], "--choice-a and --choice-b are both x; pick two different names")]
+    #[case(&["--choice-b", "a-name-far-too-long-to-fit-in-the-grid"], "--choice-b must be at most 32 characters long")]
+    fn test_friendly_error(#[case] args: &[&str], #[case] expected: &str) {
+        let error = parse(args).game_options().unwrap_err();
+
+        assert_eq!(friendly_error(&error), expected);
+    }
 }
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::io::{
+    self,
+    BufRead,
+    IsTerminal,
+    Write,
+};
+
+use crossterm::{
+    event::{
+        self,
+        Event,
+        KeyCode,
+        KeyEventKind,
+        KeyModifiers,
+    },
+    terminal::{
+        disable_raw_mode,
+        enable_raw_mode,
+    },
+};
+
+/// Disables raw mode when dropped, so an error while reading does not leave
+/// the terminal unusable.
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enable() -> io::Result<Self> {
+        enable_raw_mode()?;
+
+        Ok(Self)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        if let Err(e) = disable_raw_mode() {
+            eprintln!("Failed to disable raw mode: {e}");
+        }
+    }
+}
+
+/// Prints `prompt` and reads a line from stdin.
+///
+/// Returns `None` once stdin has ended, or cannot be read, as no amount of
+/// asking again will get an answer then.
+pub fn read_user_input(prompt: &str) -> Option<String> {
+    print!("{prompt}");
+    match io::stdout().flush() {
+        Ok(()) => (),
+        Err(e) => eprintln!("Failed to flush stdout: {e}"),
+    }
+
+    let input = read_line(&mut io::stdin().lock());
+
+    // Nothing was typed to end the prompt's line, so end it here.
+    if input.is_none() {
+        println!();
+    }
+
+    input
+}
+
+/// Reads a line from `reader`, or `None` at the end of its input.
+pub fn read_line(reader: &mut impl BufRead) -> Option<String> {
+    let mut input = String::new();
+
+    match reader.read_line(&mut input) {
+        Ok(0) => None,
+        Ok(_) => Some(input),
+        Err(e) => {
+            eprintln!("Failed to read line: {e}");
+
+            None
+        }
+    }
+}
+
+/// Reads a line without echoing it, printing `*` for every character typed.
+///
+/// Falls back to [`read_user_input`] when stdin is not a terminal, as there is
+/// nobody watching the screen in that case. Returns `None` if the input has
+/// ended, including when `Ctrl-D` is pressed on an empty line.
+pub fn read_hidden_input(prompt: &str) -> Option<String> {
+    if !io::stdin().is_terminal() {
+        return read_user_input(prompt);
+    }
+
+    print!("{prompt}");
+
+    match read_masked() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read hidden input: {e}");
+
+            None
+        }
+    }
+}
+
+fn read_masked() -> io::Result<Option<String>> {
+    let mut input = String::new();
+    let mut stdout = io::stdout();
+
+    stdout.flush()?;
+
+    let guard = RawModeGuard::enable()?;
+
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Enter => break,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                drop(guard);
+                println!();
+                std::process::exit(130);
+            }
+            KeyCode::Char('d')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && input.is_empty() =>
+            {
+                drop(guard);
+                println!();
+
+                return Ok(None);
+            }
+            KeyCode::Char(c) => {
+                input.push(c);
+                write!(stdout, "*")?;
+            }
+            KeyCode::Backspace if input.pop().is_some() => {
+                write!(stdout, "\u{8} \u{8}")?;
+            }
+            _ => continue,
+        }
+
+        stdout.flush()?;
+    }
+
+    drop(guard);
+    println!();
+
+    Ok(Some(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_line() {
+        let mut reader = "a\nb".as_bytes();
+
+        assert_eq!(read_line(&mut reader).as_deref(), Some("a\n"));
+        assert_eq!(read_line(&mut reader).as_deref(), Some("b"));
+        assert_eq!(read_line(&mut reader), None);
+        assert_eq!(read_line(&mut io::empty()), None);
+    }
+}
@@ -3,112 +3,424 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+mod cli;
+mod input;
+mod net;
+mod players;
+
 use std::{
-    cmp::Ordering,
-    io::{
-        self,
-        Write,
-    },
+    process::ExitCode,
+    time::Duration,
 };
 
+use clap::Parser;
+use cli::{
+    friendly_error,
+    Cli,
+    Command,
+};
 use dilemma_tactix_lib::{
     Choice,
+    ExternalStrategy,
     GameGrid,
     GameOptions,
+    MatchHistory,
+    Player,
+    PluginStrategy,
+    ScriptedStrategy,
+    Strategy,
+    StrategyError,
+    StrategyKind,
 };
-use rand::{
-    prelude::IndexedRandom,
-    SeedableRng,
-};
+use input::read_user_input;
+use players::HumanPlayer;
+
+const DEFAULT_ROUNDS: usize = 5;
+
+/// Asks for the number of rounds to play, or `None` if the input has ended.
+fn read_rounds() -> Option<usize> {
+    loop {
+        let rounds = read_user_input(&format!("How many rounds? [{DEFAULT_ROUNDS}]: "))?;
+        let rounds = rounds.trim();
 
-fn parse_choice(choice: &str) -> Choice {
-    match choice {
-        "A" => Choice::Atlantis,
-        "B" => Choice::Olympus,
-        _ => {
-            println!("Invalid choice, defaulting to A");
+        if rounds.is_empty() {
+            return Some(DEFAULT_ROUNDS);
+        }
 
-            Choice::Atlantis
+        match rounds.parse() {
+            Ok(rounds) if rounds > 0 => return Some(rounds),
+            _ => println!("Please enter a positive number"),
         }
     }
 }
 
-fn get_computer_choice(seed: Option<u64>) -> Choice {
-    let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(seed.unwrap_or(0));
+/// Asks for the opponent to play against, or `None` if the input has ended.
+fn read_opponent() -> Option<StrategyKind> {
+    println!("Opponents:");
+
+    for (index, kind) in StrategyKind::ALL.iter().enumerate() {
+        println!("{}: {kind}", index + 1);
+    }
+
+    loop {
+        let opponent = read_user_input("Choose your opponent (number or name) [random]: ")?;
+        let opponent = opponent.trim();
 
-    let choices = ["A", "B"];
+        if opponent.is_empty() {
+            return Some(StrategyKind::Random);
+        }
 
-    let choice = choices.choose(&mut rng).unwrap_or_else(|| &choices[0]);
+        let kind = opponent
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| index.checked_sub(1))
+            .and_then(|index| StrategyKind::ALL.get(index).copied())
+            .map_or_else(|| opponent.parse(), Ok);
 
-    parse_choice(choice)
+        match kind {
+            Ok(kind) => return Some(kind),
+            Err(e) => println!("{e}"),
+        }
+    }
 }
 
-pub fn game_loop(game_options: GameOptions, game_grid: GameGrid) {
+fn cooperation_rate(choices: &[Choice]) -> f64 {
+    if choices.is_empty() {
+        return 0.0;
+    }
+
+    let cooperations = choices.iter().filter(|&&c| c == Choice::Atlantis).count();
+
+    #[allow(clippy::cast_precision_loss)]
+    let rate = cooperations as f64 / choices.len() as f64;
+
+    rate * 100.0
+}
+
+/// Prints the final results of a match.
+///
+/// `you` is the side played by the person at this terminal, if only one
+/// side is, whose win is announced as theirs.
+fn print_summary(
+    game_options: &GameOptions,
+    history: &MatchHistory,
+    aleph: &str,
+    beth: &str,
+    you: Option<Player>,
+) {
+    println!("Final results after {} rounds:", history.len());
+
+    history.make_table(game_options).printstd();
+
+    println!(
+        "{aleph} Score: {}\n{beth} Score: {}",
+        history.aleph_total(),
+        history.beth_total()
+    );
+
+    println!(
+        "{aleph} chose {} {:.0}% of the time; {beth} chose it {:.0}% of the time",
+        game_options.choice_atlantis(),
+        cooperation_rate(history.aleph_choices()),
+        cooperation_rate(history.beth_choices())
+    );
+
+    if let Some(forfeit) = history.forfeit() {
+        let name = match forfeit.player {
+            Player::Aleph => aleph,
+            Player::Beth => beth,
+        };
+
+        println!(
+            "{name} forfeited in round {}: {}",
+            forfeit.round, forfeit.reason
+        );
+    }
+
+    match history.winner() {
+        Some(winner) if Some(winner) == you => println!("You win!"),
+        Some(Player::Aleph) => println!("{aleph} wins!"),
+        Some(Player::Beth) => println!("{beth} wins!"),
+        None => println!("It's a tie!"),
+    }
+}
+
+pub fn game_loop(
+    game_options: GameOptions,
+    game_grid: GameGrid,
+    aleph: &mut dyn Strategy,
+    beth: &mut dyn Strategy,
+    rounds: usize,
+    you: Option<Player>,
+) {
+    let mut history = MatchHistory::new();
+
+    aleph.reset();
+    beth.reset();
+
     game_grid.show_grid();
 
-    println!("The choices available to you are: ");
+    println!("The choices available are: ");
 
     println!("A: {}", game_options.choice_atlantis());
 
     println!("B: {}", game_options.choice_olympus());
 
-    let choice = read_user_input("Enter your choice (A or B): ");
+    for round in 1..=rounds {
+        println!(
+            "Round {round} of {rounds}: {} vs {}",
+            aleph.name(),
+            beth.name()
+        );
 
-    let choice = parse_choice(choice.trim());
+        let aleph_choice = aleph.next_choice(history.aleph_choices(), history.beth_choices());
 
-    let computer_choice = get_computer_choice(None);
+        if let Some(error) = aleph.error() {
+            history.record_forfeit(Player::Aleph, error.clone());
+            break;
+        }
 
-    let result = game_grid.return_score(choice, computer_choice);
+        let beth_choice = beth.next_choice(history.beth_choices(), history.aleph_choices());
 
-    println!("You chose: {choice}");
+        if let Some(error) = beth.error() {
+            history.record_forfeit(Player::Beth, error.clone());
+            break;
+        }
 
-    println!("The computer chose: {computer_choice}");
+        let result = game_grid.play_round(&mut history, aleph_choice, beth_choice);
+
+        println!(
+            "{} chose: {}",
+            aleph.name(),
+            game_options.choice_name(aleph_choice)
+        );
+
+        println!(
+            "{} chose: {}",
+            beth.name(),
+            game_options.choice_name(beth_choice)
+        );
+
+        println!(
+            "Round Score: {} - {}\nTotal Score: {} - {}",
+            result.first(),
+            result.second(),
+            history.aleph_total(),
+            history.beth_total()
+        );
+
+        if round < rounds {
+            history.make_table(&game_options).printstd();
+        }
+    }
+
+    print_summary(&game_options, &history, aleph.name(), beth.name(), you);
+}
+
+fn report_round(game_options: &GameOptions, history: &MatchHistory) {
+    let Some(round) = history.last() else {
+        GameGrid::new(*game_options).show_grid();
+
+        return;
+    };
 
     println!(
-        "Your Score: {}\nComputer Score: {}",
-        result.first(),
-        result.second()
+        "Round {}: Aleph chose {}, Beth chose {}",
+        history.len(),
+        game_options.choice_name(round.aleph_choice),
+        game_options.choice_name(round.beth_choice)
     );
 
-    match result.first().cmp(&result.second()) {
-        Ordering::Greater => println!("You win!"),
-        Ordering::Less => println!("The computer wins!"),
-        Ordering::Equal => println!("It's a tie!"),
-    }
+    println!(
+        "Round Score: {} - {}\nTotal Score: {} - {}",
+        round.score.first(),
+        round.score.second(),
+        history.aleph_total(),
+        history.beth_total()
+    );
+}
+
+fn spawn_external(
+    command: &str,
+    game_options: GameOptions,
+    timeout: u64,
+) -> Result<ExternalStrategy, StrategyError> {
+    let mut words = command.split_whitespace();
+
+    let program = words
+        .next()
+        .ok_or_else(|| StrategyError::Spawn("the command is empty".to_string()))?;
+    let args: Vec<&str> = words.collect();
+
+    ExternalStrategy::spawn(program, &args, game_options, Duration::from_millis(timeout))
 }
 
-fn read_user_input(prompt: &str) -> String {
-    print!("{prompt}");
-    match io::stdout().flush() {
-        Ok(()) => (),
-        Err(e) => eprintln!("Failed to flush stdout: {e}"),
+/// Builds the opponent chosen on the command line, asking for one if none
+/// was, or `None` if the input ends before one is chosen.
+fn build_opponent(
+    cli: &Cli,
+    game_options: GameOptions,
+) -> Result<Option<Box<dyn Strategy>>, StrategyError> {
+    if let Some(command) = &cli.opponent_command {
+        return Ok(Some(Box::new(spawn_external(
+            command,
+            game_options,
+            cli.move_timeout,
+        )?)));
+    }
+
+    if let Some(path) = &cli.opponent_script {
+        return Ok(Some(Box::new(ScriptedStrategy::from_file(
+            path,
+            game_options,
+        )?)));
     }
 
-    let mut input = String::new();
-    match io::stdin().read_line(&mut input) {
-        Ok(_) => (),
-        Err(e) => eprintln!("Failed to read line: {e}"),
+    if let Some(path) = &cli.opponent_plugin {
+        return Ok(Some(Box::new(PluginStrategy::from_file(
+            path,
+            game_options,
+            cli.plugin_fuel,
+        )?)));
     }
 
-    input
+    let Some(kind) = cli.opponent.or_else(read_opponent) else {
+        return Ok(None);
+    };
+
+    Ok(Some(kind.strategy(cli.seed)))
 }
 
-fn main() {
-    let game_options = GameOptions::builder("randomized").build();
+fn play_networked(cli: &Cli, command: &Command, game_options: GameOptions) -> ExitCode {
+    let (bot, seat) = match command {
+        Command::Host { bot, .. } => (bot, "Aleph"),
+        Command::Join { bot, .. } => (bot, "Beth"),
+    };
 
-    let game_grid = GameGrid::new(game_options);
+    let mut player = match bot {
+        Some(kind) => kind.strategy(cli.seed),
+        None => Box::new(HumanPlayer::new(seat)),
+    };
+
+    let result = tokio::runtime::Runtime::new()
+        .map_err(net::ProtocolError::from)
+        .and_then(|runtime| {
+            runtime.block_on(async {
+                match command {
+                    Command::Host { bind, .. } => {
+                        let Some(rounds) = cli.rounds.or_else(read_rounds) else {
+                            return Ok(None);
+                        };
+
+                        GameGrid::new(game_options).show_grid();
+
+                        let listener = tokio::net::TcpListener::bind(bind).await?;
+
+                        println!(
+                            "Waiting for a player to join on {}...",
+                            listener.local_addr()?
+                        );
+
+                        let history = net::host(
+                            listener,
+                            game_options,
+                            rounds,
+                            player.as_mut(),
+                            &mut report_round,
+                        )
+                        .await?;
+
+                        Ok(Some((game_options, history)))
+                    }
+                    Command::Join { address, .. } => {
+                        let stream = tokio::net::TcpStream::connect(address).await?;
+
+                        println!("Connected to {address}");
+
+                        net::join(stream, player.as_mut(), &mut report_round)
+                            .await
+                            .map(Some)
+                    }
+                }
+            })
+        });
+
+    match result {
+        // The input ended before the game could start.
+        Ok(None) => ExitCode::SUCCESS,
+        Ok(Some((game_options, history))) => {
+            print_summary(&game_options, &history, "Aleph", "Beth", None);
+
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let game_options = match cli.game_options() {
+        Ok(game_options) => game_options,
+        Err(e) => {
+            eprintln!("error: {}", friendly_error(&e));
+
+            return ExitCode::from(2);
+        }
+    };
 
     println!("Welcome to Dilemma Tactix!");
 
-    loop {
-        game_loop(game_options, game_grid);
+    if let Some(command) = &cli.command {
+        return play_networked(&cli, command, game_options);
+    }
+
+    let game_grid = GameGrid::new(game_options);
 
-        let play_again = read_user_input("Play again? (Y/N): ").trim().to_string();
+    // The game ends when the player stops playing again or the input ends.
+    while let Some(rounds) = cli.rounds.or_else(read_rounds) {
+        if cli.hot_seat {
+            game_loop(
+                game_options,
+                game_grid,
+                &mut HumanPlayer::hidden("Aleph"),
+                &mut HumanPlayer::hidden("Beth"),
+                rounds,
+                None,
+            );
+        } else {
+            let mut opponent = match build_opponent(&cli, game_options) {
+                Ok(Some(opponent)) => opponent,
+                Ok(None) => break,
+                Err(e) => {
+                    eprintln!("error: {e}");
 
-        if play_again.to_lowercase() != "y" {
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            game_loop(
+                game_options,
+                game_grid,
+                &mut HumanPlayer::new("You"),
+                opponent.as_mut(),
+                rounds,
+                Some(Player::Aleph),
+            );
+        }
+
+        let play_again = read_user_input("Play again? (Y/N): ").unwrap_or_default();
+
+        if play_again.trim().to_lowercase() != "y" {
             break;
         }
     }
 
     println!("Thanks for playing!");
+
+    ExitCode::SUCCESS
 }
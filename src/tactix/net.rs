@@ -0,0 +1,789 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Networked matches between two `tactix` instances.
+//!
+//! The protocol is line-delimited JSON over TCP. The host plays as Aleph and
+//! the joining side as Beth:
+//!
+//! 1. The joining side sends `hello` with the protocol version.
+//! 2. The host answers with `options`, carrying the payoffs and the number of rounds.
+//! 3. Every round, each side settles on its choice and sends a `commit` holding the SHA-256 hash of
+//!    its seat, the round, the choice and a random nonce. Only once both commitments have been
+//!    received does either side send a `reveal` with the choice and nonce.
+//! 4. Each side checks the other's reveal against its commitment. A reveal that does not match, or
+//!    a commitment or nonce that merely echoes our own, is answered with `cheating` and ends the
+//!    match.
+//!
+//! Committing first means neither side can wait to see the other's choice
+//! before making its own, and the nonce stops a commitment from being undone
+//! by hashing both possible choices. Hashing the seat stops a side from
+//! copying the other's commitment and then its reveal, which would let it
+//! mirror every move.
+
+use std::{
+    error::Error,
+    fmt,
+    io,
+};
+
+use dilemma_tactix_lib::{
+    BuilderError,
+    Choice,
+    GameGrid,
+    GameOptions,
+    GameOptionsBuilder,
+    GameOptionsBuilderTypes,
+    GameParams,
+    MatchHistory,
+    NumberPair,
+    Strategy,
+    StrategyError,
+};
+use rand::RngCore;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sha2::{
+    Digest,
+    Sha256,
+};
+use tokio::{
+    io::{
+        AsyncBufReadExt,
+        AsyncWriteExt,
+        BufReader,
+        Lines,
+    },
+    net::{
+        tcp::{
+            OwnedReadHalf,
+            OwnedWriteHalf,
+        },
+        TcpListener,
+        TcpStream,
+    },
+};
+
+pub const PROTOCOL_VERSION: u32 = 3;
+
+const NONCE_LENGTH: usize = 16;
+
+/// The payoffs of a game, in a form that can be sent over the wire.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WireOptions {
+    pub choice_atlantis:   String,
+    pub choice_olympus:    String,
+    pub atlantis_atlantis: NumberPair,
+    pub atlantis_olympus:  NumberPair,
+    pub olympus_atlantis:  NumberPair,
+    pub olympus_olympus:   NumberPair,
+}
+
+impl From<GameOptions> for WireOptions {
+    fn from(game_options: GameOptions) -> Self {
+        Self {
+            choice_atlantis:   game_options.choice_atlantis().to_string(),
+            choice_olympus:    game_options.choice_olympus().to_string(),
+            atlantis_atlantis: game_options.atlantis_atlantis(),
+            atlantis_olympus:  game_options.atlantis_olympus(),
+            olympus_atlantis:  game_options.olympus_atlantis(),
+            olympus_olympus:   game_options.olympus_olympus(),
+        }
+    }
+}
+
+impl TryFrom<WireOptions> for GameOptions {
+    type Error = BuilderError;
+
+    fn try_from(options: WireOptions) -> Result<Self, Self::Error> {
+        GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized)
+            .choice_atlantis(GameParams::intern_name(
+                "choice_atlantis",
+                &options.choice_atlantis,
+            )?)?
+            .choice_olympus(GameParams::intern_name(
+                "choice_olympus",
+                &options.choice_olympus,
+            )?)?
+            .atlantis_atlantis(options.atlantis_atlantis)?
+            .atlantis_olympus(options.atlantis_olympus)?
+            .olympus_atlantis(options.olympus_atlantis)?
+            .olympus_olympus(options.olympus_olympus)?
+            .build()
+    }
+}
+
+/// A single line of the protocol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    Hello {
+        version: u32,
+    },
+    Options {
+        game:   WireOptions,
+        rounds: usize,
+    },
+    Commit {
+        round: usize,
+        hash:  String,
+    },
+    Reveal {
+        round:  usize,
+        choice: Choice,
+        nonce:  String,
+    },
+    Cheating {
+        round: usize,
+    },
+}
+
+impl Message {
+    const fn kind(&self) -> &'static str {
+        match self {
+            Self::Hello { .. } => "hello",
+            Self::Options { .. } => "options",
+            Self::Commit { .. } => "commit",
+            Self::Reveal { .. } => "reveal",
+            Self::Cheating { .. } => "cheating",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ProtocolError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Disconnected,
+    UnexpectedMessage {
+        expected: &'static str,
+        got:      &'static str,
+    },
+    VersionMismatch {
+        ours:   u32,
+        theirs: u32,
+    },
+    RoundMismatch {
+        expected: usize,
+        got:      usize,
+    },
+    InvalidOptions(BuilderError),
+    /// The other side revealed a choice that does not match its commitment.
+    CheatingDetected {
+        round: usize,
+    },
+    /// The other side claims our reveal did not match our commitment.
+    AccusedOfCheating {
+        round: usize,
+    },
+    /// Our own player could not choose a move, e.g. because its input ended.
+    PlayerFailed(StrategyError),
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Connection error: {e}"),
+            Self::Json(e) => write!(f, "Malformed message: {e}"),
+            Self::Disconnected => write!(f, "The other player disconnected"),
+            Self::UnexpectedMessage { expected, got } => {
+                write!(f, "Expected a {expected} message but received {got}")
+            }
+            Self::VersionMismatch { ours, theirs } => {
+                write!(
+                    f,
+                    "Protocol version {theirs} is not supported; this tactix speaks version {ours}"
+                )
+            }
+            Self::RoundMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Expected a move for round {expected} but received round {got}"
+                )
+            }
+            Self::InvalidOptions(e) => write!(f, "The host sent an invalid game: {e}"),
+            Self::CheatingDetected { round } => {
+                write!(
+                    f,
+                    "The other player's move in round {round} does not match their commitment; \
+                     the match was stopped"
+                )
+            }
+            Self::AccusedOfCheating { round } => {
+                write!(
+                    f,
+                    "The other player rejected our move in round {round} as not matching its \
+                     commitment"
+                )
+            }
+            Self::PlayerFailed(e) => write!(f, "Could not play: {e}"),
+        }
+    }
+}
+
+impl Error for ProtocolError {}
+
+impl From<io::Error> for ProtocolError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ProtocolError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl From<BuilderError> for ProtocolError {
+    fn from(e: BuilderError) -> Self {
+        Self::InvalidOptions(e)
+    }
+}
+
+/// Returns a fresh random nonce, hex encoded.
+pub fn nonce() -> String {
+    let mut nonce = [0_u8; NONCE_LENGTH];
+
+    rand::rng().fill_bytes(&mut nonce);
+
+    hex::encode(nonce)
+}
+
+/// Returns the commitment of `seat` to `choice` in `round`, hex encoded.
+///
+/// The round is part of the hash so that a commitment from one round can not
+/// be replayed in another, and the seat so that one side's commitment can
+/// not be passed off as the other's.
+pub fn commitment(seat: Seat, round: usize, choice: Choice, nonce: &str) -> String {
+    let mut hasher = Sha256::new();
+
+    hasher.update(format!("{}:{round}:{choice}:{nonce}", seat.name()));
+
+    hex::encode(hasher.finalize())
+}
+
+/// A line-delimited JSON connection to the other player.
+pub struct Connection {
+    lines:  Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl Connection {
+    pub fn new(stream: TcpStream) -> Self {
+        let (reader, writer) = stream.into_split();
+
+        Self {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        }
+    }
+
+    pub async fn send(&mut self, message: &Message) -> Result<(), ProtocolError> {
+        let mut line = serde_json::to_string(message)?;
+        line.push('\n');
+
+        self.writer.write_all(line.as_bytes()).await?;
+        self.writer.flush().await?;
+
+        Ok(())
+    }
+
+    pub async fn recv(&mut self) -> Result<Message, ProtocolError> {
+        let line = self
+            .lines
+            .next_line()
+            .await?
+            .ok_or(ProtocolError::Disconnected)?;
+
+        Ok(serde_json::from_str(&line)?)
+    }
+}
+
+/// Which side of the match the local player is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seat {
+    Aleph,
+    Beth,
+}
+
+impl Seat {
+    /// The name hashed into the commitments of this seat.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Aleph => "aleph",
+            Self::Beth => "beth",
+        }
+    }
+
+    /// The seat of the other player.
+    pub const fn other(self) -> Self {
+        match self {
+            Self::Aleph => Self::Beth,
+            Self::Beth => Self::Aleph,
+        }
+    }
+}
+
+/// Waits for one player to connect and plays a match against them as Aleph.
+pub async fn host(
+    listener: TcpListener,
+    game_options: GameOptions,
+    rounds: usize,
+    player: &mut (dyn Strategy + Send),
+    report: &mut (dyn FnMut(&GameOptions, &MatchHistory) + Send),
+) -> Result<MatchHistory, ProtocolError> {
+    let (stream, _) = listener.accept().await?;
+    let mut connection = Connection::new(stream);
+
+    match connection.recv().await? {
+        Message::Hello {
+            version: PROTOCOL_VERSION,
+        } => (),
+        Message::Hello { version } => {
+            return Err(ProtocolError::VersionMismatch {
+                ours:   PROTOCOL_VERSION,
+                theirs: version,
+            })
+        }
+        other => return Err(unexpected("hello", &other)),
+    }
+
+    connection
+        .send(&Message::Options {
+            game: game_options.into(),
+            rounds,
+        })
+        .await?;
+
+    play(
+        &mut connection,
+        game_options,
+        rounds,
+        Seat::Aleph,
+        player,
+        report,
+    )
+    .await
+}
+
+/// Connects to a host and plays a match against them as Beth.
+///
+/// Returns the game the host chose along with the match history.
+pub async fn join(
+    stream: TcpStream,
+    player: &mut (dyn Strategy + Send),
+    report: &mut (dyn FnMut(&GameOptions, &MatchHistory) + Send),
+) -> Result<(GameOptions, MatchHistory), ProtocolError> {
+    let mut connection = Connection::new(stream);
+
+    connection
+        .send(&Message::Hello {
+            version: PROTOCOL_VERSION,
+        })
+        .await?;
+
+    let (game_options, rounds) = match connection.recv().await? {
+        Message::Options { game, rounds } => (GameOptions::try_from(game)?, rounds),
+        other => return Err(unexpected("options", &other)),
+    };
+
+    report(&game_options, &MatchHistory::new());
+
+    let history = play(
+        &mut connection,
+        game_options,
+        rounds,
+        Seat::Beth,
+        player,
+        report,
+    )
+    .await?;
+
+    Ok((game_options, history))
+}
+
+async fn play(
+    connection: &mut Connection,
+    game_options: GameOptions,
+    rounds: usize,
+    seat: Seat,
+    player: &mut (dyn Strategy + Send),
+    report: &mut (dyn FnMut(&GameOptions, &MatchHistory) + Send),
+) -> Result<MatchHistory, ProtocolError> {
+    let game_grid = GameGrid::new(game_options);
+    let mut history = MatchHistory::new();
+
+    player.reset();
+
+    for round in 1..=rounds {
+        let (own_history, opponent_history) = match seat {
+            Seat::Aleph => (history.aleph_choices(), history.beth_choices()),
+            Seat::Beth => (history.beth_choices(), history.aleph_choices()),
+        };
+
+        // Human players block on stdin, so let the runtime move other work
+        // off this thread while they decide.
+        let choice =
+            tokio::task::block_in_place(|| player.next_choice(own_history, opponent_history));
+
+        if let Some(error) = player.error() {
+            return Err(ProtocolError::PlayerFailed(error.clone()));
+        }
+
+        let opponent_choice = exchange(connection, seat, round, choice).await?;
+
+        match seat {
+            Seat::Aleph => game_grid.play_round(&mut history, choice, opponent_choice),
+            Seat::Beth => game_grid.play_round(&mut history, opponent_choice, choice),
+        };
+
+        report(&game_options, &history);
+    }
+
+    Ok(history)
+}
+
+/// Commits to `choice`, waits for the other side's commitment, then reveals
+/// and verifies the other side's choice.
+async fn exchange(
+    connection: &mut Connection,
+    seat: Seat,
+    round: usize,
+    choice: Choice,
+) -> Result<Choice, ProtocolError> {
+    let nonce = nonce();
+    let own_hash = commitment(seat, round, choice, &nonce);
+
+    connection
+        .send(&Message::Commit {
+            round,
+            hash: own_hash.clone(),
+        })
+        .await?;
+
+    let hash = match connection.recv().await? {
+        Message::Commit {
+            round: received,
+            hash,
+        } => {
+            check_round(round, received)?;
+
+            hash
+        }
+        other => return Err(unexpected("commit", &other)),
+    };
+
+    // An echo of our own commitment can only be followed by an echo of our
+    // reveal, so stop before revealing anything.
+    if hash == own_hash {
+        return Err(accuse(connection, round).await);
+    }
+
+    connection
+        .send(&Message::Reveal {
+            round,
+            choice,
+            nonce: nonce.clone(),
+        })
+        .await?;
+
+    let (opponent_choice, opponent_nonce) = match connection.recv().await? {
+        Message::Reveal {
+            round: received,
+            choice,
+            nonce,
+        } => {
+            check_round(round, received)?;
+
+            (choice, nonce)
+        }
+        other => return Err(unexpected("reveal", &other)),
+    };
+
+    if opponent_nonce == nonce
+        || commitment(seat.other(), round, opponent_choice, &opponent_nonce) != hash
+    {
+        return Err(accuse(connection, round).await);
+    }
+
+    Ok(opponent_choice)
+}
+
+/// Tells the other side it was caught cheating in `round`, returning the
+/// error that ends the match.
+async fn accuse(connection: &mut Connection, round: usize) -> ProtocolError {
+    // The match is over either way, so a failure to deliver the accusation
+    // is not worth reporting over the cheating itself.
+    let _ = connection.send(&Message::Cheating { round }).await;
+
+    ProtocolError::CheatingDetected { round }
+}
+
+const fn check_round(expected: usize, got: usize) -> Result<(), ProtocolError> {
+    if expected == got {
+        Ok(())
+    } else {
+        Err(ProtocolError::RoundMismatch { expected, got })
+    }
+}
+
+fn unexpected(expected: &'static str, message: &Message) -> ProtocolError {
+    match *message {
+        Message::Cheating { round } => ProtocolError::AccusedOfCheating { round },
+        _ => ProtocolError::UnexpectedMessage {
+            expected,
+            got: message.kind(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dilemma_tactix_lib::{
+        AlwaysDefect,
+        CustomizedBuilder,
+        TitForTat,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_message_format() {
+        let message = Message::Reveal {
+            round:  3,
+            choice: Choice::Olympus,
+            nonce:  "00ff".to_string(),
+        };
+
+        let line = serde_json::to_string(&message).unwrap();
+
+        assert_eq!(
+            line,
+            r#"{"type":"reveal","round":3,"choice":"Olympus","nonce":"00ff"}"#
+        );
+        assert_eq!(serde_json::from_str::<Message>(&line).unwrap(), message);
+    }
+
+    #[test]
+    fn test_commitment() {
+        let nonce = nonce();
+
+        assert_eq!(nonce.len(), NONCE_LENGTH * 2);
+        assert_ne!(nonce, super::nonce());
+
+        let hash = commitment(Seat::Aleph, 1, Choice::Atlantis, &nonce);
+
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, commitment(Seat::Aleph, 1, Choice::Atlantis, &nonce));
+        assert_ne!(hash, commitment(Seat::Aleph, 1, Choice::Olympus, &nonce));
+        assert_ne!(hash, commitment(Seat::Aleph, 2, Choice::Atlantis, &nonce));
+        assert_ne!(hash, commitment(Seat::Beth, 1, Choice::Atlantis, &nonce));
+    }
+
+    #[test]
+    fn test_wire_options_round_trip() {
+        let game_options = CustomizedBuilder::from_preset("stag_hunt")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let wire = WireOptions::from(game_options);
+
+        assert_eq!(GameOptions::try_from(wire).unwrap(), game_options);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_match_over_localhost() {
+        let game_options = CustomizedBuilder::from_preset("prisoners_dilemma")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let host = tokio::spawn(async move {
+            host(
+                listener,
+                game_options,
+                4,
+                &mut TitForTat,
+                &mut |_: &GameOptions, _: &MatchHistory| {},
+            )
+            .await
+        });
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        let (joined_options, joined_history) = join(
+            stream,
+            &mut AlwaysDefect,
+            &mut |_: &GameOptions, _: &MatchHistory| {},
+        )
+        .await
+        .unwrap();
+
+        let hosted_history = host.await.unwrap().unwrap();
+
+        assert_eq!(joined_options, game_options);
+        assert_eq!(joined_history, hosted_history);
+        assert_eq!(
+            hosted_history.aleph_choices(),
+            &[
+                Choice::Atlantis,
+                Choice::Olympus,
+                Choice::Olympus,
+                Choice::Olympus
+            ]
+        );
+        assert_eq!(hosted_history.beth_total(), 8);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_version_mismatch() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let host = tokio::spawn(async move {
+            host(
+                listener,
+                GameOptions::default(),
+                1,
+                &mut TitForTat,
+                &mut |_: &GameOptions, _: &MatchHistory| {},
+            )
+            .await
+        });
+
+        let mut connection = Connection::new(TcpStream::connect(address).await.unwrap());
+        connection
+            .send(&Message::Hello { version: 99 })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            host.await.unwrap(),
+            Err(ProtocolError::VersionMismatch {
+                ours:   PROTOCOL_VERSION,
+                theirs: 99,
+            })
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_changed_reveal_is_flagged() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let host = tokio::spawn(async move {
+            host(
+                listener,
+                GameOptions::default(),
+                1,
+                &mut AlwaysDefect,
+                &mut |_: &GameOptions, _: &MatchHistory| {},
+            )
+            .await
+        });
+
+        let mut cheater = Connection::new(TcpStream::connect(address).await.unwrap());
+        cheater
+            .send(&Message::Hello {
+                version: PROTOCOL_VERSION,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            cheater.recv().await.unwrap(),
+            Message::Options { .. }
+        ));
+
+        let nonce = nonce();
+        cheater
+            .send(&Message::Commit {
+                round: 1,
+                hash:  commitment(Seat::Beth, 1, Choice::Atlantis, &nonce),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            cheater.recv().await.unwrap(),
+            Message::Commit { round: 1, .. }
+        ));
+
+        // Having seen the host commit, switch to defecting
+        cheater
+            .send(&Message::Reveal {
+                round: 1,
+                choice: Choice::Olympus,
+                nonce,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            cheater.recv().await.unwrap(),
+            Message::Reveal { round: 1, .. }
+        ));
+        assert_eq!(
+            cheater.recv().await.unwrap(),
+            Message::Cheating { round: 1 }
+        );
+
+        assert!(matches!(
+            host.await.unwrap(),
+            Err(ProtocolError::CheatingDetected { round: 1 })
+        ));
+    }
+
+    /// Joins a match by hand, returning the connection and the host's
+    /// commitment for the first round.
+    async fn join_with_commit(address: std::net::SocketAddr) -> (Connection, Message) {
+        let mut copier = Connection::new(TcpStream::connect(address).await.unwrap());
+
+        copier
+            .send(&Message::Hello {
+                version: PROTOCOL_VERSION,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            copier.recv().await.unwrap(),
+            Message::Options { .. }
+        ));
+
+        let commit = copier.recv().await.unwrap();
+
+        (copier, commit)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_copied_commitment_is_flagged() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let host = tokio::spawn(async move {
+            host(
+                listener,
+                GameOptions::default(),
+                1,
+                &mut AlwaysDefect,
+                &mut |_: &GameOptions, _: &MatchHistory| {},
+            )
+            .await
+        });
+
+        // Echo the host's commitment, hoping to echo its reveal next.
+        let (mut copier, commit) = join_with_commit(address).await;
+        copier.send(&commit).await.unwrap();
+
+        assert_eq!(copier.recv().await.unwrap(), Message::Cheating { round: 1 });
+        assert!(matches!(
+            host.await.unwrap(),
+            Err(ProtocolError::CheatingDetected { round: 1 })
+        ));
+    }
+}
@@ -0,0 +1,144 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::io::BufRead;
+
+use dilemma_tactix_lib::{
+    Choice,
+    Strategy,
+    StrategyError,
+};
+
+use crate::input::{
+    read_hidden_input,
+    read_line,
+    read_user_input,
+};
+
+pub fn parse_choice(choice: &str) -> Option<Choice> {
+    match choice.to_uppercase().as_str() {
+        "A" => Some(Choice::Atlantis),
+        "B" => Some(Choice::Olympus),
+        _ => None,
+    }
+}
+
+/// A person choosing moves at the terminal.
+///
+/// In hot-seat games both players share the terminal, so their input is
+/// masked to keep it from the other player until the round is revealed.
+///
+/// If the input ends the player fails, forfeiting the match, as nobody is
+/// left to choose.
+pub struct HumanPlayer {
+    name:   String,
+    hidden: bool,
+    /// Where moves are read from, if not the terminal.
+    input:  Option<Box<dyn BufRead + Send>>,
+    error:  Option<StrategyError>,
+}
+
+impl HumanPlayer {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name:   name.into(),
+            hidden: false,
+            input:  None,
+            error:  None,
+        }
+    }
+
+    pub fn hidden(name: impl Into<String>) -> Self {
+        Self {
+            hidden: true,
+            ..Self::new(name)
+        }
+    }
+
+    #[cfg(test)]
+    fn reading(name: impl Into<String>, input: impl BufRead + Send + 'static) -> Self {
+        Self {
+            input: Some(Box::new(input)),
+            ..Self::new(name)
+        }
+    }
+
+    fn read(&mut self, prompt: &str) -> Option<String> {
+        match &mut self.input {
+            Some(input) => read_line(input),
+            None if self.hidden => read_hidden_input(prompt),
+            None => read_user_input(prompt),
+        }
+    }
+}
+
+impl Strategy for HumanPlayer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_choice(&mut self, own_history: &[Choice], _opponent_history: &[Choice]) -> Choice {
+        let prompt = format!("{}, enter your choice (A or B): ", self.name);
+
+        while self.error.is_none() {
+            let Some(choice) = self.read(&prompt) else {
+                self.error = Some(StrategyError::Crashed {
+                    round:  own_history.len() + 1,
+                    status: "input ended".to_string(),
+                });
+
+                break;
+            };
+
+            match parse_choice(choice.trim()) {
+                Some(choice) => return choice,
+                None => println!("Invalid choice, please enter A or B"),
+            }
+        }
+
+        // The match is forfeited, so the choice does not count.
+        Choice::Atlantis
+    }
+
+    fn error(&self) -> Option<&StrategyError> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("A", Some(Choice::Atlantis))]
+    #[case("b", Some(Choice::Olympus))]
+    #[case("C", None)]
+    #[case("", None)]
+    fn test_parse_choice(#[case] input: &str, #[case] expected: Option<Choice>) {
+        assert_eq!(parse_choice(input), expected);
+    }
+
+    #[test]
+    fn test_reads_until_valid_choice() {
+        let mut player = HumanPlayer::reading("You", "x\nb\n".as_bytes());
+
+        assert_eq!(player.next_choice(&[], &[]), Choice::Olympus);
+        assert!(player.error().is_none());
+    }
+
+    #[test]
+    fn test_empty_input_forfeits() {
+        let mut player = HumanPlayer::reading("You", std::io::empty());
+
+        player.next_choice(&[Choice::Atlantis], &[Choice::Olympus]);
+
+        assert!(matches!(
+            player.error(),
+            Some(StrategyError::Crashed { round: 2, .. })
+        ));
+    }
+}
@@ -2,3 +2,36 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
+
+use clap::Parser;
+
+/// Drive the Dilemma Tactix simulator from other programs.
+///
+/// With `--rpc`, requests are read from standard input and answered on
+/// standard output as JSON-RPC 2.0, one message per line, until standard
+/// input is closed.
+#[derive(Debug, Clone, Default, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Speak JSON-RPC 2.0 on standard input and output.
+    #[arg(long)]
+    pub rpc: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn test_cli_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_rpc_flag() {
+        assert!(Cli::parse_from(["tactix-bench", "--rpc"]).rpc);
+        assert!(!Cli::parse_from(["tactix-bench"]).rpc);
+    }
+}
@@ -3,6 +3,33 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-fn main() {
-    println!("Dilemma Tactix Bench");
+mod cli;
+mod rpc;
+
+use std::{
+    io,
+    process::ExitCode,
+};
+
+use clap::Parser;
+use cli::Cli;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if !cli.rpc {
+        println!("Dilemma Tactix Bench");
+        println!("Run with --rpc to drive the simulator over JSON-RPC on stdin and stdout.");
+
+        return ExitCode::SUCCESS;
+    }
+
+    match rpc::serve(io::stdin().lock(), io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+
+            ExitCode::FAILURE
+        }
+    }
 }
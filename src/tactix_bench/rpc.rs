@@ -0,0 +1,657 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! A JSON-RPC 2.0 interface to the simulator over standard input and output.
+//!
+//! Each line of input is one request, notification or batch, and each answer
+//! is written as one line of output. Notifications are run but never answered.
+//!
+//! | Method | Params | Result |
+//! | ------ | ------ | ------ |
+//! | `build_game` | game parameters | `{game, class}` |
+//! | `classify_game` | `{game}` | `{class}` |
+//! | `score` | `{game, aleph, beth}` | `{aleph, beth, score}` |
+//! | `play_match` | `{game, aleph, beth, rounds, seed?}` | totals, winner and rounds |
+//! | `play_tournament` | `{game, strategies, rounds, seed?}` | `{standings, matches}` |
+//! | `list_strategies` | none | strategy names |
+//! | `list_presets` | none | preset names |
+//!
+//! A `game` is given as the parameters of [`GameParams`]. The `game` returned
+//! by `build_game` is itself valid game parameters, so a randomized game can be
+//! built once and then passed to the other methods.
+//!
+//! The `aleph`, `beth` and `strategies` are each the name of a built-in
+//! strategy, `script:<path>` or `plugin:<path>`, as described for
+//! [`StrategySource`], so scripts and plugins can be entered into matches and
+//! tournaments alongside the built-in strategies.
+
+use std::io::{
+    self,
+    BufRead,
+    Write,
+};
+
+use dilemma_tactix_lib::{
+    BuilderError,
+    Choice,
+    GameClass,
+    GameGrid,
+    GameOptions,
+    GameParams,
+    GamePresets,
+    MatchHistory,
+    NumberPair,
+    Player,
+    Round,
+    Standing,
+    Strategy,
+    StrategyError,
+    StrategyKind,
+    StrategySource,
+    Tournament,
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    json,
+    Value,
+};
+
+/// The most rounds a single match may be asked to play.
+pub const MAX_ROUNDS: usize = 1_000_000;
+
+/// The most strategies a single tournament may be asked to play.
+pub const MAX_ENTRANTS: usize = 32;
+
+/// A JSON-RPC error object.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RpcError {
+    code:    i64,
+    message: String,
+}
+
+impl RpcError {
+    const INTERNAL_ERROR: i64 = -32603;
+    const INVALID_PARAMS: i64 = -32602;
+    const INVALID_REQUEST: i64 = -32600;
+    const METHOD_NOT_FOUND: i64 = -32601;
+    const PARSE_ERROR: i64 = -32700;
+
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(Self::INVALID_PARAMS, message)
+    }
+}
+
+impl From<BuilderError> for RpcError {
+    fn from(error: BuilderError) -> Self {
+        Self::invalid_params(error.to_string())
+    }
+}
+
+impl From<StrategyError> for RpcError {
+    fn from(error: StrategyError) -> Self {
+        Self::invalid_params(error.to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GameRequest {
+    game: GameParams,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScoreRequest {
+    game:  GameParams,
+    aleph: String,
+    beth:  String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MatchRequest {
+    game:   GameParams,
+    aleph:  String,
+    beth:   String,
+    rounds: usize,
+    seed:   Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TournamentRequest {
+    game:       GameParams,
+    strategies: Vec<String>,
+    rounds:     usize,
+    seed:       Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct BuildResult {
+    game:  GameOptions,
+    class: GameClass,
+}
+
+#[derive(Debug, Serialize)]
+struct ScoreResult {
+    aleph: Choice,
+    beth:  Choice,
+    score: NumberPair,
+}
+
+#[derive(Debug, Serialize)]
+struct MatchResult {
+    aleph:       String,
+    beth:        String,
+    aleph_total: u64,
+    beth_total:  u64,
+    winner:      Option<Player>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rounds:      Option<Vec<Round>>,
+}
+
+impl MatchResult {
+    fn new(aleph: &str, beth: &str, history: &MatchHistory) -> Self {
+        Self {
+            aleph:       aleph.to_string(),
+            beth:        beth.to_string(),
+            aleph_total: history.aleph_total(),
+            beth_total:  history.beth_total(),
+            winner:      history.winner(),
+            rounds:      None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TournamentResult {
+    standings: Vec<Standing>,
+    matches:   Vec<MatchResult>,
+}
+
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::invalid_params(e.to_string()))
+}
+
+fn check_rounds(rounds: usize) -> Result<(), RpcError> {
+    if (1..=MAX_ROUNDS).contains(&rounds) {
+        Ok(())
+    } else {
+        Err(RpcError::invalid_params(format!(
+            "rounds must be between 1 and {MAX_ROUNDS}"
+        )))
+    }
+}
+
+fn parse_choice(game_options: &GameOptions, choice: &str) -> Result<Choice, RpcError> {
+    game_options.parse_choice(choice).ok_or_else(|| {
+        RpcError::invalid_params(format!(
+            "Unknown choice {choice}; expected A, B, {} or {}",
+            game_options.choice_atlantis(),
+            game_options.choice_olympus()
+        ))
+    })
+}
+
+/// Creates the strategy named by `source`, loading it if it is a script or
+/// plugin.
+fn strategy(
+    source: &str,
+    game_options: GameOptions,
+    seed: Option<u64>,
+) -> Result<Box<dyn Strategy + Send>, RpcError> {
+    Ok(source
+        .parse::<StrategySource>()?
+        .strategy(game_options, seed)?)
+}
+
+fn to_value(result: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(result).map_err(|e| RpcError::new(RpcError::INTERNAL_ERROR, e.to_string()))
+}
+
+fn build_game(game: GameParams) -> Result<Value, RpcError> {
+    let game = game.build()?;
+
+    to_value(BuildResult {
+        game,
+        class: game.classify(),
+    })
+}
+
+fn classify_game(request: GameRequest) -> Result<Value, RpcError> {
+    let game = request.game.build()?;
+
+    to_value(json!({ "class": game.classify() }))
+}
+
+fn score(request: ScoreRequest) -> Result<Value, RpcError> {
+    let game = request.game.build()?;
+
+    let aleph = parse_choice(&game, &request.aleph)?;
+    let beth = parse_choice(&game, &request.beth)?;
+
+    to_value(ScoreResult {
+        aleph,
+        beth,
+        score: GameGrid::new(game).return_score(aleph, beth),
+    })
+}
+
+fn play_match(request: MatchRequest) -> Result<Value, RpcError> {
+    let game = request.game.build()?;
+
+    check_rounds(request.rounds)?;
+
+    let mut aleph = strategy(&request.aleph, game, request.seed)?;
+    let mut beth = strategy(&request.beth, game, request.seed)?;
+
+    let history = GameGrid::new(game).play_match(aleph.as_mut(), beth.as_mut(), request.rounds);
+
+    to_value(MatchResult {
+        rounds: Some(history.rounds().collect()),
+        ..MatchResult::new(aleph.name(), beth.name(), &history)
+    })
+}
+
+fn play_tournament(request: TournamentRequest) -> Result<Value, RpcError> {
+    let game = request.game.build()?;
+
+    check_rounds(request.rounds)?;
+
+    if !(2..=MAX_ENTRANTS).contains(&request.strategies.len()) {
+        return Err(RpcError::invalid_params(format!(
+            "a tournament needs between 2 and {MAX_ENTRANTS} strategies"
+        )));
+    }
+
+    let mut entrants = request
+        .strategies
+        .iter()
+        .map(|source| strategy(source, game, request.seed))
+        .collect::<Result<Vec<_>, RpcError>>()?;
+
+    let tournament = Tournament::round_robin(&GameGrid::new(game), &mut entrants, request.rounds);
+
+    let names = tournament.names();
+
+    to_value(TournamentResult {
+        standings: tournament.standings(),
+        matches:   tournament
+            .pairings()
+            .iter()
+            .map(|pairing| {
+                MatchResult::new(
+                    &names[pairing.aleph],
+                    &names[pairing.beth],
+                    &pairing.history,
+                )
+            })
+            .collect(),
+    })
+}
+
+/// Runs a method with its params, which are `null` when none were given.
+fn dispatch(method: &str, params_value: Value) -> Result<Value, RpcError> {
+    // Methods that take an object treat missing params as an empty object.
+    let object = if params_value.is_null() {
+        json!({})
+    } else {
+        params_value
+    };
+
+    match method {
+        "build_game" => build_game(params(object)?),
+        "classify_game" => classify_game(params(object)?),
+        "score" => score(params(object)?),
+        "play_match" => play_match(params(object)?),
+        "play_tournament" => play_tournament(params(object)?),
+        "list_strategies" => to_value(
+            StrategyKind::ALL
+                .iter()
+                .map(StrategyKind::name)
+                .collect::<Vec<_>>(),
+        ),
+        "list_presets" => to_value(GamePresets::preset_names()),
+        _ => Err(RpcError::new(
+            RpcError::METHOD_NOT_FOUND,
+            format!("Method not found: {method}"),
+        )),
+    }
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    }
+}
+
+/// Answers a single request, or returns `None` for a notification.
+fn handle_request(request: Value) -> Option<Value> {
+    let Value::Object(mut request) = request else {
+        return Some(response(
+            Value::Null,
+            Err(RpcError::new(
+                RpcError::INVALID_REQUEST,
+                "Invalid request: expected an object",
+            )),
+        ));
+    };
+
+    // A request without an id is a notification, which is never answered.
+    let id = request.remove("id");
+
+    let method = match (request.remove("jsonrpc"), request.remove("method")) {
+        (Some(Value::String(version)), Some(Value::String(method))) if version == "2.0" => method,
+        _ => {
+            return Some(response(
+                id.unwrap_or(Value::Null),
+                Err(RpcError::new(
+                    RpcError::INVALID_REQUEST,
+                    "Invalid request: expected jsonrpc \"2.0\" and a method",
+                )),
+            ))
+        }
+    };
+
+    let params = request.remove("params").unwrap_or(Value::Null);
+
+    let result = if params.is_null() || params.is_object() {
+        dispatch(&method, params)
+    } else {
+        Err(RpcError::invalid_params("params must be given by name"))
+    };
+
+    id.map(|id| response(id, result))
+}
+
+/// Answers one line of input, or returns `None` if there is nothing to
+/// answer.
+pub fn handle_line(line: &str) -> Option<Value> {
+    let message = match serde_json::from_str::<Value>(line) {
+        Ok(message) => message,
+        Err(e) => {
+            return Some(response(
+                Value::Null,
+                Err(RpcError::new(
+                    RpcError::PARSE_ERROR,
+                    format!("Parse error: {e}"),
+                )),
+            ))
+        }
+    };
+
+    match message {
+        Value::Array(batch) if batch.is_empty() => Some(response(
+            Value::Null,
+            Err(RpcError::new(
+                RpcError::INVALID_REQUEST,
+                "Invalid request: empty batch",
+            )),
+        )),
+        Value::Array(batch) => {
+            let responses: Vec<Value> = batch.into_iter().filter_map(handle_request).collect();
+
+            (!responses.is_empty()).then_some(Value::Array(responses))
+        }
+        request => handle_request(request),
+    }
+}
+
+/// Answers requests read from `input` on `output` until `input` ends.
+///
+/// # Errors
+///
+/// Returns an error if reading or writing fails.
+pub fn serve(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = handle_line(&line) {
+            writeln!(output, "{response}")?;
+            output.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn call(method: &str, params: Value) -> Value {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+
+        handle_line(&request.to_string()).unwrap()
+    }
+
+    fn error_code(response: &Value) -> i64 {
+        response["error"]["code"].as_i64().unwrap()
+    }
+
+    #[test]
+    fn test_build_game() {
+        let response = call("build_game", json!({"preset": "stag_hunt"}));
+
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["class"], "stag_hunt");
+        assert_eq!(response["result"]["game"]["choice_olympus"], "war");
+    }
+
+    #[test]
+    fn test_built_game_can_be_reused() {
+        let built = call("build_game", json!({"seed": 11}));
+        let game = built["result"]["game"].clone();
+
+        let classified = call("classify_game", json!({ "game": game }));
+
+        assert_eq!(classified["result"]["class"], built["result"]["class"]);
+    }
+
+    #[rstest]
+    #[case("A", "B", json!({"first": 0, "second": 5}))]
+    #[case("defect", "cooperate", json!({"first": 5, "second": 0}))]
+    fn test_score(#[case] aleph: &str, #[case] beth: &str, #[case] expected: Value) {
+        let response = call(
+            "score",
+            json!({"game": {"preset": "prisoners_dilemma"}, "aleph": aleph, "beth": beth}),
+        );
+
+        assert_eq!(response["result"]["score"], expected);
+    }
+
+    #[test]
+    fn test_play_match() {
+        let response = call(
+            "play_match",
+            json!({
+                "game": {"preset": "prisoners_dilemma"},
+                "aleph": "tit-for-tat",
+                "beth": "always-defect",
+                "rounds": 3,
+            }),
+        );
+
+        assert_eq!(response["result"]["aleph_total"], 2);
+        assert_eq!(response["result"]["beth_total"], 7);
+        assert_eq!(response["result"]["rounds"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_play_tournament() {
+        let response = call(
+            "play_tournament",
+            json!({
+                "game": {"preset": "prisoners_dilemma"},
+                "strategies": ["tit-for-tat", "always-defect", "always-cooperate"],
+                "rounds": 10,
+            }),
+        );
+
+        assert_eq!(response["result"]["standings"][0]["name"], "always-defect");
+        assert_eq!(response["result"]["matches"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_play_tournament_with_files() {
+        let dir = std::env::temp_dir();
+        let script = dir.join(format!("tactix-bench-script-{}.rhai", std::process::id()));
+        let plugin = dir.join(format!("tactix-bench-plugin-{}.wasm", std::process::id()));
+
+        std::fs::write(
+            &script,
+            r#"fn next_choice(own, opponent, round, game) { "B" }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &plugin,
+            wat::parse_str(
+                r#"(module
+                    (memory (export "memory") 1)
+                    (func (export "alloc") (param i32) (result i32) i32.const 0)
+                    (func (export "next_move") (param i32 i32) (result i32) i32.const 1))"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let response = call(
+            "play_tournament",
+            json!({
+                "game": {"preset": "prisoners_dilemma"},
+                "strategies": [
+                    format!("script:{}", script.display()),
+                    format!("plugin:{}", plugin.display()),
+                    "always-cooperate",
+                ],
+                "rounds": 10,
+            }),
+        );
+
+        std::fs::remove_file(&script).unwrap();
+        std::fs::remove_file(&plugin).unwrap();
+
+        let standings = &response["result"]["standings"];
+
+        assert_eq!(standings[0]["total"], 60);
+        assert_eq!(standings[1]["total"], 60);
+        assert_eq!(standings[2]["name"], "always-cooperate");
+    }
+
+    #[rstest]
+    #[case("build_game", json!({"preset": "nonexistent"}))]
+    #[case("build_game", json!({"colour": "blue"}))]
+    #[case("score", json!({"game": {}, "aleph": "C", "beth": "A"}))]
+    #[case("play_match", json!({"game": {}, "aleph": "nobody", "beth": "pavlov", "rounds": 3}))]
+    #[case("play_match", json!({"game": {}, "aleph": "grudger", "beth": "pavlov", "rounds": 0}))]
+    #[case(
+        "play_match",
+        json!({"game": {}, "aleph": "script:/no/such/script.rhai", "beth": "pavlov", "rounds": 3})
+    )]
+    #[case(
+        "play_match",
+        json!({"game": {}, "aleph": "pavlov", "beth": "plugin:/no/such/plugin.wasm", "rounds": 3})
+    )]
+    #[case("play_tournament", json!({"game": {}, "strategies": ["pavlov"], "rounds": 3}))]
+    #[case(
+        "play_tournament",
+        json!({"game": {}, "strategies": vec!["pavlov"; MAX_ENTRANTS + 1], "rounds": 3})
+    )]
+    #[case("build_game", json!([1, 2]))]
+    fn test_invalid_params(#[case] method: &str, #[case] params: Value) {
+        assert_eq!(error_code(&call(method, params)), RpcError::INVALID_PARAMS);
+    }
+
+    #[rstest]
+    #[case("not json", RpcError::PARSE_ERROR)]
+    #[case("[]", RpcError::INVALID_REQUEST)]
+    #[case("42", RpcError::INVALID_REQUEST)]
+    #[case(
+        r#"{"jsonrpc": "1.0", "id": 1, "method": "list_presets"}"#,
+        RpcError::INVALID_REQUEST
+    )]
+    #[case(
+        r#"{"jsonrpc": "2.0", "id": 1, "method": "explode"}"#,
+        RpcError::METHOD_NOT_FOUND
+    )]
+    fn test_protocol_errors(#[case] line: &str, #[case] expected: i64) {
+        assert_eq!(error_code(&handle_line(line).unwrap()), expected);
+    }
+
+    #[test]
+    fn test_notifications_are_not_answered() {
+        assert_eq!(
+            handle_line(r#"{"jsonrpc": "2.0", "method": "list_presets"}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_batch() {
+        let response = handle_line(
+            r#"[
+                {"jsonrpc": "2.0", "id": "a", "method": "list_strategies"},
+                {"jsonrpc": "2.0", "method": "list_presets"},
+                {"jsonrpc": "2.0", "id": "b", "method": "list_presets"}
+            ]"#,
+        )
+        .unwrap();
+
+        let ids: Vec<&Value> = response
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|response| &response["id"])
+            .collect();
+
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_serve() {
+        let input = concat!(
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "list_strategies"}"#,
+            "\n\n",
+            r#"{"jsonrpc": "2.0", "method": "list_presets"}"#,
+            "\n",
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "classify_game", "params": {"game": {"preset": "chicken"}}}"#,
+            "\n",
+        );
+
+        let mut output = Vec::new();
+
+        serve(Cursor::new(input), &mut output).unwrap();
+
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["id"], 1);
+        assert_eq!(lines[1]["result"]["class"], "chicken");
+    }
+}
@@ -0,0 +1,1116 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    path::{
+        Path as FilePath,
+        PathBuf,
+    },
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
+
+use axum::{
+    extract::{
+        rejection::PathRejection,
+        ws::WebSocketUpgrade,
+        FromRequest,
+        FromRequestParts,
+        Path,
+        State,
+    },
+    http::StatusCode,
+    response::Response,
+    routing::{
+        get,
+        post,
+    },
+    Json,
+    Router,
+};
+use dilemma_tactix_lib::{
+    Choice,
+    GameGrid,
+    GameOptions,
+    GameParams,
+    GamePresets,
+    MatchHistory,
+    NumberPair,
+    Player,
+    Round,
+    Standing,
+    Strategy,
+    StrategyKind,
+    StrategySource,
+    Tournament,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tokio::sync::broadcast;
+
+use crate::{
+    error::ApiError,
+    feed::{
+        self,
+        Event,
+        Feed,
+    },
+    games::Games,
+};
+
+/// The most rounds a single match may be asked to play.
+pub const MAX_ROUNDS: usize = 10_000;
+
+/// The most strategies a single tournament may be asked to play.
+pub const MAX_ENTRANTS: usize = 32;
+
+/// The longest a match may be asked to pause between rounds for spectators.
+pub const MAX_DELAY_MS: u64 = 5_000;
+
+/// The longest a match or tournament may be paused for spectators in all,
+/// counting the pause after every round it plays.
+pub const MAX_TOTAL_DELAY_MS: u64 = 60_000;
+
+/// The state shared by every request.
+#[derive(Debug, Clone, Default)]
+pub struct AppState {
+    games:        Arc<Games>,
+    /// The directory scripts and plugins entered as `script:<file>` and
+    /// `plugin:<file>` are loaded from. Neither can be entered without one.
+    strategy_dir: Option<Arc<FilePath>>,
+    /// The id of the last match or tournament played.
+    last_run:     Arc<AtomicU64>,
+}
+
+/// A JSON body whose rejection is reported as an [`ApiError`].
+#[derive(FromRequest)]
+#[from_request(via(Json), rejection(ApiError))]
+struct ApiJson<T>(T);
+
+/// The game id in the path, whose rejection is reported as an [`ApiError`].
+#[derive(Deserialize, FromRequestParts)]
+#[from_request(via(Path), rejection(ApiError))]
+struct GameId(u64);
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+impl AppState {
+    /// Creates the state of a server that loads the scripts and plugins
+    /// entered into matches and tournaments from `strategy_dir`.
+    pub fn with_strategy_dir(strategy_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            strategy_dir: Some(strategy_dir.into().into()),
+            ..Self::default()
+        }
+    }
+
+    fn game(&self, id: u64) -> Result<GameOptions, ApiError> {
+        self.games.get(id).ok_or(ApiError::GameNotFound(id))
+    }
+
+    /// Returns the feed for a new match or tournament in the game with the
+    /// given id, under a run id of its own.
+    fn feed(&self, id: u64, delay_ms: u64) -> Result<Feed, ApiError> {
+        if delay_ms > MAX_DELAY_MS {
+            return Err(ApiError::BadRequest(format!(
+                "delay_ms must be at most {MAX_DELAY_MS}"
+            )));
+        }
+
+        let sender = self.games.feed(id).ok_or(ApiError::GameNotFound(id))?;
+        let run = self.last_run.fetch_add(1, Ordering::Relaxed) + 1;
+
+        Ok(Feed::new(sender, run, Duration::from_millis(delay_ms)))
+    }
+
+    /// Returns a new spectator's view of the feed of the game with the given
+    /// id.
+    fn subscribe(&self, id: u64) -> Result<broadcast::Receiver<Event>, ApiError> {
+        self.games
+            .feed(id)
+            .map(|sender| sender.subscribe())
+            .ok_or(ApiError::GameNotFound(id))
+    }
+
+    /// Returns the files in the strategy directory with the given extension,
+    /// sorted by name.
+    fn strategy_files(&self, extension: &str) -> Vec<String> {
+        let Some(Ok(entries)) = self.strategy_dir.as_deref().map(std::fs::read_dir) else {
+            return Vec::new();
+        };
+
+        let mut files: Vec<String> = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| {
+                FilePath::new(name)
+                    .extension()
+                    .is_some_and(|e| e == extension)
+            })
+            .collect();
+
+        files.sort();
+
+        files
+    }
+
+    /// Creates the strategy named by `source`, which may be a built-in
+    /// strategy or a script or plugin in the strategy directory.
+    fn strategy(
+        &self,
+        source: &str,
+        game: GameOptions,
+        seed: Option<u64>,
+    ) -> Result<Box<dyn Strategy + Send>, ApiError> {
+        let source = match source.parse::<StrategySource>()? {
+            StrategySource::Script(file) => StrategySource::Script(self.strategy_file(&file)?),
+            StrategySource::Plugin(file) => StrategySource::Plugin(self.strategy_file(&file)?),
+            builtin @ StrategySource::Builtin(_) => builtin,
+        };
+
+        Ok(source.strategy(game, seed)?)
+    }
+
+    /// Finds `file` in the strategy directory. Clients only name files in it,
+    /// so no other file on the server can be loaded.
+    fn strategy_file(&self, file: &FilePath) -> Result<PathBuf, ApiError> {
+        let dir = self.strategy_dir.as_deref().ok_or_else(|| {
+            ApiError::BadRequest("This server does not load strategies from files".to_string())
+        })?;
+
+        let path = dir.join(file);
+
+        if file.file_name() == Some(file.as_os_str()) && path.is_file() {
+            Ok(path)
+        } else {
+            Err(ApiError::BadRequest(format!(
+                "No strategy file named {}",
+                file.display()
+            )))
+        }
+    }
+}
+
+/// Builds the routes of the API.
+///
+/// | Method | Path | Does |
+/// | ------ | ---- | ---- |
+/// | `GET` | `/strategies` | Lists the strategies, scripts and plugins matches can be played with. |
+/// | `GET` | `/presets` | Lists the presets games can be created from. |
+/// | `POST` | `/games` | Creates a game from builder parameters. |
+/// | `GET` | `/games/:id` | Returns a game. |
+/// | `GET` | `/games/:id/grid` | Returns the payoff grid of a game. |
+/// | `POST` | `/games/:id/score` | Scores a pair of choices. |
+/// | `POST` | `/games/:id/match` | Plays a match between two strategies. |
+/// | `POST` | `/games/:id/tournament` | Plays a round-robin tournament. |
+/// | `GET` | `/games/:id/watch` | Streams the rounds played in a game over a WebSocket. |
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/strategies", get(list_strategies))
+        .route("/presets", get(list_presets))
+        .route("/games", post(create_game))
+        .route("/games/:id", get(get_game))
+        .route("/games/:id/grid", get(get_grid))
+        .route("/games/:id/score", post(score))
+        .route("/games/:id/match", post(play_match))
+        .route("/games/:id/tournament", post(play_tournament))
+        .route("/games/:id/watch", get(watch))
+        .with_state(state)
+}
+
+#[derive(Debug, Serialize)]
+struct GameResponse {
+    id:   u64,
+    game: GameOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct GridResponse {
+    choices: [&'static str; 2],
+    /// The payoffs indexed by Aleph's choice, then Beth's choice.
+    scores:  [[NumberPair; 2]; 2],
+    table:   String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScoreRequest {
+    aleph: String,
+    beth:  String,
+}
+
+#[derive(Debug, Serialize)]
+struct ScoreResponse {
+    aleph: Choice,
+    beth:  Choice,
+    score: NumberPair,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MatchRequest {
+    aleph:    String,
+    beth:     String,
+    rounds:   usize,
+    seed:     Option<u64>,
+    /// How long to pause after each round, for spectators.
+    #[serde(default)]
+    delay_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct MatchResponse {
+    /// The run id the match was reported to spectators under, if it was
+    /// played on its own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run:         Option<u64>,
+    aleph:       String,
+    beth:        String,
+    aleph_total: u64,
+    beth_total:  u64,
+    winner:      Option<Player>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rounds:      Option<Vec<Round>>,
+}
+
+impl MatchResponse {
+    fn new(aleph: &str, beth: &str, history: &MatchHistory) -> Self {
+        Self {
+            run:         None,
+            aleph:       aleph.to_string(),
+            beth:        beth.to_string(),
+            aleph_total: history.aleph_total(),
+            beth_total:  history.beth_total(),
+            winner:      history.winner(),
+            rounds:      None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TournamentRequest {
+    strategies: Vec<String>,
+    rounds:     usize,
+    seed:       Option<u64>,
+    /// How long to pause after each round, for spectators.
+    #[serde(default)]
+    delay_ms:   u64,
+}
+
+#[derive(Debug, Serialize)]
+struct TournamentResponse {
+    /// The run id the tournament was reported to spectators under.
+    run:       u64,
+    standings: Vec<Standing>,
+    matches:   Vec<MatchResponse>,
+}
+
+fn check_rounds(rounds: usize) -> Result<(), ApiError> {
+    if (1..=MAX_ROUNDS).contains(&rounds) {
+        Ok(())
+    } else {
+        Err(ApiError::BadRequest(format!(
+            "rounds must be between 1 and {MAX_ROUNDS}"
+        )))
+    }
+}
+
+/// Checks that pausing `delay_ms` after each of `rounds` rounds stays within
+/// [`MAX_TOTAL_DELAY_MS`].
+fn check_total_delay(delay_ms: u64, rounds: usize) -> Result<(), ApiError> {
+    let total = u64::try_from(rounds)
+        .ok()
+        .and_then(|rounds| rounds.checked_mul(delay_ms));
+
+    if total.is_some_and(|total| total <= MAX_TOTAL_DELAY_MS) {
+        Ok(())
+    } else {
+        Err(ApiError::BadRequest(format!(
+            "delay_ms times the number of rounds played must be at most {MAX_TOTAL_DELAY_MS}"
+        )))
+    }
+}
+
+fn parse_choice(game_options: &GameOptions, choice: &str) -> Result<Choice, ApiError> {
+    game_options.parse_choice(choice).ok_or_else(|| {
+        ApiError::BadRequest(format!(
+            "Unknown choice {choice}; expected A, B, {} or {}",
+            game_options.choice_atlantis(),
+            game_options.choice_olympus()
+        ))
+    })
+}
+
+/// Runs a simulation off the async workers, as long matches would otherwise
+/// stall every other request.
+async fn simulate<T: Send + 'static>(
+    simulation: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, ApiError> {
+    tokio::task::spawn_blocking(simulation)
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))
+}
+
+async fn list_strategies(State(state): State<AppState>) -> Json<Vec<String>> {
+    let builtins = StrategyKind::ALL.iter().map(|kind| kind.name().to_string());
+    let scripts = state
+        .strategy_files("rhai")
+        .into_iter()
+        .map(|file| format!("script:{file}"));
+    let plugins = state
+        .strategy_files("wasm")
+        .into_iter()
+        .map(|file| format!("plugin:{file}"));
+
+    Json(builtins.chain(scripts).chain(plugins).collect())
+}
+
+async fn list_presets() -> Json<[&'static str; 9]> {
+    Json(GamePresets::preset_names())
+}
+
+async fn create_game(
+    State(state): State<AppState>,
+    ApiJson(params): ApiJson<GameParams>,
+) -> Result<(StatusCode, Json<GameResponse>), ApiError> {
+    let game = params.build()?;
+    let id = state.games.insert(game);
+
+    Ok((StatusCode::CREATED, Json(GameResponse { id, game })))
+}
+
+async fn get_game(
+    State(state): State<AppState>,
+    GameId(id): GameId,
+) -> Result<Json<GameResponse>, ApiError> {
+    let game = state.game(id)?;
+
+    Ok(Json(GameResponse { id, game }))
+}
+
+async fn get_grid(
+    State(state): State<AppState>,
+    GameId(id): GameId,
+) -> Result<Json<GridResponse>, ApiError> {
+    let game = state.game(id)?;
+
+    Ok(Json(GridResponse {
+        choices: [game.choice_atlantis, game.choice_olympus],
+        scores:  [
+            [game.atlantis_atlantis(), game.atlantis_olympus()],
+            [game.olympus_atlantis(), game.olympus_olympus()],
+        ],
+        table:   GameGrid::new(game).make_grid().to_string(),
+    }))
+}
+
+async fn score(
+    State(state): State<AppState>,
+    GameId(id): GameId,
+    ApiJson(request): ApiJson<ScoreRequest>,
+) -> Result<Json<ScoreResponse>, ApiError> {
+    let game = state.game(id)?;
+
+    let aleph = parse_choice(&game, &request.aleph)?;
+    let beth = parse_choice(&game, &request.beth)?;
+
+    Ok(Json(ScoreResponse {
+        aleph,
+        beth,
+        score: GameGrid::new(game).return_score(aleph, beth),
+    }))
+}
+
+async fn play_match(
+    State(state): State<AppState>,
+    GameId(id): GameId,
+    ApiJson(request): ApiJson<MatchRequest>,
+) -> Result<Json<MatchResponse>, ApiError> {
+    let game = state.game(id)?;
+
+    let feed = state.feed(id, request.delay_ms)?;
+
+    check_rounds(request.rounds)?;
+    check_total_delay(request.delay_ms, request.rounds)?;
+
+    let mut aleph = state.strategy(&request.aleph, game, request.seed)?;
+    let mut beth = state.strategy(&request.beth, game, request.seed)?;
+
+    simulate(move || {
+        feed.send(Event::MatchStarted {
+            run:    feed.run(),
+            aleph:  aleph.name().to_string(),
+            beth:   beth.name().to_string(),
+            rounds: request.rounds,
+        });
+
+        let history = GameGrid::new(game).play_match_observed(
+            aleph.as_mut(),
+            beth.as_mut(),
+            request.rounds,
+            &mut |history| feed.observe(history),
+        );
+
+        feed.finish(&history);
+
+        MatchResponse {
+            run: Some(feed.run()),
+            rounds: Some(history.rounds().collect()),
+            ..MatchResponse::new(aleph.name(), beth.name(), &history)
+        }
+    })
+    .await
+    .map(Json)
+}
+
+async fn play_tournament(
+    State(state): State<AppState>,
+    GameId(id): GameId,
+    ApiJson(request): ApiJson<TournamentRequest>,
+) -> Result<Json<TournamentResponse>, ApiError> {
+    let game = state.game(id)?;
+    let feed = state.feed(id, request.delay_ms)?;
+
+    check_rounds(request.rounds)?;
+
+    let count = request.strategies.len();
+
+    if !(2..=MAX_ENTRANTS).contains(&count) {
+        return Err(ApiError::BadRequest(format!(
+            "a tournament needs between 2 and {MAX_ENTRANTS} strategies"
+        )));
+    }
+
+    // Every entrant plays every other entrant once.
+    check_total_delay(request.delay_ms, count * (count - 1) / 2 * request.rounds)?;
+
+    let mut entrants = request
+        .strategies
+        .iter()
+        .map(|source| state.strategy(source, game, request.seed))
+        .collect::<Result<Vec<_>, ApiError>>()?;
+
+    simulate(move || {
+        let names: Vec<String> = entrants
+            .iter()
+            .map(|entrant| entrant.name().to_string())
+            .collect();
+
+        feed.send(Event::TournamentStarted {
+            run:        feed.run(),
+            strategies: names.clone(),
+            rounds:     request.rounds,
+        });
+
+        let mut playing = None;
+
+        let tournament = Tournament::round_robin_observed(
+            &GameGrid::new(game),
+            &mut entrants,
+            request.rounds,
+            &mut |aleph, beth, history| {
+                if playing != Some((aleph, beth)) {
+                    playing = Some((aleph, beth));
+
+                    feed.send(Event::MatchStarted {
+                        run:    feed.run(),
+                        aleph:  names[aleph].clone(),
+                        beth:   names[beth].clone(),
+                        rounds: request.rounds,
+                    });
+                }
+
+                feed.observe(history);
+
+                if history.forfeit().is_some() || history.len() == request.rounds {
+                    feed.finish(history);
+                }
+            },
+        );
+
+        let standings = tournament.standings();
+
+        feed.send(Event::TournamentFinished {
+            run:       feed.run(),
+            standings: standings.clone(),
+        });
+
+        TournamentResponse {
+            run: feed.run(),
+            standings,
+            matches: tournament
+                .pairings()
+                .iter()
+                .map(|pairing| {
+                    MatchResponse::new(
+                        &names[pairing.aleph],
+                        &names[pairing.beth],
+                        &pairing.history,
+                    )
+                })
+                .collect(),
+        }
+    })
+    .await
+    .map(Json)
+}
+
+async fn watch(
+    State(state): State<AppState>,
+    GameId(id): GameId,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    let game = state.game(id)?;
+
+    // Subscribing before the upgrade means a spectator sees every event sent
+    // after its `watching` event.
+    let events = state.subscribe(id)?;
+
+    Ok(upgrade.on_upgrade(move |socket| feed::spectate(socket, game, events)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        future::IntoFuture,
+    };
+
+    use axum::{
+        body::Body,
+        http::{
+            Method,
+            Request,
+        },
+    };
+    use futures_util::StreamExt;
+    use http_body_util::BodyExt;
+    use rstest::rstest;
+    use serde_json::{
+        json,
+        Value,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn send(
+        app: &Router,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder().method(method).uri(uri);
+
+        let request = match body {
+            Some(body) => request
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    async fn create(app: &Router, params: Value) -> u64 {
+        let (status, body) = send(app, Method::POST, "/games", Some(params)).await;
+
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        body["id"].as_u64().unwrap()
+    }
+
+    fn app() -> Router {
+        router(AppState::default())
+    }
+
+    #[tokio::test]
+    async fn test_create_and_fetch_game() {
+        let app = app();
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (status, body) = send(&app, Method::GET, &format!("/games/{id}"), None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], id);
+        assert_eq!(
+            body["game"]["olympus_atlantis"],
+            json!({"first": 5, "second": 0})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_grid() {
+        let app = app();
+        let id = create(
+            &app,
+            json!({"preset": "prisoners_dilemma", "choice_atlantis": "silent", "choice_olympus": "betray"}),
+        )
+        .await;
+
+        let (status, body) = send(&app, Method::GET, &format!("/games/{id}/grid"), None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["choices"], json!(["silent", "betray"]));
+        assert_eq!(body["scores"][0][1], json!({"first": 0, "second": 5}));
+        assert!(body["table"].as_str().unwrap().contains("silent"));
+    }
+
+    #[rstest]
+    #[case("A", "B", Some(json!({"first": 0, "second": 5})))]
+    #[case("olympus", "atlantis", Some(json!({"first": 5, "second": 0})))]
+    #[case("defect", "Defect", Some(json!({"first": 1, "second": 1})))]
+    #[case("C", "A", None)]
+    #[tokio::test]
+    async fn test_score(#[case] aleph: &str, #[case] beth: &str, #[case] expected: Option<Value>) {
+        let app = app();
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/score"),
+            Some(json!({"aleph": aleph, "beth": beth})),
+        )
+        .await;
+
+        match expected {
+            Some(score) => {
+                assert_eq!(status, StatusCode::OK);
+                assert_eq!(body["score"], score);
+            }
+            None => {
+                assert_eq!(status, StatusCode::BAD_REQUEST);
+                assert!(body["error"].as_str().unwrap().contains("Unknown choice C"));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_match() {
+        let app = app();
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/match"),
+            Some(json!({"aleph": "tit-for-tat", "beth": "always-defect", "rounds": 3})),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["aleph_total"], 2);
+        assert_eq!(body["beth_total"], 7);
+        assert_eq!(body["winner"], "Beth");
+        assert_eq!(body["rounds"].as_array().unwrap().len(), 3);
+        assert_eq!(body["rounds"][0]["beth_choice"], "Olympus");
+    }
+
+    #[tokio::test]
+    async fn test_match_is_not_paced_without_spectators() {
+        let app = app();
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let uri = format!("/games/{id}/match");
+        let request = send(
+            &app,
+            Method::POST,
+            &uri,
+            Some(json!({"aleph": "tit-for-tat", "beth": "pavlov", "rounds": 50, "delay_ms": 1000})),
+        );
+
+        let (status, _) = tokio::time::timeout(Duration::from_secs(5), request)
+            .await
+            .expect("an unwatched match should not pause between rounds");
+
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_tournament() {
+        let app = app();
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/tournament"),
+            Some(json!({
+                "strategies": ["tit-for-tat", "always-defect", "always-cooperate"],
+                "rounds": 10,
+            })),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["matches"].as_array().unwrap().len(), 3);
+        assert_eq!(body["standings"][0]["name"], "always-defect");
+        assert_eq!(body["standings"][0]["wins"], 2);
+    }
+
+    #[rstest]
+    #[case(Method::GET, "/games/99", None, StatusCode::NOT_FOUND)]
+    #[case(Method::GET, "/games/nope", None, StatusCode::BAD_REQUEST)]
+    #[case(Method::POST, "/games", Some(json!({"preset": "nonexistent"})), StatusCode::BAD_REQUEST)]
+    #[case(Method::POST, "/games", Some(json!({"colour": "blue"})), StatusCode::BAD_REQUEST)]
+    #[case(
+        Method::POST,
+        "/games/1/match",
+        Some(json!({"aleph": "tit-for-tat", "beth": "nobody", "rounds": 3})),
+        StatusCode::BAD_REQUEST
+    )]
+    #[case(
+        Method::POST,
+        "/games/1/match",
+        Some(json!({"aleph": "tit-for-tat", "beth": "pavlov", "rounds": 0})),
+        StatusCode::BAD_REQUEST
+    )]
+    #[case(
+        Method::POST,
+        "/games/1/match",
+        Some(json!({"aleph": "tit-for-tat", "beth": "pavlov", "rounds": 3, "delay_ms": 60000})),
+        StatusCode::BAD_REQUEST
+    )]
+    #[case(
+        Method::POST,
+        "/games/1/match",
+        Some(json!({"aleph": "tit-for-tat", "beth": "pavlov", "rounds": 100, "delay_ms": 1000})),
+        StatusCode::BAD_REQUEST
+    )]
+    #[case(
+        Method::POST,
+        "/games/1/tournament",
+        Some(json!({"strategies": ["tit-for-tat"], "rounds": 3})),
+        StatusCode::BAD_REQUEST
+    )]
+    #[case(
+        Method::POST,
+        "/games/1/tournament",
+        Some(json!({"strategies": ["tit-for-tat", "pavlov", "grudger"], "rounds": 10, "delay_ms": 5000})),
+        StatusCode::BAD_REQUEST
+    )]
+    #[tokio::test]
+    async fn test_errors(
+        #[case] method: Method,
+        #[case] uri: &str,
+        #[case] body: Option<Value>,
+        #[case] expected: StatusCode,
+    ) {
+        let app = app();
+        create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (status, body) = send(&app, method, uri, body).await;
+
+        assert_eq!(status, expected);
+        assert!(body["error"].is_string());
+    }
+
+    /// Returns a strategy directory holding a script and a plugin that
+    /// always defect.
+    fn strategy_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tactix-server-{name}-{}", std::process::id()));
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("defector.rhai"),
+            r#"fn next_choice(own, opponent, round, game) { "B" }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("betrayer.wasm"),
+            wat::parse_str(
+                r#"(module
+                    (memory (export "memory") 1)
+                    (func (export "alloc") (param i32) (result i32) i32.const 0)
+                    (func (export "next_move") (param i32 i32) (result i32) i32.const 1))"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_tournament_with_script() {
+        let dir = strategy_dir("tournament");
+        let app = router(AppState::with_strategy_dir(&dir));
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (_, strategies) = send(&app, Method::GET, "/strategies", None).await;
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/tournament"),
+            Some(json!({
+                "strategies": [
+                    "script:defector.rhai",
+                    "plugin:betrayer.wasm",
+                    "always-cooperate",
+                ],
+                "rounds": 10,
+            })),
+        )
+        .await;
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(strategies
+            .as_array()
+            .unwrap()
+            .ends_with(&[json!("script:defector.rhai"), json!("plugin:betrayer.wasm")]));
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["standings"][2]["name"], "always-cooperate");
+        assert_eq!(body["standings"][2]["total"], 0);
+    }
+
+    #[rstest]
+    #[case("no-directory", false, "script:defector.rhai")]
+    #[case("missing", true, "script:missing.rhai")]
+    #[case("outside", true, "script:../defector.rhai")]
+    #[case("absolute", true, "script:/etc/hostname")]
+    #[case("plugin-outside", true, "plugin:../betrayer.wasm")]
+    #[tokio::test]
+    async fn test_script_errors(#[case] name: &str, #[case] with_dir: bool, #[case] source: &str) {
+        let dir = strategy_dir(name);
+        let state = if with_dir {
+            AppState::with_strategy_dir(dir.join("nested"))
+        } else {
+            AppState::default()
+        };
+
+        // The script sits just outside the strategy directory.
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+
+        let app = router(state);
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/match"),
+            Some(json!({"aleph": source, "beth": "pavlov", "rounds": 3})),
+        )
+        .await;
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].is_string());
+    }
+
+    async fn next_event<S>(socket: &mut S) -> Value
+    where
+        S: futures_util::Stream<
+                Item = Result<
+                    tokio_tungstenite::tungstenite::Message,
+                    tokio_tungstenite::tungstenite::Error,
+                >,
+            > + Unpin,
+    {
+        let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("no event within 5 seconds")
+            .unwrap()
+            .unwrap();
+
+        serde_json::from_str(message.to_text().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_watch_match() {
+        let state = AppState::default();
+        let app = router(state.clone());
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(axum::serve(listener, router(state)).into_future());
+
+        let (mut socket, _) =
+            tokio_tungstenite::connect_async(format!("ws://{address}/games/{id}/watch"))
+                .await
+                .unwrap();
+
+        let watching = next_event(&mut socket).await;
+
+        assert_eq!(watching["type"], "watching");
+        assert_eq!(watching["game"]["choice_atlantis"], "cooperate");
+
+        let (status, _) = send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/match"),
+            Some(json!({"aleph": "tit-for-tat", "beth": "always-defect", "rounds": 3})),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+
+        let started = next_event(&mut socket).await;
+
+        assert_eq!(started["type"], "match_started");
+        assert_eq!(started["beth"], "always-defect");
+
+        let mut totals = Vec::new();
+
+        for round in 1..=3 {
+            let event = next_event(&mut socket).await;
+
+            assert_eq!(event["type"], "round");
+            assert_eq!(event["round"], round);
+
+            totals.push((event["aleph_total"].clone(), event["beth_total"].clone()));
+        }
+
+        assert_eq!(
+            totals,
+            vec![
+                (json!(0), json!(5)),
+                (json!(1), json!(6)),
+                (json!(2), json!(7))
+            ]
+        );
+
+        let finished = next_event(&mut socket).await;
+
+        assert_eq!(finished["type"], "match_finished");
+        assert_eq!(finished["winner"], "Beth");
+    }
+
+    #[tokio::test]
+    async fn test_watch_tournament() {
+        let state = AppState::default();
+        let app = router(state.clone());
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let mut events = state.subscribe(id).unwrap();
+
+        send(
+            &app,
+            Method::POST,
+            &format!("/games/{id}/tournament"),
+            Some(json!({"strategies": ["tit-for-tat", "pavlov", "grudger"], "rounds": 2})),
+        )
+        .await;
+
+        let mut types = Vec::new();
+
+        while let Ok(event) = events.try_recv() {
+            types.push(serde_json::to_value(event).unwrap()["type"].clone());
+        }
+
+        let count = |kind: &str| types.iter().filter(|t| *t == kind).count();
+
+        assert_eq!(types.first(), Some(&json!("tournament_started")));
+        assert_eq!(types.last(), Some(&json!("tournament_finished")));
+        assert_eq!(count("match_started"), 3);
+        assert_eq!(count("round"), 6);
+        assert_eq!(count("match_finished"), 3);
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_runs_are_told_apart() {
+        let state = AppState::default();
+        let app = router(state.clone());
+        let id = create(&app, json!({"preset": "prisoners_dilemma"})).await;
+
+        let mut events = state.subscribe(id).unwrap();
+
+        let uri = format!("/games/{id}/tournament");
+        let tournament =
+            json!({"strategies": ["tit-for-tat", "pavlov"], "rounds": 5, "delay_ms": 10});
+        let uri_match = format!("/games/{id}/match");
+        let single =
+            json!({"aleph": "grudger", "beth": "always-defect", "rounds": 5, "delay_ms": 10});
+
+        let ((_, tournament), (_, single)) = tokio::join!(
+            send(&app, Method::POST, &uri, Some(tournament)),
+            send(&app, Method::POST, &uri_match, Some(single)),
+        );
+
+        let tournament_run = tournament["run"].as_u64().unwrap();
+        let match_run = single["run"].as_u64().unwrap();
+
+        assert_ne!(tournament_run, match_run);
+
+        let mut runs: HashMap<u64, Vec<Value>> = HashMap::new();
+
+        while let Ok(event) = events.try_recv() {
+            let event = serde_json::to_value(event).unwrap();
+
+            runs.entry(event["run"].as_u64().unwrap())
+                .or_default()
+                .push(event);
+        }
+
+        let types = |run: u64| -> Vec<&str> {
+            runs[&run]
+                .iter()
+                .map(|event| event["type"].as_str().unwrap())
+                .collect()
+        };
+        let rounds = |run: u64| -> Vec<u64> {
+            runs[&run]
+                .iter()
+                .filter_map(|event| event["round"].as_u64())
+                .collect()
+        };
+
+        assert_eq!(runs.len(), 2);
+        assert_eq!(
+            types(tournament_run),
+            [
+                "tournament_started",
+                "match_started",
+                "round",
+                "round",
+                "round",
+                "round",
+                "round",
+                "match_finished",
+                "tournament_finished"
+            ]
+        );
+        assert_eq!(types(match_run)[0], "match_started");
+        assert_eq!(rounds(tournament_run), [1, 2, 3, 4, 5]);
+        assert_eq!(rounds(match_run), [1, 2, 3, 4, 5]);
+        assert_eq!(runs[&match_run][0]["aleph"], "grudger");
+    }
+
+    #[tokio::test]
+    async fn test_lists() {
+        let app = app();
+
+        let (_, strategies) = send(&app, Method::GET, "/strategies", None).await;
+        let (_, presets) = send(&app, Method::GET, "/presets", None).await;
+
+        assert_eq!(
+            strategies.as_array().unwrap().len(),
+            StrategyKind::ALL.len()
+        );
+        assert!(presets.as_array().unwrap().contains(&json!("chicken")));
+    }
+}
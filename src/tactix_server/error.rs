@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+use axum::{
+    extract::rejection::JsonRejection,
+    http::StatusCode,
+    response::{
+        IntoResponse,
+        Response,
+    },
+    Json,
+};
+use dilemma_tactix_lib::{
+    BuilderError,
+    StrategyError,
+};
+use serde_json::json;
+
+/// An error answered to a client as `{"error": "..."}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    /// The request could not be understood or asked for something invalid.
+    BadRequest(String),
+    /// There is no game with the given id.
+    GameNotFound(u64),
+    /// A simulation could not be run to completion.
+    Internal(String),
+    /// The server can not take on what was asked of it, though it may still
+    /// serve other requests.
+    Unavailable(String),
+}
+
+impl ApiError {
+    const fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::GameNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadRequest(message) | Self::Internal(message) | Self::Unavailable(message) => {
+                write!(f, "{message}")
+            }
+            Self::GameNotFound(id) => write!(f, "No game with id {id}"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<BuilderError> for ApiError {
+    fn from(error: BuilderError) -> Self {
+        match error {
+            // Choice names are kept for the life of the server, so once it has
+            // taken as many as it can, no client can add another.
+            BuilderError::TooManyChoiceNames(limit) => Self::Unavailable(format!(
+                "This server has already taken {limit} distinct choice names and can not take any \
+                 more; use the standard choice names or names that other games already use"
+            )),
+            error => Self::BadRequest(error.to_string()),
+        }
+    }
+}
+
+impl From<StrategyError> for ApiError {
+    fn from(error: StrategyError) -> Self {
+        Self::BadRequest(error.to_string())
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status(), Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exhausted_choice_names_are_unavailable() {
+        let error = ApiError::from(BuilderError::TooManyChoiceNames(1024));
+
+        assert_eq!(error.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(error.to_string().contains("standard choice names"));
+
+        assert_eq!(
+            ApiError::from(BuilderError::EmptyChoiceName("choice_atlantis")).status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
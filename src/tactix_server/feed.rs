@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use axum::extract::ws::{
+    Message,
+    WebSocket,
+};
+use dilemma_tactix_lib::{
+    Choice,
+    GameOptions,
+    MatchHistory,
+    NumberPair,
+    Player,
+    Standing,
+};
+use serde::Serialize;
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+/// How many events a spectator may fall behind before it starts missing
+/// them.
+pub const FEED_CAPACITY: usize = 1024;
+
+/// Something that happened in a game, as sent to spectators.
+///
+/// Events are sent as JSON text messages tagged with their `type`, e.g.
+/// `{"type":"round","run":1,"round":1,...}`.
+///
+/// Every event of a match or tournament carries the `run` id returned by the
+/// request that played it, so spectators can tell apart runs that overlap.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    /// Sent once when a spectator connects.
+    Watching { game: GameOptions },
+    /// A tournament is about to start.
+    TournamentStarted {
+        run:        u64,
+        strategies: Vec<String>,
+        rounds:     usize,
+    },
+    /// A match is about to start.
+    MatchStarted {
+        run:    u64,
+        aleph:  String,
+        beth:   String,
+        rounds: usize,
+    },
+    /// A round has been played.
+    Round {
+        run:          u64,
+        round:        usize,
+        aleph_choice: Choice,
+        beth_choice:  Choice,
+        score:        NumberPair,
+        aleph_total:  u64,
+        beth_total:   u64,
+    },
+    /// A match is over.
+    MatchFinished {
+        run:         u64,
+        aleph_total: u64,
+        beth_total:  u64,
+        winner:      Option<Player>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        forfeit:     Option<String>,
+    },
+    /// A tournament is over.
+    TournamentFinished {
+        run:       u64,
+        standings: Vec<Standing>,
+    },
+    /// The spectator fell behind and missed some events.
+    Lagged { missed: u64 },
+}
+
+/// The sending side of a game's spectator feed, used by a single match or
+/// tournament.
+///
+/// Events are dropped when nobody is watching, so a feed costs next to
+/// nothing for games without spectators.
+#[derive(Debug, Clone)]
+pub struct Feed {
+    sender: broadcast::Sender<Event>,
+    run:    u64,
+    delay:  Duration,
+}
+
+impl Feed {
+    /// Creates a feed for the run with id `run` that waits `delay` after
+    /// every round while anybody is watching, so that spectators can follow
+    /// along.
+    pub const fn new(sender: broadcast::Sender<Event>, run: u64, delay: Duration) -> Self {
+        Self { sender, run, delay }
+    }
+
+    /// Returns the id of the run this feed reports.
+    pub const fn run(&self) -> u64 {
+        self.run
+    }
+
+    /// Sends an event to every spectator.
+    pub fn send(&self, event: Event) {
+        // Sending only fails when there are no spectators.
+        let _ = self.sender.send(event);
+    }
+
+    /// Reports the latest round of a match, for use as the observer of
+    /// [`GameGrid::play_match_observed`](dilemma_tactix_lib::GameGrid::play_match_observed).
+    ///
+    /// This blocks for the delay of the feed if anybody is watching, so it
+    /// must be called off the async workers.
+    pub fn observe(&self, history: &MatchHistory) {
+        // A forfeit is reported when the match finishes.
+        if history.forfeit().is_some() {
+            return;
+        }
+
+        let Some(round) = history.last() else {
+            return;
+        };
+
+        self.send(Event::Round {
+            run:          self.run,
+            round:        history.len(),
+            aleph_choice: round.aleph_choice,
+            beth_choice:  round.beth_choice,
+            score:        round.score,
+            aleph_total:  history.aleph_total(),
+            beth_total:   history.beth_total(),
+        });
+
+        if !self.delay.is_zero() && self.sender.receiver_count() > 0 {
+            std::thread::sleep(self.delay);
+        }
+    }
+
+    /// Reports the end of a match.
+    pub fn finish(&self, history: &MatchHistory) {
+        self.send(Event::MatchFinished {
+            run:         self.run,
+            aleph_total: history.aleph_total(),
+            beth_total:  history.beth_total(),
+            winner:      history.winner(),
+            forfeit:     history.forfeit().map(|forfeit| {
+                format!(
+                    "{:?} forfeited in round {}: {}",
+                    forfeit.player, forfeit.round, forfeit.reason
+                )
+            }),
+        });
+    }
+}
+
+async fn send_event(socket: &mut WebSocket, event: &Event) -> bool {
+    let Ok(text) = serde_json::to_string(event) else {
+        return false;
+    };
+
+    socket.send(Message::Text(text)).await.is_ok()
+}
+
+/// Streams a game's events to a spectator until either side goes away.
+pub async fn spectate(
+    mut socket: WebSocket,
+    game: GameOptions,
+    mut events: broadcast::Receiver<Event>,
+) {
+    if !send_event(&mut socket, &Event::Watching { game }).await {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            event = events.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(missed)) => Event::Lagged { missed },
+                    Err(RecvError::Closed) => break,
+                };
+
+                if !send_event(&mut socket, &event).await {
+                    break;
+                }
+            }
+            message = socket.recv() => {
+                // Spectators have nothing to say; anything but a close is
+                // ignored.
+                match message {
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        RwLock,
+    },
+};
+
+use dilemma_tactix_lib::GameOptions;
+use tokio::sync::broadcast;
+
+use crate::feed::{
+    Event,
+    FEED_CAPACITY,
+};
+
+/// A game created through the API.
+#[derive(Debug)]
+struct Game {
+    options: GameOptions,
+    feed:    broadcast::Sender<Event>,
+}
+
+/// The most games kept at once.
+pub const MAX_GAMES: usize = 10_000;
+
+/// The games created through the API, by id.
+///
+/// Only the latest [`MAX_GAMES`] games are kept; creating another forgets
+/// the oldest one, closing its spectator feed.
+#[derive(Debug)]
+pub struct Games {
+    next_id:  AtomicU64,
+    capacity: usize,
+    games:    RwLock<BTreeMap<u64, Game>>,
+}
+
+impl Default for Games {
+    fn default() -> Self {
+        Self::with_capacity(MAX_GAMES)
+    }
+}
+
+impl Games {
+    /// Creates a store that keeps at most `capacity` games.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            next_id: AtomicU64::default(),
+            capacity,
+            games: RwLock::default(),
+        }
+    }
+
+    /// Stores a game and returns its id, forgetting the oldest game if the
+    /// store is full.
+    pub fn insert(&self, game_options: GameOptions) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+
+        let game = Game {
+            options: game_options,
+            feed:    broadcast::channel(FEED_CAPACITY).0,
+        };
+
+        let mut games = self
+            .games
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        // Ids only grow, so the first game is the oldest.
+        while games.len() >= self.capacity.max(1) {
+            games.pop_first();
+        }
+
+        games.insert(id, game);
+
+        id
+    }
+
+    /// Returns the game with the given id, if there is one.
+    pub fn get(&self, id: u64) -> Option<GameOptions> {
+        self.games
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&id)
+            .map(|game| game.options)
+    }
+
+    /// Returns the sending side of the spectator feed of the game with the
+    /// given id, if there is one.
+    pub fn feed(&self, id: u64) -> Option<broadcast::Sender<Event>> {
+        self.games
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&id)
+            .map(|game| game.feed.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dilemma_tactix_lib::GamePresets;
+
+    use super::*;
+
+    #[test]
+    fn test_games_store() {
+        let games = Games::default();
+        let game_options = GamePresets::get_preset("chicken").unwrap();
+
+        let first = games.insert(game_options);
+        let second = games.insert(GameOptions::default());
+
+        assert_ne!(first, second);
+        assert_eq!(games.get(first), Some(game_options));
+        assert_eq!(games.get(second + 1), None);
+        assert!(games.feed(first).is_some());
+        assert!(games.feed(second + 1).is_none());
+    }
+
+    #[test]
+    fn test_oldest_game_is_forgotten() {
+        let games = Games::with_capacity(2);
+
+        let first = games.insert(GameOptions::default());
+        let second = games.insert(GameOptions::default());
+        let third = games.insert(GameOptions::default());
+
+        assert_eq!(games.get(first), None);
+        assert!(games.get(second).is_some());
+        assert!(games.get(third).is_some());
+    }
+}
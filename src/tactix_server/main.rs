@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+mod api;
+mod error;
+mod feed;
+mod games;
+
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use api::AppState;
+use clap::Parser;
+
+/// Serve the Dilemma Tactix engine as a JSON API over HTTP.
+///
+/// Games are created with `POST /games` and kept in memory, the oldest being
+/// forgotten once there are too many. Matches and tournaments between the
+/// built-in strategies are played with `POST /games/{id}/match` and
+/// `POST /games/{id}/tournament`, and can be watched round by round over a WebSocket at
+/// `/games/{id}/watch`. Scripts and plugins in the `--strategy-dir` can be
+/// entered as `script:<file>` and `plugin:<file>`.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The address to listen on.
+    #[arg(short, long, default_value = "127.0.0.1:8080")]
+    bind: SocketAddr,
+
+    /// A directory of Rhai scripts and WebAssembly plugins that can be entered
+    /// into matches and tournaments as `script:<file>` and `plugin:<file>`.
+    #[arg(long, value_name = "DIR")]
+    strategy_dir: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let listener = match tokio::net::TcpListener::bind(cli.bind).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("error: could not listen on {}: {e}", cli.bind);
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Dilemma Tactix server listening on http://{}", cli.bind);
+
+    let state = cli
+        .strategy_dir
+        .map_or_else(AppState::default, AppState::with_strategy_dir);
+
+    if let Err(e) = axum::serve(listener, api::router(state)).await {
+        eprintln!("error: {e}");
+
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -3,22 +3,236 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use dilemma_tactix_lib::{
-    Choice,
-    GameGrid,
+use crossterm::event::{
+    KeyCode,
+    MouseEvent,
+};
+use ratatui::layout::{
+    Position,
+    Rect,
+};
+
+use crate::{
+    events::{
+        Message,
+        Messenger,
+    },
+    logger::{
+        LogEntries,
+        LogPane,
+    },
+    screens::{
+        Action,
+        MenuScreen,
+        Screen,
+        Settings,
+    },
+    ui::screen_layout,
 };
 
-#[allow(dead_code)]
+/// The state of the whole app: the shared settings and a stack of screens,
+/// the last of which is shown.
 pub struct App {
-    pub game_grid: GameGrid,
-    pub selected:  Option<Choice>,
+    pub settings: Settings,
+    /// The open screens, with the main menu at the bottom. Never empty.
+    screens:      Vec<Screen>,
+    /// Set once the user has asked to leave.
+    pub quit:     bool,
+    /// Handed to screens that start work in the background.
+    messenger:    Messenger,
+    /// The log pane, shown below every screen when toggled on.
+    pub log:      LogPane,
 }
 
 impl App {
-    pub const fn new(game_grid: GameGrid) -> Self {
+    /// Creates an app showing the main menu, whose screens report back
+    /// from the background through `messenger`, with a hidden log pane
+    /// showing `log`.
+    pub fn new(settings: Settings, messenger: Messenger, log: LogEntries) -> Self {
         Self {
-            game_grid,
-            selected: None,
+            settings,
+            screens: vec![Screen::Menu(MenuScreen::default())],
+            quit: false,
+            messenger,
+            log: LogPane::new(log),
+        }
+    }
+
+    /// The screen being shown.
+    pub fn screen(&self) -> &Screen {
+        self.screens.last().expect("the main menu is never closed")
+    }
+
+    fn screen_mut(&mut self) -> &mut Screen {
+        self.screens
+            .last_mut()
+            .expect("the main menu is never closed")
+    }
+
+    fn apply(&mut self, action: Action) {
+        match action {
+            Action::None => {}
+            Action::Push(screen) => self.screens.push(screen),
+            Action::Replace(screen) => *self.screen_mut() = screen,
+            // Going back from the main menu leaves the app.
+            Action::Back if self.screens.len() == 1 => self.quit = true,
+            Action::Back => {
+                self.screens.pop();
+            }
+            Action::Quit => self.quit = true,
         }
     }
+
+    /// Handles a key press.
+    ///
+    /// `Q` quits from any screen, `Esc` goes back to the previous one and
+    /// `L` shows or hides the log; every other key is left to the screen
+    /// being shown, as is every key while it is taking text input.
+    pub fn on_key(&mut self, code: KeyCode) {
+        let typing = self.screen().is_typing();
+
+        let action = match code {
+            KeyCode::Char('q' | 'Q') if !typing => Action::Quit,
+            KeyCode::Esc if !typing => Action::Back,
+            KeyCode::Char('l' | 'L') if !typing => {
+                self.log.toggle();
+
+                Action::None
+            }
+            code => {
+                let Self {
+                    settings,
+                    screens,
+                    messenger,
+                    ..
+                } = self;
+
+                screens.last_mut().map_or(Action::None, |screen| {
+                    screen.on_key(code, settings, messenger)
+                })
+            }
+        };
+
+        self.apply(action);
+    }
+
+    /// Moves the animation of the screen being shown along.
+    pub fn tick(&mut self) {
+        self.screen_mut().tick();
+    }
+
+    /// Passes a message from the background on to every open screen, since
+    /// the one that started the task may be hidden under another.
+    pub fn on_message(&mut self, message: &Message) {
+        for screen in &mut self.screens {
+            screen.on_message(message);
+        }
+    }
+
+    /// Handles a mouse event, given the `area` of the whole terminal.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect) {
+        let [_, body, log, _] = screen_layout(area, self.log.shown);
+
+        if self.log.shown && log.contains(Position::new(event.column, event.row)) {
+            self.log.on_mouse(event, log);
+
+            return;
+        }
+
+        let Self {
+            settings, screens, ..
+        } = self;
+
+        let action = screens.last_mut().map_or(Action::None, |screen| {
+            screen.on_mouse(event, body, settings)
+        });
+
+        self.apply(action);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        screens::tests::settings,
+        ui::{
+            tests::render,
+            ui,
+        },
+    };
+
+    #[test]
+    fn test_navigation() {
+        let mut app = App::new(settings(), Messenger::channel().0, LogEntries::default());
+
+        assert!(matches!(app.screen(), Screen::Menu(_)));
+
+        app.on_key(KeyCode::Enter);
+        assert!(matches!(app.screen(), Screen::Game(_)));
+
+        app.on_key(KeyCode::Char('a'));
+        app.on_key(KeyCode::Esc);
+        assert!(matches!(app.screen(), Screen::Menu(_)));
+        assert!(!app.quit);
+
+        app.on_key(KeyCode::Esc);
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn test_load_replaces_itself() {
+        let mut app = App::new(settings(), Messenger::channel().0, LogEntries::default());
+
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Enter);
+        assert!(matches!(app.screen(), Screen::Load(_)));
+
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Enter);
+        assert!(matches!(app.screen(), Screen::Game(_)));
+        assert_eq!(app.settings.game_name, "chicken");
+
+        app.on_key(KeyCode::Esc);
+        assert!(matches!(app.screen(), Screen::Menu(_)));
+
+        app.on_key(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn test_typing_keeps_keys() {
+        let mut app = App::new(settings(), Messenger::channel().0, LogEntries::default());
+
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Enter);
+        assert!(matches!(app.screen(), Screen::Editor(_)));
+
+        app.on_key(KeyCode::Left);
+        app.on_key(KeyCode::Enter);
+        app.on_key(KeyCode::Char('q'));
+        app.on_key(KeyCode::Esc);
+        assert!(!app.quit);
+        assert!(matches!(app.screen(), Screen::Editor(_)));
+
+        app.on_key(KeyCode::Esc);
+        assert!(matches!(app.screen(), Screen::Menu(_)));
+    }
+
+    #[test]
+    fn test_toggle_log() {
+        let mut app = App::new(settings(), Messenger::channel().0, LogEntries::default());
+
+        app.on_key(KeyCode::Char('l'));
+        assert!(app.log.shown);
+
+        let text = render(|frame, _| ui(frame, &app));
+
+        assert!(text.contains("╭ Log "));
+        assert!(text.contains("L: log"));
+
+        app.on_key(KeyCode::Char('L'));
+        assert!(!app.log.shown);
+    }
 }
@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    any::Any,
+    io,
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    thread,
+    time::Duration,
+};
+
+use crossterm::event::{
+    self,
+    Event,
+};
+use dilemma_tactix_lib::Pairing;
+use tokio::{
+    sync::mpsc::{
+        self,
+        UnboundedReceiver,
+        UnboundedSender,
+    },
+    time::{
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+/// Something the app has to react to.
+#[derive(Debug)]
+pub enum AppEvent {
+    /// Input from the terminal, or the error that stopped it being read.
+    Terminal(io::Result<Event>),
+    /// Time to redraw the screen and move animations along.
+    Tick,
+    /// News from a task running in the background.
+    Message(Message),
+}
+
+/// News from a task running in the background.
+///
+/// Each task is known by the id it got from [`task_id()`], so that the
+/// screen that started it can tell its messages from those of tasks it has
+/// since given up on.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// A tournament has finished a match.
+    Pairing { task: u64, pairing: Pairing },
+    /// A task has finished.
+    Finished { task: u64 },
+    /// A task has panicked, for the given reason, before it could finish.
+    Failed { task: u64, reason: String },
+}
+
+/// Returns an id that no other background task has.
+pub fn task_id() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Sends messages from background tasks into the event loop.
+#[derive(Debug, Clone)]
+pub struct Messenger(UnboundedSender<AppEvent>);
+
+impl Messenger {
+    /// Creates a messenger, along with the receiving end of its messages.
+    pub fn channel() -> (Self, UnboundedReceiver<AppEvent>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        (Self(sender), receiver)
+    }
+
+    /// Sends a message, returning whether anybody is still listening.
+    pub fn send(&self, message: Message) -> bool {
+        self.0.send(AppEvent::Message(message)).is_ok()
+    }
+
+    /// Runs `work` as the task `task` on a thread of its own, then sends
+    /// [`Message::Finished`], or [`Message::Failed`] if it panicked, so that
+    /// the screen waiting on it always hears back.
+    pub fn spawn(&self, task: u64, work: impl FnOnce(&Self) + Send + 'static) {
+        let messenger = self.clone();
+
+        thread::spawn(move || {
+            let message = match panic::catch_unwind(AssertUnwindSafe(|| work(&messenger))) {
+                Ok(()) => Message::Finished { task },
+                Err(payload) => {
+                    let reason = panic_reason(payload.as_ref());
+
+                    log::error!("A background task stopped: {reason}");
+
+                    Message::Failed { task, reason }
+                }
+            };
+
+            // The app may have quit, in which case nobody is listening any
+            // more.
+            messenger.send(message);
+        });
+    }
+}
+
+/// The message a panic was raised with.
+fn panic_reason(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// The events of the app, merged into one stream: terminal input, a steady
+/// tick, and messages from background tasks.
+#[derive(Debug)]
+pub struct Events {
+    messenger: Messenger,
+    receiver:  UnboundedReceiver<AppEvent>,
+    ticks:     Interval,
+}
+
+impl Events {
+    /// Starts reading terminal input and ticking every `tick_rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn new(tick_rate: Duration) -> Self {
+        let events = Self::ticking(tick_rate);
+
+        // Reading blocks, so it gets a thread of its own, which stops once
+        // the app stops listening or the terminal fails.
+        let sender = events.messenger.0.clone();
+        thread::spawn(move || loop {
+            let event = event::read();
+            let failed = event.is_err();
+
+            if sender.send(AppEvent::Terminal(event)).is_err() || failed {
+                break;
+            }
+        });
+
+        events
+    }
+
+    /// Starts ticking every `tick_rate`, without reading the terminal.
+    fn ticking(tick_rate: Duration) -> Self {
+        let (messenger, receiver) = Messenger::channel();
+
+        let mut ticks = tokio::time::interval(tick_rate);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        Self {
+            messenger,
+            receiver,
+            ticks,
+        }
+    }
+
+    /// Returns a messenger for background tasks to report back with.
+    pub fn messenger(&self) -> Messenger {
+        self.messenger.clone()
+    }
+
+    /// Waits for the next event.
+    pub async fn next(&mut self) -> AppEvent {
+        tokio::select! {
+            _ = self.ticks.tick() => AppEvent::Tick,
+            event = self.receiver.recv() => {
+                // The messenger is kept here, so the channel never closes.
+                event.expect("the events hold a sender")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_ids_differ() {
+        assert_ne!(task_id(), task_id());
+    }
+
+    #[tokio::test]
+    async fn test_next() {
+        let mut events = Events::ticking(Duration::from_millis(50));
+
+        // The first tick is immediate.
+        assert!(matches!(events.next().await, AppEvent::Tick));
+
+        assert!(events.messenger().send(Message::Finished { task: 7 }));
+        assert!(matches!(
+            events.next().await,
+            AppEvent::Message(Message::Finished { task: 7 })
+        ));
+
+        assert!(matches!(events.next().await, AppEvent::Tick));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_reports_panics() {
+        let mut events = Events::ticking(Duration::from_secs(60));
+
+        events.next().await;
+
+        events.messenger().spawn(1, |_| {});
+        events.messenger().spawn(2, |_| panic!("out of moves"));
+
+        let mut messages = Vec::new();
+
+        while messages.len() < 2 {
+            if let AppEvent::Message(message) = events.next().await {
+                messages.push(message);
+            }
+        }
+
+        assert!(messages
+            .iter()
+            .any(|message| matches!(message, Message::Finished { task: 1 })));
+        assert!(messages.iter().any(|message| matches!(
+            message,
+            Message::Failed { task: 2, reason } if reason == "out of moves"
+        )));
+    }
+}
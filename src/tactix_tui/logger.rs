@@ -0,0 +1,364 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::Write,
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+        PoisonError,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crossterm::event::{
+    MouseEvent,
+    MouseEventKind,
+};
+use log::{
+    Level,
+    LevelFilter,
+    Log,
+    Metadata,
+    Record,
+    SetLoggerError,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+    },
+};
+
+/// The number of entries kept for the log pane, dropping the oldest first.
+const MAX_ENTRIES: usize = 1000;
+
+/// A line of the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The time since the app started.
+    pub elapsed: Duration,
+    pub level:   Level,
+    pub message: String,
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>8.2}s {:<5} {}",
+            self.elapsed.as_secs_f64(),
+            self.level,
+            self.message
+        )
+    }
+}
+
+/// The latest entries of the log, shared between the logger and the log
+/// pane.
+#[derive(Debug, Clone, Default)]
+pub struct LogEntries(Arc<Mutex<VecDeque<Entry>>>);
+
+impl LogEntries {
+    /// Locks the entries, carrying on past a panic in another thread, since
+    /// the log matters most just then.
+    fn lock(&self) -> MutexGuard<'_, VecDeque<Entry>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn push(&self, entry: Entry) {
+        let mut entries = self.lock();
+
+        if entries.len() == MAX_ENTRIES {
+            entries.pop_front();
+        }
+
+        entries.push_back(entry);
+    }
+
+    fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns up to `count` entries, ending `skip` entries before the
+    /// latest one.
+    pub fn latest(&self, count: usize, skip: usize) -> Vec<Entry> {
+        let entries = self.lock();
+        let end = entries.len().saturating_sub(skip);
+
+        entries
+            .range(end.saturating_sub(count)..end)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Collects the diagnostics of the app for the log pane, rather than letting
+/// them scribble over the screen, and optionally appends them to a file.
+#[derive(Debug)]
+pub struct Logger {
+    start:   Instant,
+    entries: LogEntries,
+    file:    Option<Mutex<File>>,
+}
+
+impl Logger {
+    /// Creates a logger that also writes to `file`, if given.
+    pub fn new(file: Option<File>) -> Self {
+        Self {
+            start:   Instant::now(),
+            entries: LogEntries::default(),
+            file:    file.map(Mutex::new),
+        }
+    }
+
+    /// Makes this the logger of the `log` macros, recording `Info` and
+    /// above.
+    ///
+    /// # Returns
+    ///
+    /// The entries the logger records into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a logger has already been set.
+    pub fn install(self) -> Result<LogEntries, SetLoggerError> {
+        let entries = self.entries.clone();
+
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(LevelFilter::Info);
+
+        Ok(entries)
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let entry = Entry {
+            elapsed: self.start.elapsed(),
+            level:   record.level(),
+            message: record.args().to_string(),
+        };
+
+        if let Some(file) = &self.file {
+            let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+
+            // There is nowhere left to report a log that cannot be written.
+            let _ = writeln!(file, "{entry}");
+        }
+
+        self.entries.push(entry);
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            let _ = file.lock().unwrap_or_else(PoisonError::into_inner).flush();
+        }
+    }
+}
+
+/// The color of the level of an entry.
+const fn level_color(level: Level) -> Color {
+    match level {
+        Level::Error => Color::Red,
+        Level::Warn => Color::Yellow,
+        Level::Info => Color::Green,
+        Level::Debug | Level::Trace => Color::DarkGray,
+    }
+}
+
+/// A pane at the bottom of the app showing the latest log entries, which
+/// can be hidden and scrolled back.
+#[derive(Debug)]
+pub struct LogPane {
+    entries:   LogEntries,
+    /// Whether the pane takes up any room.
+    pub shown: bool,
+    /// How many entries the pane is scrolled back from the latest one.
+    scroll:    usize,
+}
+
+impl LogPane {
+    /// The height of the pane, including its border.
+    pub const HEIGHT: u16 = 8;
+
+    /// Creates a hidden pane showing `entries`.
+    pub const fn new(entries: LogEntries) -> Self {
+        Self {
+            entries,
+            shown: false,
+            scroll: 0,
+        }
+    }
+
+    /// Shows or hides the pane, returning to the latest entries.
+    pub fn toggle(&mut self) {
+        self.shown = !self.shown;
+        self.scroll = 0;
+    }
+
+    /// The number of entries that fit inside `rect`.
+    const fn visible(rect: Rect) -> usize {
+        rect.height.saturating_sub(2) as usize
+    }
+
+    /// Scrolling over the pane, drawn in `rect`, moves through the entries.
+    pub fn on_mouse(&mut self, event: MouseEvent, rect: Rect) {
+        if !rect.contains(Position::new(event.column, event.row)) {
+            return;
+        }
+
+        let max_scroll = self.entries.len().saturating_sub(Self::visible(rect));
+
+        match event.kind {
+            MouseEventKind::ScrollUp => self.scroll = (self.scroll + 1).min(max_scroll),
+            MouseEventKind::ScrollDown => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, rect: Rect) {
+        let lines: Vec<Line> = self
+            .entries
+            .latest(Self::visible(rect), self.scroll)
+            .into_iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::raw(format!("{:>8.2}s ", entry.elapsed.as_secs_f64())),
+                    Span::styled(format!("{:<5} ", entry.level), level_color(entry.level)),
+                    Span::raw(entry.message),
+                ])
+            })
+            .collect();
+
+        let title = if self.scroll > 0 {
+            format!(" Log (-{}) ", self.scroll)
+        } else {
+            " Log ".to_string()
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            ),
+            rect,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::ui::tests::render;
+
+    /// Logs `message` at `level` through `logger`, as the `log` macros would.
+    fn log(logger: &Logger, level: Level, message: &str) {
+        logger.log(
+            &Record::builder()
+                .level(level)
+                .args(format_args!("{message}"))
+                .build(),
+        );
+    }
+
+    #[test]
+    fn test_entries_are_capped() {
+        let entries = LogEntries::default();
+
+        for index in 0..=MAX_ENTRIES {
+            entries.push(Entry {
+                elapsed: Duration::ZERO,
+                level:   Level::Info,
+                message: index.to_string(),
+            });
+        }
+
+        assert_eq!(entries.len(), MAX_ENTRIES);
+
+        let latest = entries.latest(2, 1);
+
+        assert_eq!(latest[0].message, (MAX_ENTRIES - 2).to_string());
+        assert_eq!(latest[1].message, (MAX_ENTRIES - 1).to_string());
+        assert!(entries.latest(5, MAX_ENTRIES).is_empty());
+    }
+
+    #[test]
+    fn test_logger_writes_file() {
+        let path = std::env::temp_dir().join(format!("tactix-tui-{}.log", std::process::id()));
+        let logger = Logger::new(Some(File::create(&path).unwrap()));
+
+        log::set_max_level(LevelFilter::Info);
+        log(&logger, Level::Warn, "the opponent forfeited");
+        log(&logger, Level::Debug, "too chatty");
+        logger.flush();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(written.ends_with("WARN  the opponent forfeited\n"));
+        assert_eq!(written.lines().count(), 1);
+        assert_eq!(logger.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_pane_scrolls() {
+        let logger = Logger::new(None);
+
+        log::set_max_level(LevelFilter::Info);
+        for index in 1..=20 {
+            log(&logger, Level::Info, &format!("entry {index}"));
+        }
+
+        let mut pane = LogPane::new(logger.entries.clone());
+        let rect = Rect::new(0, 30, 120, LogPane::HEIGHT);
+        let scroll = |kind| MouseEvent {
+            kind,
+            column: 5,
+            row: 32,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        pane.on_mouse(scroll(MouseEventKind::ScrollUp), rect);
+        pane.on_mouse(scroll(MouseEventKind::ScrollUp), rect);
+        pane.on_mouse(scroll(MouseEventKind::ScrollDown), rect);
+
+        let text = render(|frame, _| pane.render(frame, rect));
+
+        assert!(text.contains("Log (-1)"));
+        assert!(text.contains("INFO  entry 19"));
+        assert!(!text.contains("entry 20"));
+
+        for _ in 0..50 {
+            pane.on_mouse(scroll(MouseEventKind::ScrollUp), rect);
+        }
+        assert_eq!(pane.scroll, 14);
+
+        pane.toggle();
+        assert!(pane.shown);
+        assert_eq!(pane.scroll, 0);
+    }
+}
@@ -5,93 +5,158 @@
 
 use std::{
     error::Error,
+    fs::File,
     io,
+    path::PathBuf,
+    time::Duration,
 };
 
-use crossterm::{
-    event::{
-        self,
-        DisableMouseCapture,
-        EnableMouseCapture,
-        Event,
-        KeyCode,
-    },
-    execute,
-    terminal::{
-        disable_raw_mode,
-        enable_raw_mode,
-        EnterAlternateScreen,
-        LeaveAlternateScreen,
-    },
+use clap::Parser;
+use crossterm::event::{
+    self,
+    Event,
 };
 use dilemma_tactix_lib::{
-    GameGrid,
     GameOptions,
+    StrategyKind,
 };
 use ratatui::{
-    backend::{
-        Backend,
-        CrosstermBackend,
-    },
+    backend::Backend,
+    layout::Rect,
     Terminal,
 };
 
 mod app;
+mod events;
+mod logger;
+mod screens;
+mod terminal;
 mod ui;
 use crate::{
     app::App,
+    events::{
+        AppEvent,
+        Events,
+    },
+    logger::Logger,
+    screens::Settings,
+    terminal::TerminalGuard,
     ui::ui,
 };
 
-fn main() -> Result<(), Box<dyn Error>> {
-    // setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    let game_options = GameOptions::builder("customized").build();
-    let game = GameGrid::new(game_options);
-
-    // create app and run it
-    let mut app = App::new(game);
-    let _res = run_app(&mut terminal, &mut app);
-
-    // restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+/// How often the app ticks, redrawing the screen and moving animations along.
+const TICK_RATE: Duration = Duration::from_millis(50);
 
-    Ok(())
+/// Play an iterated two-player dilemma against a computer opponent in the
+/// terminal.
+///
+/// You play as Aleph, choosing a row of the grid each round, while the
+/// opponent plays as Beth. The options below set the starting settings,
+/// which can be changed from the main menu.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The opponent strategy.
+    #[arg(short, long, value_name = "STRATEGY", default_value = "tit-for-tat")]
+    opponent: StrategyKind,
+
+    /// The number of rounds in a match.
+    #[arg(
+        short,
+        long,
+        default_value_t = 10,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=Settings::MAX_ROUNDS as u64)
+    )]
+    rounds: usize,
+
+    /// Seed the random strategy, so that it plays the same way every time.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// The directory the load screen lists game files from.
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    games_dir: PathBuf,
+
+    /// Append the log, which can be shown with `L`, to this file as well.
+    #[arg(long, value_name = "FILE")]
+    log_file: Option<PathBuf>,
 }
 
-#[allow(clippy::needless_pass_by_ref_mut)]
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<bool> {
-    loop {
-        terminal.draw(|f| ui(f, app))?;
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+
+    let game_options = GameOptions::builder("customized").build()?;
+    let settings = Settings {
+        opponent: cli.opponent,
+        rounds: cli.rounds,
+        seed: cli.seed,
+        games_dir: cli.games_dir,
+        ..Settings::new("custom", game_options)
+    };
+
+    let log_file = cli
+        .log_file
+        .map(|path| File::options().create(true).append(true).open(path))
+        .transpose()?;
+    let log = Logger::new(log_file).install()?;
+
+    log::info!(
+        "Playing {} rounds against {} in {}",
+        settings.rounds,
+        settings.opponent.name(),
+        settings.game_name
+    );
+
+    // The guard restores the terminal however the app ends, and the hook
+    // does so before a panic is reported.
+    terminal::install_panic_hook();
+    let mut terminal = TerminalGuard::new()?;
+
+    let mut events = Events::new(TICK_RATE);
+    let mut app = App::new(settings, events.messenger(), log);
+    let result = run_app(&mut terminal, &mut app, &mut events).await;
+
+    drop(terminal);
+
+    if let Err(e) = &result {
+        log::error!("The terminal failed: {e}");
+    }
+
+    log::logger().flush();
+
+    Ok(result?)
+}
+
+async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    events: &mut Events,
+) -> io::Result<()> {
+    while !app.quit {
+        // Messages only change what the next tick draws, so that a busy
+        // background task does not redraw the screen for every one of them.
+        match events.next().await {
+            AppEvent::Tick => app.tick(),
+            AppEvent::Message(message) => {
+                app.on_message(&message);
 
-        if let Event::Key(key) = event::read()? {
-            if key.kind == event::KeyEventKind::Release {
-                // Skip events that are not KeyEventKind::Press
                 continue;
             }
-            match key.code {
-                KeyCode::Char('q') => {
-                    eprint!("'q' pressed; breaking loop");
-                    break;
+            AppEvent::Terminal(event) => match event? {
+                Event::Key(key) if key.kind != event::KeyEventKind::Release => {
+                    app.on_key(key.code);
                 }
-                KeyCode::Esc => {
-                    eprint!("ESC pressed; breaking loop");
-                    break;
+                Event::Mouse(mouse) => {
+                    let size = terminal.size()?;
+
+                    app.on_mouse(mouse, Rect::new(0, 0, size.width, size.height));
                 }
                 _ => {}
-            }
+            },
         }
+
+        terminal.draw(|f| ui(f, app))?;
     }
-    Ok(false)
+    Ok(())
 }
@@ -0,0 +1,564 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    Choice,
+    GameOptions,
+    GameParams,
+    NumberPair,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+        Wrap,
+    },
+};
+
+use super::{
+    game::{
+        index,
+        GameLayout,
+    },
+    Action,
+    GameScreen,
+    Screen,
+    Settings,
+};
+use crate::ui::cell;
+
+/// The name an edited game is remembered by.
+const CUSTOM_GAME: &str = "custom";
+
+/// The choices in the order of the rows and columns of the grid.
+const CHOICES: [Choice; 2] = [Choice::Atlantis, Choice::Olympus];
+
+/// A value of the game that can be edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    /// The name of a choice, shown both above a column and beside a row.
+    Name(Choice),
+    /// The scores when Aleph and Beth make these choices.
+    Score(Choice, Choice),
+}
+
+impl Field {
+    /// The field under the cursor at `(row, column)` of the grid, where the
+    /// first row and column hold the choice names.
+    ///
+    /// The top left corner holds no field.
+    const fn at(row: usize, column: usize) -> Option<Self> {
+        match (row, column) {
+            (0, 0) => None,
+            (0, column) => Some(Self::Name(CHOICES[column - 1])),
+            (row, 0) => Some(Self::Name(CHOICES[row - 1])),
+            (row, column) => Some(Self::Score(CHOICES[row - 1], CHOICES[column - 1])),
+        }
+    }
+
+    /// The value of the field as it is typed.
+    fn text(self, game_options: &GameOptions) -> String {
+        match self {
+            Self::Name(choice) => game_options.choice_name(choice).to_string(),
+            Self::Score(aleph_choice, beth_choice) => {
+                let score = score(game_options, aleph_choice, beth_choice);
+
+                format!("{}, {}", score.first(), score.second())
+            }
+        }
+    }
+
+    /// Builds `game_options` again with the field set to `text`.
+    fn apply(self, game_options: &GameOptions, text: &str) -> Result<GameOptions, String> {
+        let mut params = params(game_options);
+
+        match self {
+            Self::Name(Choice::Atlantis) => params.choice_atlantis = Some(text.trim().to_string()),
+            Self::Name(Choice::Olympus) => params.choice_olympus = Some(text.trim().to_string()),
+            Self::Score(aleph_choice, beth_choice) => {
+                let score = Some(parse_score(text)?);
+
+                match (aleph_choice, beth_choice) {
+                    (Choice::Atlantis, Choice::Atlantis) => params.atlantis_atlantis = score,
+                    (Choice::Atlantis, Choice::Olympus) => params.atlantis_olympus = score,
+                    (Choice::Olympus, Choice::Atlantis) => params.olympus_atlantis = score,
+                    (Choice::Olympus, Choice::Olympus) => params.olympus_olympus = score,
+                }
+            }
+        }
+
+        params.build().map_err(|e| e.to_string())
+    }
+}
+
+/// The scores of one cell of the grid.
+const fn score(
+    game_options: &GameOptions,
+    aleph_choice: Choice,
+    beth_choice: Choice,
+) -> NumberPair {
+    match (aleph_choice, beth_choice) {
+        (Choice::Atlantis, Choice::Atlantis) => game_options.atlantis_atlantis(),
+        (Choice::Atlantis, Choice::Olympus) => game_options.atlantis_olympus(),
+        (Choice::Olympus, Choice::Atlantis) => game_options.olympus_atlantis(),
+        (Choice::Olympus, Choice::Olympus) => game_options.olympus_olympus(),
+    }
+}
+
+/// The parameters of a customized game equal to `game_options`.
+fn params(game_options: &GameOptions) -> GameParams {
+    GameParams {
+        builder: Some("customized".to_string()),
+        choice_atlantis: Some(game_options.choice_atlantis().to_string()),
+        choice_olympus: Some(game_options.choice_olympus().to_string()),
+        atlantis_atlantis: Some(game_options.atlantis_atlantis()),
+        atlantis_olympus: Some(game_options.atlantis_olympus()),
+        olympus_atlantis: Some(game_options.olympus_atlantis()),
+        olympus_olympus: Some(game_options.olympus_olympus()),
+        ..GameParams::default()
+    }
+}
+
+/// Reads Aleph's and Beth's scores from text such as `3, 5`, `3 5` or
+/// `(3, 5)`.
+fn parse_score(text: &str) -> Result<NumberPair, String> {
+    let error = || format!("Invalid scores {text:?}; type Aleph's then Beth's, like 3, 5");
+
+    let parts: Vec<&str> = text
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    match parts[..] {
+        [first, second] => Ok(NumberPair::new(
+            first.parse().map_err(|_| error())?,
+            second.parse().map_err(|_| error())?,
+        )),
+        _ => Err(error()),
+    }
+}
+
+/// The screen for changing the payoffs and choice names of the game.
+///
+/// Each change is checked by building the game again, so the grid always
+/// shows a valid game.
+#[derive(Debug)]
+pub struct EditorScreen {
+    /// The game as edited so far.
+    pub game_options: GameOptions,
+    /// The row of the cursor, where row 0 holds Beth's choices.
+    row:              usize,
+    /// The column of the cursor, where column 0 holds Aleph's choices.
+    column:           usize,
+    /// The text typed into the field under the cursor, while editing it.
+    pub input:        Option<String>,
+    /// Why the last value typed was rejected.
+    pub error:        Option<String>,
+}
+
+impl EditorScreen {
+    /// Starts editing the game chosen in the settings.
+    pub const fn new(settings: &Settings) -> Self {
+        Self {
+            game_options: settings.game_options,
+            row:          1,
+            column:       1,
+            input:        None,
+            error:        None,
+        }
+    }
+
+    /// Whether a value is being typed, so that every key belongs to the
+    /// editor.
+    pub const fn is_typing(&self) -> bool {
+        self.input.is_some()
+    }
+
+    fn field(&self) -> Option<Field> {
+        Field::at(self.row, self.column)
+    }
+
+    /// Moves the cursor, unless that would leave the grid or land on the
+    /// empty corner.
+    fn move_cursor(&mut self, rows: isize, columns: isize) {
+        let row = self.row.saturating_add_signed(rows).min(2);
+        let column = self.column.saturating_add_signed(columns).min(2);
+
+        if Field::at(row, column).is_some() {
+            self.row = row;
+            self.column = column;
+        }
+    }
+
+    /// Sets the field under the cursor to what was typed, or shows why it
+    /// can not be set and keeps the text for fixing.
+    fn commit(&mut self) {
+        let (Some(field), Some(input)) = (self.field(), &self.input) else {
+            return;
+        };
+
+        match field.apply(&self.game_options, input) {
+            Ok(game_options) => {
+                self.game_options = game_options;
+                self.input = None;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Makes the edited game the one played and starts a match of it.
+    fn save(&self, settings: &mut Settings) -> Action {
+        if self.game_options != settings.game_options {
+            log::info!("Playing an edited game as {CUSTOM_GAME}");
+            settings.game_name = CUSTOM_GAME.to_string();
+            settings.game_options = self.game_options;
+        }
+
+        Action::Replace(Screen::Game(GameScreen::from_settings(settings)))
+    }
+
+    /// Handles a key press.
+    ///
+    /// `Enter` starts typing a new value for the field under the cursor,
+    /// and while typing `Enter` sets it and `Esc` leaves it unchanged. `S`
+    /// saves the game and plays it.
+    pub fn on_key(&mut self, code: KeyCode, settings: &mut Settings) -> Action {
+        if let Some(input) = &mut self.input {
+            match code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => self.commit(),
+                KeyCode::Esc => {
+                    self.input = None;
+                    self.error = None;
+                }
+                _ => {}
+            }
+
+            return Action::None;
+        }
+
+        match code {
+            KeyCode::Up => self.move_cursor(-1, 0),
+            KeyCode::Down => self.move_cursor(1, 0),
+            KeyCode::Left => self.move_cursor(0, -1),
+            KeyCode::Right => self.move_cursor(0, 1),
+            KeyCode::Enter => {
+                self.input = self.field().map(|field| field.text(&self.game_options));
+            }
+            KeyCode::Char('s' | 'S') => return self.save(settings),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// Clicking a field moves the cursor to it.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect) -> Action {
+        if self.is_typing() || event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Action::None;
+        }
+
+        let layout = GameLayout::new(area);
+        let position = Position::new(event.column, event.row);
+
+        let mut fields = layout
+            .column_headers
+            .iter()
+            .enumerate()
+            .map(|(column, rect)| (0, column + 1, rect))
+            .chain(
+                layout
+                    .row_headers
+                    .iter()
+                    .enumerate()
+                    .map(|(row, rect)| (row + 1, 0, rect)),
+            )
+            .chain(layout.cells.iter().enumerate().flat_map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(move |(column, rect)| (row + 1, column + 1, rect))
+            }));
+
+        if let Some((row, column, _)) = fields.find(|(_, _, rect)| rect.contains(position)) {
+            self.row = row;
+            self.column = column;
+        }
+
+        Action::None
+    }
+
+    /// The text and style of the field at `(row, column)`.
+    fn cell_content(&self, row: usize, column: usize, text: String) -> (String, Style) {
+        if (row, column) != (self.row, self.column) {
+            return (text, Style::new().white().on_black());
+        }
+
+        match (&self.input, &self.error) {
+            (Some(input), Some(_)) => (format!("{input}_"), Style::new().white().on_red().bold()),
+            (Some(input), None) => (format!("{input}_"), Style::new().black().on_green().bold()),
+            (None, _) => (text, Style::new().black().on_yellow().bold()),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, settings: &Settings) {
+        let layout = GameLayout::new(area);
+        let game_options = &self.game_options;
+
+        self.render_status(frame, layout.status, settings);
+        render_help(frame, layout.history);
+        self.render_summary(frame, layout.scoreboard);
+
+        frame.render_widget(
+            cell("Player Beth", Style::new().white().on_black()),
+            layout.beth_header,
+        );
+        frame.render_widget(
+            cell("Player Aleph", Style::new().white().on_black()),
+            layout.aleph_header,
+        );
+
+        for choice in CHOICES {
+            let name = game_options.choice_name(choice).to_string();
+            let (text, style) = self.cell_content(0, index(choice) + 1, name.clone());
+
+            frame.render_widget(cell(text, style), layout.column_headers[index(choice)]);
+
+            let (text, style) = self.cell_content(index(choice) + 1, 0, name);
+
+            frame.render_widget(cell(text, style), layout.row_headers[index(choice)]);
+        }
+
+        for aleph_choice in CHOICES {
+            for beth_choice in CHOICES {
+                let (text, style) = self.cell_content(
+                    index(aleph_choice) + 1,
+                    index(beth_choice) + 1,
+                    score(game_options, aleph_choice, beth_choice).to_string(),
+                );
+
+                frame.render_widget(
+                    cell(text, style),
+                    layout.cells[index(aleph_choice)][index(beth_choice)],
+                );
+            }
+        }
+    }
+
+    /// Shows which game is being edited and why the last value was
+    /// rejected, if it was.
+    fn render_status(&self, frame: &mut Frame, rect: Rect, settings: &Settings) {
+        let name = if self.game_options == settings.game_options {
+            settings.game_name.as_str()
+        } else {
+            CUSTOM_GAME
+        };
+
+        let mut lines = vec![Line::from(format!("Editing {name}")).bold()];
+
+        match (&self.error, self.is_typing()) {
+            (Some(error), _) => lines.push(Line::from(error.as_str()).red()),
+            (None, true) => lines.push(Line::from("Type a value and press Enter to set it")),
+            (None, false) => {
+                lines.push(Line::from("Press Enter to change the highlighted value"));
+            }
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).centered().wrap(Wrap { trim: false }),
+            rect,
+        );
+    }
+
+    /// Shows what kind of game the payoffs make.
+    fn render_summary(&self, frame: &mut Frame, rect: Rect) {
+        let lines = vec![
+            Line::from("Class").bold(),
+            Line::from(format!("  {}", self.game_options.classify())),
+        ];
+
+        let block = Block::default()
+            .title(" Game ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            rect,
+        );
+    }
+}
+
+/// Explains how the grid is read and edited.
+fn render_help(frame: &mut Frame, rect: Rect) {
+    let lines = vec![
+        Line::from("Each cell holds Aleph's score, then Beth's."),
+        Line::default(),
+        Line::from("Type scores as two whole numbers, like 3, 5."),
+        Line::default(),
+        Line::from("The headers name the two choices; both must differ."),
+    ];
+
+    let block = Block::default()
+        .title(" Help ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded);
+
+    frame.render_widget(
+        Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+        rect,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        screens::settings::tests::settings,
+        ui::tests::render,
+    };
+
+    /// Types `text` into the field under the cursor and presses `Enter`.
+    fn type_value(editor: &mut EditorScreen, settings: &mut Settings, text: &str) {
+        editor.on_key(KeyCode::Enter, settings);
+
+        for _ in 0..20 {
+            editor.on_key(KeyCode::Backspace, settings);
+        }
+
+        for c in text.chars() {
+            editor.on_key(KeyCode::Char(c), settings);
+        }
+
+        editor.on_key(KeyCode::Enter, settings);
+    }
+
+    #[rstest]
+    #[case("3, 5", Some(NumberPair::new(3, 5)))]
+    #[case("(2,0)", Some(NumberPair::new(2, 0)))]
+    #[case(" 7 1 ", Some(NumberPair::new(7, 1)))]
+    #[case("3", None)]
+    #[case("3, 5, 1", None)]
+    #[case("-1, 5", None)]
+    #[case("a, b", None)]
+    fn test_parse_score(#[case] text: &str, #[case] expected: Option<NumberPair>) {
+        assert_eq!(parse_score(text).ok(), expected);
+    }
+
+    #[test]
+    fn test_edit_score() {
+        let mut settings = settings();
+        let mut editor = EditorScreen::new(&settings);
+
+        editor.on_key(KeyCode::Right, &mut settings);
+        type_value(&mut editor, &mut settings, "9, 0");
+
+        assert!(!editor.is_typing());
+        assert_eq!(
+            editor.game_options.atlantis_olympus(),
+            NumberPair::new(9, 0)
+        );
+
+        type_value(&mut editor, &mut settings, "nine");
+
+        assert!(editor.is_typing());
+        assert!(editor.error.as_ref().unwrap().contains("nine"));
+        assert_eq!(
+            editor.game_options.atlantis_olympus(),
+            NumberPair::new(9, 0)
+        );
+
+        editor.on_key(KeyCode::Esc, &mut settings);
+
+        assert!(!editor.is_typing());
+        assert!(editor.error.is_none());
+    }
+
+    #[test]
+    fn test_edit_names() {
+        let mut settings = settings();
+        let mut editor = EditorScreen::new(&settings);
+
+        // The corner holds no field, so the cursor stays beside the row.
+        editor.on_key(KeyCode::Left, &mut settings);
+        editor.on_key(KeyCode::Up, &mut settings);
+        type_value(&mut editor, &mut settings, "defect");
+
+        assert_eq!(
+            editor.error.as_deref(),
+            Some("Both choices are named defect; choice names must differ")
+        );
+
+        type_value(&mut editor, &mut settings, " share ");
+
+        assert!(editor.error.is_none());
+        assert_eq!(editor.game_options.choice_atlantis(), "share");
+
+        editor.on_key(KeyCode::Right, &mut settings);
+        editor.on_key(KeyCode::Up, &mut settings);
+        type_value(&mut editor, &mut settings, "");
+
+        assert_eq!(
+            editor.error.as_deref(),
+            Some("choice_atlantis must not be empty")
+        );
+    }
+
+    #[test]
+    fn test_save() {
+        let mut settings = settings();
+        let mut editor = EditorScreen::new(&settings);
+
+        assert!(matches!(
+            editor.on_key(KeyCode::Char('s'), &mut settings),
+            Action::Replace(Screen::Game(_))
+        ));
+        assert_eq!(settings.game_name, "prisoners_dilemma");
+
+        editor.on_key(KeyCode::Down, &mut settings);
+        type_value(&mut editor, &mut settings, "0, 0");
+        editor.on_key(KeyCode::Char('S'), &mut settings);
+
+        assert_eq!(settings.game_name, CUSTOM_GAME);
+        assert_eq!(
+            settings.game_options.olympus_atlantis(),
+            NumberPair::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn test_render_error() {
+        let mut settings = settings();
+        let mut editor = EditorScreen::new(&settings);
+
+        type_value(&mut editor, &mut settings, "3");
+        let screen = render(|frame, area| editor.render(frame, area, &settings));
+
+        assert!(screen.contains("Editing prisoners_dilemma"));
+        assert!(screen.contains("Invalid scores \"3\""));
+        assert!(screen.contains("3_"));
+        assert!(screen.contains("Class"));
+    }
+}
@@ -0,0 +1,641 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    Choice,
+    GameGrid,
+    MatchHistory,
+    Player,
+    Round,
+    Strategy,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+        Wrap,
+    },
+};
+
+use super::{
+    Action,
+    Settings,
+};
+use crate::ui::{
+    cell,
+    row_at,
+};
+
+/// The areas of the game screen, shared by drawing and mouse hit-testing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameLayout {
+    pub history:        Rect,
+    pub scoreboard:     Rect,
+    pub status:         Rect,
+    pub beth_header:    Rect,
+    pub aleph_header:   Rect,
+    /// Beth's choices, heading the columns.
+    pub column_headers: [Rect; 2],
+    /// Aleph's choices, heading the rows.
+    pub row_headers:    [Rect; 2],
+    /// The payoff cells, by Aleph's choice and then Beth's.
+    pub cells:          [[Rect; 2]; 2],
+}
+
+impl GameLayout {
+    pub fn new(area: Rect) -> Self {
+        let main_content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(3, 5),
+                Constraint::Ratio(1, 5),
+            ])
+            .flex(layout::Flex::Center)
+            .split(area);
+
+        let game_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(20); 5])
+            .split(main_content_layout[1]);
+
+        let beth_header_row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(2, 5),
+                Constraint::Ratio(1, 5),
+            ])
+            .split(game_rows[1]);
+
+        let aleph_header_row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(20); 5])
+            .split(game_rows[2]);
+
+        let atlantis_row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(20); 5])
+            .split(game_rows[3]);
+
+        let olympus_row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(20); 5])
+            .split(game_rows[4]);
+
+        Self {
+            history:        main_content_layout[0],
+            scoreboard:     main_content_layout[2],
+            status:         game_rows[0],
+            beth_header:    beth_header_row[2],
+            aleph_header:   aleph_header_row[1],
+            column_headers: [aleph_header_row[2], aleph_header_row[3]],
+            row_headers:    [atlantis_row[1], olympus_row[1]],
+            cells:          [
+                [atlantis_row[2], atlantis_row[3]],
+                [olympus_row[2], olympus_row[3]],
+            ],
+        }
+    }
+}
+
+/// The choices in the order of the rows and columns of the grid.
+const CHOICES: [Choice; 2] = [Choice::Atlantis, Choice::Olympus];
+
+/// The index of a choice in the rows and columns of the grid.
+pub const fn index(choice: Choice) -> usize {
+    match choice {
+        Choice::Atlantis => 0,
+        Choice::Olympus => 1,
+    }
+}
+
+/// The color a choice is drawn in, green for cooperating and red for
+/// defecting.
+pub const fn choice_color(choice: Choice) -> Color {
+    match choice {
+        Choice::Atlantis => Color::Green,
+        Choice::Olympus => Color::Red,
+    }
+}
+
+/// A match between the user, playing as Aleph, and a computer opponent
+/// playing as Beth.
+pub struct GameScreen {
+    pub game_grid:      GameGrid,
+    /// The choice highlighted in the grid, played on `Enter`.
+    pub selected:       Choice,
+    pub opponent:       Box<dyn Strategy + Send>,
+    pub history:        MatchHistory,
+    /// The number of rounds in a match.
+    pub rounds:         usize,
+    /// How many lines the history is scrolled back from the latest round.
+    pub history_scroll: usize,
+}
+
+impl GameScreen {
+    pub fn new(game_grid: GameGrid, opponent: Box<dyn Strategy + Send>, rounds: usize) -> Self {
+        Self {
+            game_grid,
+            selected: Choice::Atlantis,
+            opponent,
+            history: MatchHistory::new(),
+            rounds,
+            history_scroll: 0,
+        }
+    }
+
+    /// Starts a match with the game, opponent and length chosen in the
+    /// settings.
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self::new(
+            GameGrid::new(settings.game_options),
+            settings.opponent.strategy(settings.seed),
+            settings.rounds,
+        )
+    }
+
+    /// Whether every round has been played or the opponent has forfeited.
+    pub fn is_finished(&self) -> bool {
+        self.history.len() >= self.rounds || self.history.forfeit().is_some()
+    }
+
+    /// The round played last, if any.
+    pub fn last_round(&self) -> Option<Round> {
+        self.history.last()
+    }
+
+    /// Moves the highlight to the other choice.
+    pub fn toggle_selected(&mut self) {
+        self.selected = match self.selected {
+            Choice::Atlantis => Choice::Olympus,
+            Choice::Olympus => Choice::Atlantis,
+        };
+    }
+
+    /// Plays the highlighted choice against the opponent's next move.
+    ///
+    /// Does nothing once the match is finished.
+    pub fn play(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+
+        let beth_choice = self
+            .opponent
+            .next_choice(self.history.beth_choices(), self.history.aleph_choices());
+
+        if let Some(error) = self.opponent.error() {
+            log::warn!("{} forfeited the match: {error}", self.opponent.name());
+            self.history.record_forfeit(Player::Beth, error.clone());
+
+            return;
+        }
+
+        self.game_grid
+            .play_round(&mut self.history, self.selected, beth_choice);
+
+        if self.is_finished() {
+            log::info!(
+                "Finished a match of {} rounds against {}: {} - {}",
+                self.history.len(),
+                self.opponent.name(),
+                self.history.aleph_total(),
+                self.history.beth_total()
+            );
+        }
+    }
+
+    /// Starts a new match against the same opponent.
+    pub fn restart(&mut self) {
+        self.opponent.reset();
+        self.history = MatchHistory::new();
+        self.history_scroll = 0;
+    }
+
+    /// Handles a key press.
+    ///
+    /// The arrow keys move the highlight and `Enter` or `Space` plays it,
+    /// while `A` and `B` pick and play a choice in one go. `N` starts a new
+    /// match.
+    pub fn on_key(&mut self, code: KeyCode) -> Action {
+        match code {
+            KeyCode::Up | KeyCode::Down => self.toggle_selected(),
+            KeyCode::Enter | KeyCode::Char(' ') => self.play(),
+            KeyCode::Char('a' | 'A') => {
+                self.selected = Choice::Atlantis;
+                self.play();
+            }
+            KeyCode::Char('b' | 'B') => {
+                self.selected = Choice::Olympus;
+                self.play();
+            }
+            KeyCode::Char('n' | 'N') => self.restart(),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// The number of lines the history can be scrolled back, when shown in
+    /// `rect`: the legend, the column titles and a line per round, less
+    /// those that fit.
+    fn max_history_scroll(&self, rect: Rect) -> usize {
+        let height = usize::from(rect.height.saturating_sub(2));
+
+        (self.history.len() + 2).saturating_sub(height)
+    }
+
+    /// Handles a mouse event inside `area`.
+    ///
+    /// Clicking one of the user's choices selects it and clicking a payoff
+    /// cell plays the choice of its row, while scrolling over the history
+    /// moves through the rounds played.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect) -> Action {
+        let layout = GameLayout::new(area);
+        let position = Position::new(event.column, event.row);
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(row) = row_at(&layout.row_headers, event.column, event.row) {
+                    self.selected = CHOICES[row];
+                } else if let Some(row) = layout
+                    .cells
+                    .iter()
+                    .position(|cells| row_at(cells, event.column, event.row).is_some())
+                {
+                    self.selected = CHOICES[row];
+                    self.play();
+                }
+            }
+            MouseEventKind::ScrollUp if layout.history.contains(position) => {
+                self.history_scroll =
+                    (self.history_scroll + 1).min(self.max_history_scroll(layout.history));
+            }
+            MouseEventKind::ScrollDown if layout.history.contains(position) => {
+                self.history_scroll = self.history_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let layout = GameLayout::new(area);
+
+        self.render_history(frame, layout.history);
+        self.render_scoreboard(frame, layout.scoreboard);
+        self.render_status(frame, layout.status);
+
+        frame.render_widget(
+            cell("Player Beth", Style::new().white().on_black()),
+            layout.beth_header,
+        );
+        frame.render_widget(
+            cell("Player Aleph", Style::new().white().on_black()),
+            layout.aleph_header,
+        );
+
+        let game_options = self.game_grid.game_options;
+        let last_round = self.last_round();
+
+        for choice in [Choice::Atlantis, Choice::Olympus] {
+            // Beth's choices head the columns, marking the one she played last.
+            let style = if last_round.is_some_and(|round| round.beth_choice == choice) {
+                Style::new().black().on_cyan().bold()
+            } else {
+                Style::new().white().on_black()
+            };
+
+            frame.render_widget(
+                cell(game_options.choice_name(choice), style),
+                layout.column_headers[index(choice)],
+            );
+
+            // Aleph's choices head the rows, marking the one that is selected.
+            let style = if self.selected == choice {
+                Style::new().black().on_yellow().bold()
+            } else {
+                Style::new().white().on_black()
+            };
+
+            frame.render_widget(
+                cell(game_options.choice_name(choice), style),
+                layout.row_headers[index(choice)],
+            );
+        }
+
+        for aleph_choice in [Choice::Atlantis, Choice::Olympus] {
+            for beth_choice in [Choice::Atlantis, Choice::Olympus] {
+                let played = last_round.is_some_and(|round| {
+                    round.aleph_choice == aleph_choice && round.beth_choice == beth_choice
+                });
+
+                let style = if played {
+                    Style::new().black().on_green().bold()
+                } else if self.selected == aleph_choice {
+                    Style::new().white().on_dark_gray()
+                } else {
+                    Style::new().white().on_black()
+                };
+
+                let score = self.game_grid.return_score(aleph_choice, beth_choice);
+
+                frame.render_widget(
+                    cell(score.to_string(), style),
+                    layout.cells[index(aleph_choice)][index(beth_choice)],
+                );
+            }
+        }
+    }
+
+    /// Describes the match so far above the grid.
+    fn render_status(&self, frame: &mut Frame, rect: Rect) {
+        let game_options = self.game_grid.game_options;
+        let opponent = self.opponent.name();
+
+        let progress = format!(
+            "Round {} of {} against {opponent} | You {} - {} {opponent}",
+            self.history.len(),
+            self.rounds,
+            self.history.aleph_total(),
+            self.history.beth_total(),
+        );
+
+        let outcome = match (self.history.forfeit(), self.last_round()) {
+            (Some(forfeit), _) => format!("{opponent} forfeited: {}", forfeit.reason),
+            (None, Some(round)) => format!(
+                "You: {} (+{}) | {opponent}: {} (+{})",
+                game_options.choice_name(round.aleph_choice),
+                round.score.first(),
+                game_options.choice_name(round.beth_choice),
+                round.score.second(),
+            ),
+            (None, None) => "Pick a row to make your first move".to_string(),
+        };
+
+        let mut lines = vec![Line::from(progress).bold(), Line::from(outcome)];
+
+        if self.is_finished() {
+            let result = match self.history.winner() {
+                Some(Player::Aleph) => "You won the match!",
+                Some(Player::Beth) => "You lost the match.",
+                None => "The match is a tie.",
+            };
+
+            lines.push(Line::from(format!("{result} Press 'N' to play again.")).yellow());
+        }
+
+        frame.render_widget(Paragraph::new(lines).centered(), rect);
+    }
+
+    /// Shows every round played so far as a colored timeline, keeping the
+    /// latest rounds in view unless it has been scrolled back.
+    fn render_history(&self, frame: &mut Frame, rect: Rect) {
+        let game_options = self.game_grid.game_options;
+
+        let legend = Line::from(vec![
+            Span::styled("● ", choice_color(Choice::Atlantis)),
+            Span::raw(game_options.choice_atlantis()),
+            Span::raw("  "),
+            Span::styled("● ", choice_color(Choice::Olympus)),
+            Span::raw(game_options.choice_olympus()),
+        ]);
+
+        let mut lines = vec![legend, Line::from(" Rnd You Opp Score").bold()];
+
+        lines.extend(self.history.rounds().enumerate().map(|(index, round)| {
+            Line::from(vec![
+                Span::raw(format!("{:>4}  ", index + 1)),
+                Span::styled("●", choice_color(round.aleph_choice)),
+                Span::raw("   "),
+                Span::styled("●", choice_color(round.beth_choice)),
+                Span::raw(format!("  {}", round.score)),
+            ])
+        }));
+
+        let max_scroll = self.max_history_scroll(rect);
+        let scroll = self.history_scroll.min(max_scroll);
+
+        let title = if scroll > 0 {
+            format!(" History (-{scroll}) ")
+        } else {
+            " History ".to_string()
+        };
+
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        // Without scrolling back, the latest round sits on the bottom line.
+        let offset = u16::try_from(max_scroll - scroll).unwrap_or(u16::MAX);
+
+        frame.render_widget(Paragraph::new(lines).block(block).scroll((offset, 0)), rect);
+    }
+
+    /// The scoreboard lines for one player.
+    fn player_summary<'a>(&self, player: Player, name: &'a str, total: u64) -> Vec<Line<'a>> {
+        let game_options = self.game_grid.game_options;
+
+        let cooperation = self
+            .history
+            .cooperation_rate(player)
+            .map_or_else(|| "-".to_string(), |rate| format!("{:.0}%", rate * 100.0));
+
+        let streak = self.history.streak(player).map_or_else(
+            || Span::raw("-"),
+            |(choice, length)| {
+                Span::styled(
+                    format!("{length} x {}", game_options.choice_name(choice)),
+                    choice_color(choice),
+                )
+            },
+        );
+
+        vec![
+            Line::from(name).bold(),
+            Line::from(format!("  Score: {total}")),
+            Line::from(format!("  Cooperation: {cooperation}")),
+            Line::from(vec![Span::raw("  Streak: "), streak]),
+        ]
+    }
+
+    /// Shows both players' totals, how often they cooperated and their
+    /// current streaks.
+    fn render_scoreboard(&self, frame: &mut Frame, rect: Rect) {
+        let mut lines = self.player_summary(Player::Aleph, "You", self.history.aleph_total());
+
+        lines.push(Line::default());
+        lines.extend(self.player_summary(
+            Player::Beth,
+            self.opponent.name(),
+            self.history.beth_total(),
+        ));
+
+        let block = Block::default()
+            .title(" Scoreboard ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            rect,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+    use dilemma_tactix_lib::{
+        AlwaysDefect,
+        GamePresets,
+        NumberPair,
+        TitForTat,
+    };
+
+    use super::*;
+    use crate::ui::tests::{
+        render,
+        AREA,
+    };
+
+    fn game(opponent: Box<dyn Strategy + Send>, rounds: usize) -> GameScreen {
+        let game_options = GamePresets::get_preset("prisoners_dilemma").unwrap();
+
+        GameScreen::new(GameGrid::new(game_options), opponent, rounds)
+    }
+
+    #[test]
+    fn test_keys_select_and_play() {
+        let mut game = game(Box::new(TitForTat), 10);
+
+        game.on_key(KeyCode::Down);
+        assert_eq!(game.selected, Choice::Olympus);
+        assert!(game.history.is_empty());
+
+        game.on_key(KeyCode::Enter);
+        game.on_key(KeyCode::Char('a'));
+
+        assert_eq!(
+            game.history.aleph_choices(),
+            [Choice::Olympus, Choice::Atlantis]
+        );
+        assert_eq!(
+            game.history.beth_choices(),
+            [Choice::Atlantis, Choice::Olympus]
+        );
+        assert_eq!(game.last_round().unwrap().score, NumberPair::new(0, 5));
+        assert_eq!(game.selected, Choice::Atlantis);
+    }
+
+    #[test]
+    fn test_match_ends_after_rounds() {
+        let mut game = game(Box::new(AlwaysDefect), 2);
+
+        for _ in 0..5 {
+            game.on_key(KeyCode::Char('b'));
+        }
+
+        assert!(game.is_finished());
+        assert_eq!(game.history.len(), 2);
+        assert_eq!(game.history.aleph_total(), 2);
+
+        game.on_key(KeyCode::Char('n'));
+
+        assert!(!game.is_finished());
+        assert!(game.last_round().is_none());
+    }
+
+    #[test]
+    fn test_render_round() {
+        let mut game = game(Box::new(AlwaysDefect), 1);
+
+        assert!(render(|frame, area| game.render(frame, area)).contains("Pick a row"));
+
+        game.on_key(KeyCode::Char('a'));
+        let screen = render(|frame, area| game.render(frame, area));
+
+        assert!(screen.contains("Round 1 of 1 against always-defect | You 0 - 5 always-defect"));
+        assert!(screen.contains("You: cooperate (+0) | always-defect: defect (+5)"));
+        assert!(screen.contains("You lost the match."));
+        assert!(screen.contains("Cooperation: 100%"));
+        assert!(screen.contains("Streak: 1 x defect"));
+        assert!(screen.contains("1  ●   ●  (0, 5)"));
+    }
+
+    fn mouse(kind: MouseEventKind, rect: Rect) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column: rect.x + rect.width / 2,
+            row: rect.y + rect.height / 2,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_click_selects_and_plays() {
+        let mut game = game(Box::new(TitForTat), 10);
+        let layout = GameLayout::new(AREA);
+        let click = MouseEventKind::Down(MouseButton::Left);
+
+        game.on_mouse(mouse(click, layout.row_headers[1]), AREA);
+        assert_eq!(game.selected, Choice::Olympus);
+        assert!(game.history.is_empty());
+
+        game.on_mouse(mouse(click, layout.cells[0][1]), AREA);
+        assert_eq!(game.selected, Choice::Atlantis);
+        assert_eq!(game.history.aleph_choices(), [Choice::Atlantis]);
+
+        game.on_mouse(mouse(click, layout.column_headers[1]), AREA);
+        assert_eq!(game.history.len(), 1);
+    }
+
+    #[test]
+    fn test_scroll_history() {
+        let mut game = game(Box::new(TitForTat), 100);
+        let layout = GameLayout::new(AREA);
+
+        game.on_mouse(mouse(MouseEventKind::ScrollUp, layout.history), AREA);
+        assert_eq!(game.history_scroll, 0);
+
+        for _ in 0..40 {
+            game.on_key(KeyCode::Char('a'));
+        }
+
+        for _ in 0..3 {
+            game.on_mouse(mouse(MouseEventKind::ScrollUp, layout.history), AREA);
+        }
+        game.on_mouse(mouse(MouseEventKind::ScrollDown, layout.history), AREA);
+        game.on_mouse(mouse(MouseEventKind::ScrollUp, layout.scoreboard), AREA);
+        assert_eq!(game.history_scroll, 2);
+
+        let screen = render(|frame, area| game.render(frame, area));
+
+        assert!(screen.contains("History (-2)"));
+        assert!(screen.contains("38  ●"));
+        assert!(!screen.contains("40  ●"));
+
+        for _ in 0..100 {
+            game.on_mouse(mouse(MouseEventKind::ScrollUp, layout.history), AREA);
+        }
+        assert_eq!(game.history_scroll, game.max_history_scroll(layout.history));
+        assert!(render(|frame, area| game.render(frame, area)).contains("   1  ●"));
+    }
+}
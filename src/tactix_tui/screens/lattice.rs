@@ -0,0 +1,341 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    Choice,
+    GameGrid,
+    GameOptions,
+    Lattice,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+        Wrap,
+    },
+};
+
+use super::{
+    Action,
+    Settings,
+};
+use crate::TICK_RATE;
+
+/// The number of cells in each row of the lattice.
+const COLUMNS: usize = 40;
+
+/// The number of rows of the lattice.
+const ROWS: usize = 20;
+
+/// The number of terminal columns each cell is drawn across, so that cells
+/// look roughly square.
+const CELL_WIDTH: u16 = 2;
+
+/// The width of the panel beside the lattice.
+const PANEL_WIDTH: u16 = 30;
+
+/// The speeds the animation can play at, as ticks per step.
+const SPEEDS: [u32; 5] = [20, 10, 5, 2, 1];
+
+/// The share of cooperating cells in a random lattice.
+const RANDOM_COOPERATION: f64 = 0.9;
+
+/// The color of a cell, by its choice and whether it just switched to it.
+const fn cell_color(choice: Choice, switched: bool) -> Color {
+    match (choice, switched) {
+        (Choice::Atlantis, false) => Color::Blue,
+        (Choice::Olympus, false) => Color::Red,
+        (Choice::Atlantis, true) => Color::Green,
+        (Choice::Olympus, true) => Color::Yellow,
+    }
+}
+
+/// A lattice of cooperating cells with a single defector in the middle.
+fn centred_defector() -> Lattice {
+    let mut lattice = Lattice::new(COLUMNS, ROWS, Choice::Atlantis);
+
+    lattice.set(COLUMNS / 2, ROWS / 2, Choice::Olympus);
+
+    lattice
+}
+
+/// An animation of the spatial game, where every cell plays its neighbours
+/// and copies the best of them.
+#[derive(Debug)]
+pub struct LatticeScreen {
+    pub lattice:   Lattice,
+    pub game_grid: GameGrid,
+    /// Whether the lattice steps on its own.
+    pub playing:   bool,
+    /// The index of the speed in [`SPEEDS`].
+    pub speed:     usize,
+    /// The ticks since the last step.
+    ticks:         u32,
+    /// The seed of random lattices.
+    seed:          Option<u64>,
+}
+
+impl LatticeScreen {
+    /// Starts paused, with a single defector among cooperators, in the game
+    /// chosen in the settings.
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            lattice:   centred_defector(),
+            game_grid: GameGrid::new(settings.game_options),
+            playing:   false,
+            speed:     2,
+            ticks:     0,
+            seed:      settings.seed,
+        }
+    }
+
+    /// Steps the lattice once enough ticks have passed at the current speed.
+    pub fn tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+
+        self.ticks += 1;
+
+        if self.ticks >= SPEEDS[self.speed] {
+            self.ticks = 0;
+            self.lattice.step(&self.game_grid);
+        }
+    }
+
+    /// The number of steps played each second at the current speed.
+    fn steps_per_second(&self) -> u128 {
+        1000 / (TICK_RATE.as_millis() * u128::from(SPEEDS[self.speed])).max(1)
+    }
+
+    /// Handles a key press.
+    ///
+    /// `Space` plays or pauses, `S` or the right arrow takes a single step,
+    /// `+` and `-` change the speed, `R` starts again from a random lattice
+    /// and `C` from a single defector.
+    pub fn on_key(&mut self, code: KeyCode) -> Action {
+        match code {
+            KeyCode::Char(' ' | 'p' | 'P') => self.playing = !self.playing,
+            KeyCode::Char('s' | 'S') | KeyCode::Right => {
+                self.playing = false;
+                self.lattice.step(&self.game_grid);
+            }
+            KeyCode::Char('+' | '=') | KeyCode::Up => {
+                self.speed = (self.speed + 1).min(SPEEDS.len() - 1);
+            }
+            KeyCode::Char('-') | KeyCode::Down => self.speed = self.speed.saturating_sub(1),
+            KeyCode::Char('r' | 'R') => {
+                self.lattice = Lattice::random(COLUMNS, ROWS, RANDOM_COOPERATION, self.seed);
+            }
+            KeyCode::Char('c' | 'C') => self.lattice = centred_defector(),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// The areas of the lattice, including its border, and of the panel
+    /// beside it.
+    fn areas(area: Rect) -> [Rect; 2] {
+        let width = u16::try_from(COLUMNS).unwrap_or(u16::MAX) * CELL_WIDTH + 2;
+        let height = u16::try_from(ROWS).unwrap_or(u16::MAX) + 2;
+
+        let [area] = Layout::vertical([Constraint::Length(height)])
+            .flex(layout::Flex::Center)
+            .areas(area);
+
+        Layout::horizontal([Constraint::Length(width), Constraint::Length(PANEL_WIDTH)])
+            .flex(layout::Flex::Center)
+            .areas(area)
+    }
+
+    /// The cell under `(column, row)` of the terminal, if any.
+    fn cell_at(area: Rect, column: u16, row: u16) -> Option<(usize, usize)> {
+        let [lattice, _] = Self::areas(area);
+        let inner = lattice.inner(Margin::new(1, 1));
+
+        if !inner.contains(Position::new(column, row)) {
+            return None;
+        }
+
+        let x = usize::from((column - inner.x) / CELL_WIDTH);
+        let y = usize::from(row - inner.y);
+
+        (x < COLUMNS && y < ROWS).then_some((x, y))
+    }
+
+    /// Clicking a cell flips its choice.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect) -> Action {
+        if event.kind == MouseEventKind::Down(MouseButton::Left) {
+            if let Some((x, y)) = Self::cell_at(area, event.column, event.row) {
+                let choice = match self.lattice.get(x, y) {
+                    Choice::Atlantis => Choice::Olympus,
+                    Choice::Olympus => Choice::Atlantis,
+                };
+
+                self.lattice.set(x, y, choice);
+            }
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let [lattice, panel] = Self::areas(area);
+
+        let cell = " ".repeat(usize::from(CELL_WIDTH));
+
+        let lines: Vec<Line> = (0..self.lattice.height())
+            .map(|y| {
+                Line::from(
+                    (0..self.lattice.width())
+                        .map(|x| {
+                            let color =
+                                cell_color(self.lattice.get(x, y), self.lattice.switched(x, y));
+
+                            Span::styled(cell.clone(), Style::new().bg(color))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(titled(" Lattice ")), lattice);
+
+        self.render_panel(frame, panel);
+    }
+
+    /// Shows how far the animation has got and what the colors mean.
+    fn render_panel(&self, frame: &mut Frame, rect: Rect) {
+        let game_options: GameOptions = self.game_grid.game_options;
+
+        let state = if self.playing {
+            "Playing"
+        } else {
+            "Paused"
+        };
+
+        let mut lines = vec![
+            Line::from(state).bold(),
+            Line::from(format!("Generation: {}", self.lattice.generation())),
+            Line::from(format!(
+                "Cooperation: {:.0}%",
+                self.lattice.cooperation_rate() * 100.0
+            )),
+            Line::from(format!("Speed: {} steps/s", self.steps_per_second())),
+            Line::default(),
+        ];
+
+        lines.extend(
+            [
+                (Choice::Atlantis, false, "stays"),
+                (Choice::Olympus, false, "stays"),
+                (Choice::Atlantis, true, "becomes"),
+                (Choice::Olympus, true, "becomes"),
+            ]
+            .into_iter()
+            .map(|(choice, switched, verb)| {
+                Line::from(vec![
+                    Span::styled("  ", Style::new().bg(cell_color(choice, switched))),
+                    Span::raw(format!(" {verb} {}", game_options.choice_name(choice))),
+                ])
+            }),
+        );
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(titled(" Spatial game "))
+                .wrap(Wrap { trim: false }),
+            rect,
+        );
+    }
+}
+
+fn titled<'a>(title: impl Into<Line<'a>>) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::{
+        screens::settings::tests::settings,
+        ui::tests::{
+            render,
+            AREA,
+        },
+    };
+
+    #[test]
+    fn test_play_and_step() {
+        let mut screen = LatticeScreen::new(&settings());
+
+        screen.tick();
+        assert_eq!(screen.lattice.generation(), 0);
+
+        screen.on_key(KeyCode::Char(' '));
+        screen.on_key(KeyCode::Char('+'));
+        screen.on_key(KeyCode::Char('+'));
+        assert_eq!(SPEEDS[screen.speed], 1);
+
+        for _ in 0..3 {
+            screen.tick();
+        }
+        assert_eq!(screen.lattice.generation(), 3);
+
+        screen.on_key(KeyCode::Char('s'));
+        assert!(!screen.playing);
+        assert_eq!(screen.lattice.generation(), 4);
+
+        screen.on_key(KeyCode::Char('c'));
+        assert_eq!(screen.lattice, centred_defector());
+    }
+
+    #[test]
+    fn test_click_flips_cell() {
+        let mut screen = LatticeScreen::new(&settings());
+        let [lattice, _] = LatticeScreen::areas(AREA);
+
+        let click = MouseEvent {
+            kind:      MouseEventKind::Down(MouseButton::Left),
+            column:    lattice.x + 1 + 3 * CELL_WIDTH + 1,
+            row:       lattice.y + 1 + 2,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        screen.on_mouse(click, AREA);
+        assert_eq!(screen.lattice.get(3, 2), Choice::Olympus);
+
+        screen.on_mouse(click, AREA);
+        assert_eq!(screen.lattice.get(3, 2), Choice::Atlantis);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut screen = LatticeScreen::new(&settings());
+
+        screen.on_key(KeyCode::Right);
+        let text = render(|frame, area| screen.render(frame, area));
+
+        assert!(text.contains("Generation: 1"));
+        assert!(text.contains("Cooperation: 99%"));
+        assert!(text.contains("Speed: 4 steps/s"));
+        assert!(text.contains("becomes defect"));
+    }
+}
@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    GameOptions,
+    GameParams,
+    GamePresets,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+    },
+};
+
+use super::{
+    Action,
+    GameScreen,
+    Screen,
+    Settings,
+};
+use crate::ui::{
+    list_areas,
+    row_at,
+};
+
+/// The width of the list of games.
+const WIDTH: u16 = 60;
+
+/// A game that can be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
+    /// One of the built-in presets.
+    Preset(&'static str),
+    /// A JSON file holding the same game parameters as `tactix-server` and
+    /// `tactix-bench` accept.
+    File(PathBuf),
+}
+
+impl Entry {
+    /// The name the game is shown and remembered by.
+    pub fn name(&self) -> String {
+        match self {
+            Self::Preset(name) => (*name).to_string(),
+            Self::File(path) => path.file_name().map_or_else(
+                || path.display().to_string(),
+                |name| name.to_string_lossy().to_string(),
+            ),
+        }
+    }
+
+    /// Reads the game.
+    pub fn load(&self) -> Result<GameOptions, String> {
+        match self {
+            Self::Preset(name) => {
+                GamePresets::get_preset(name).ok_or_else(|| format!("Unknown preset {name}"))
+            }
+            Self::File(path) => {
+                let text = fs::read_to_string(path)
+                    .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+
+                let params: GameParams = serde_json::from_str(&text)
+                    .map_err(|e| format!("Invalid game in {}: {e}", path.display()))?;
+
+                params
+                    .build()
+                    .map_err(|e| format!("Invalid game in {}: {e}", path.display()))
+            }
+        }
+    }
+}
+
+/// The screen for picking a preset or a game file to play.
+#[derive(Debug)]
+pub struct LoadScreen {
+    pub entries:  Vec<Entry>,
+    /// The index of the highlighted entry.
+    pub selected: usize,
+    /// Why the last game could not be loaded, or the games directory read.
+    pub error:    Option<String>,
+}
+
+impl LoadScreen {
+    /// Lists the presets followed by the `.json` files in `games_dir`.
+    pub fn new(games_dir: &Path) -> Self {
+        let mut entries: Vec<Entry> = GamePresets::preset_names()
+            .into_iter()
+            .map(Entry::Preset)
+            .collect();
+
+        let mut error = None;
+
+        match fs::read_dir(games_dir) {
+            Ok(dir) => {
+                let mut files: Vec<PathBuf> = dir
+                    .filter_map(|entry| Some(entry.ok()?.path()))
+                    .filter(|path| {
+                        path.extension()
+                            .is_some_and(|extension| extension == "json")
+                    })
+                    .collect();
+
+                files.sort();
+                entries.extend(files.into_iter().map(Entry::File));
+            }
+            Err(e) => {
+                let message = format!("Could not read {}: {e}", games_dir.display());
+
+                log::warn!("{message}");
+                error = Some(message);
+            }
+        }
+
+        Self {
+            entries,
+            selected: 0,
+            error,
+        }
+    }
+
+    /// Loads the highlighted game and starts playing it, or shows why it
+    /// could not be loaded.
+    fn activate(&mut self, settings: &mut Settings) -> Action {
+        let Some(entry) = self.entries.get(self.selected) else {
+            return Action::None;
+        };
+
+        match entry.load() {
+            Ok(game_options) => {
+                log::info!("Loaded {}", entry.name());
+                settings.game_name = entry.name();
+                settings.game_options = game_options;
+
+                Action::Replace(Screen::Game(GameScreen::from_settings(settings)))
+            }
+            Err(e) => {
+                log::warn!("{e}");
+                self.error = Some(e);
+
+                Action::None
+            }
+        }
+    }
+
+    /// The index of the first entry shown when `visible` rows fit.
+    const fn offset(&self, visible: usize) -> usize {
+        self.selected.saturating_sub(visible.saturating_sub(1))
+    }
+
+    pub fn on_key(&mut self, code: KeyCode, settings: &mut Settings) -> Action {
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => return self.activate(settings),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// Clicking an entry loads it, and scrolling moves the highlight.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect, settings: &mut Settings) -> Action {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let (_, rows) = list_areas(area, WIDTH, self.entries.len());
+
+                if let Some(row) = row_at(&rows, event.column, event.row) {
+                    self.selected = self.offset(rows.len()) + row;
+
+                    return self.activate(settings);
+                }
+            }
+            MouseEventKind::ScrollUp => return self.on_key(KeyCode::Up, settings),
+            MouseEventKind::ScrollDown => return self.on_key(KeyCode::Down, settings),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let (outer, rows) = list_areas(area, WIDTH, self.entries.len());
+        let offset = self.offset(rows.len());
+
+        frame.render_widget(
+            Block::default()
+                .title(" Load Game ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+            outer,
+        );
+
+        for (index, rect) in (offset..).zip(rows) {
+            let Some(entry) = self.entries.get(index) else {
+                break;
+            };
+
+            let style = if index == self.selected {
+                Style::new().black().on_yellow().bold()
+            } else {
+                Style::new()
+            };
+
+            let kind = match entry {
+                Entry::Preset(_) => "preset",
+                Entry::File(_) => "file",
+            };
+
+            frame.render_widget(
+                Paragraph::new(format!(" {kind:<7} {}", entry.name())).style(style),
+                rect,
+            );
+        }
+
+        if let Some(error) = &self.error {
+            let rect = Rect::new(area.x, outer.bottom() + 1, area.width, 1).intersection(area);
+
+            frame.render_widget(Paragraph::new(error.as_str()).red().centered(), rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screens::settings::tests::settings;
+
+    #[test]
+    fn test_load_files() {
+        let dir = std::env::temp_dir().join(format!("tactix-tui-load-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("a_share.json"),
+            r#"{"preset": "prisoners_dilemma", "choice_atlantis": "share"}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("b_broken.json"), "{").unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let mut settings = settings();
+        let mut screen = LoadScreen::new(&dir);
+        let presets = GamePresets::presets_length();
+
+        assert_eq!(screen.entries.len(), presets + 2);
+        assert!(screen.error.is_none());
+
+        screen.selected = presets + 1;
+        assert!(matches!(
+            screen.on_key(KeyCode::Enter, &mut settings),
+            Action::None
+        ));
+        assert!(screen.error.as_ref().unwrap().contains("b_broken.json"));
+
+        screen.on_key(KeyCode::Up, &mut settings);
+        assert!(matches!(
+            screen.on_key(KeyCode::Enter, &mut settings),
+            Action::Replace(Screen::Game(_))
+        ));
+        assert_eq!(settings.game_name, "a_share.json");
+        assert_eq!(settings.game_options.choice_atlantis(), "share");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_directory() {
+        let screen = LoadScreen::new(Path::new("/nonexistent/tactix"));
+
+        assert_eq!(screen.entries.len(), GamePresets::presets_length());
+        assert!(screen.error.is_some());
+    }
+}
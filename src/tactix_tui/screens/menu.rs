@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+    },
+};
+
+use super::{
+    Action,
+    EditorScreen,
+    GameScreen,
+    LatticeScreen,
+    LoadScreen,
+    PopulationScreen,
+    Screen,
+    Settings,
+    SettingsScreen,
+    TournamentScreen,
+};
+use crate::ui::{
+    list_areas,
+    row_at,
+};
+
+/// The width of the menu box.
+const WIDTH: u16 = 40;
+
+/// An entry of the main menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItem {
+    NewGame,
+    LoadGame,
+    EditGame,
+    RunTournament,
+    Population,
+    SpatialGame,
+    Settings,
+    Quit,
+}
+
+impl MenuItem {
+    /// Every entry, in the order they are listed.
+    pub const ALL: [Self; 8] = [
+        Self::NewGame,
+        Self::LoadGame,
+        Self::EditGame,
+        Self::RunTournament,
+        Self::Population,
+        Self::SpatialGame,
+        Self::Settings,
+        Self::Quit,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::NewGame => "New Game",
+            Self::LoadGame => "Load Game",
+            Self::EditGame => "Edit Game",
+            Self::RunTournament => "Run Tournament",
+            Self::Population => "Population Dynamics",
+            Self::SpatialGame => "Spatial Game",
+            Self::Settings => "Settings",
+            Self::Quit => "Quit",
+        }
+    }
+
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::NewGame => "Play a match against the opponent chosen in the settings.",
+            Self::LoadGame => "Pick a preset or a saved game file, then play it.",
+            Self::EditGame => "Change the payoffs and choice names, then play the game.",
+            Self::RunTournament => "Pick strategies, then watch them play a round robin.",
+            Self::Population => "Watch strategies spread and die out over generations.",
+            Self::SpatialGame => "Watch cooperation and defection spread across a grid.",
+            Self::Settings => "Choose the opponent, the number of rounds and the game.",
+            Self::Quit => "Leave Tactix.",
+        }
+    }
+}
+
+/// The main menu.
+#[derive(Debug, Default)]
+pub struct MenuScreen {
+    /// The index of the highlighted entry.
+    pub selected: usize,
+}
+
+impl MenuScreen {
+    /// Opens the highlighted entry.
+    fn activate(&self, settings: &Settings) -> Action {
+        match MenuItem::ALL[self.selected] {
+            MenuItem::NewGame => Action::Push(Screen::Game(GameScreen::from_settings(settings))),
+            MenuItem::LoadGame => Action::Push(Screen::Load(LoadScreen::new(&settings.games_dir))),
+            MenuItem::EditGame => Action::Push(Screen::Editor(EditorScreen::new(settings))),
+            MenuItem::RunTournament => {
+                Action::Push(Screen::Tournament(TournamentScreen::new(settings)))
+            }
+            MenuItem::Population => {
+                Action::Push(Screen::Population(PopulationScreen::new(settings)))
+            }
+            MenuItem::SpatialGame => Action::Push(Screen::Lattice(LatticeScreen::new(settings))),
+            MenuItem::Settings => Action::Push(Screen::Settings(SettingsScreen::default())),
+            MenuItem::Quit => Action::Quit,
+        }
+    }
+
+    fn move_selection(&mut self, up: bool) {
+        let count = MenuItem::ALL.len();
+
+        self.selected = if up {
+            (self.selected + count - 1) % count
+        } else {
+            (self.selected + 1) % count
+        };
+    }
+
+    pub fn on_key(&mut self, code: KeyCode, settings: &Settings) -> Action {
+        match code {
+            KeyCode::Up => self.move_selection(true),
+            KeyCode::Down | KeyCode::Tab => self.move_selection(false),
+            KeyCode::Enter | KeyCode::Char(' ') => return self.activate(settings),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// Clicking an entry opens it, and scrolling moves the highlight.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect, settings: &Settings) -> Action {
+        let (_, rows) = list_areas(area, WIDTH, MenuItem::ALL.len());
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(index) = row_at(&rows, event.column, event.row) {
+                    self.selected = index;
+
+                    return self.activate(settings);
+                }
+            }
+            MouseEventKind::ScrollUp => self.move_selection(true),
+            MouseEventKind::ScrollDown => self.move_selection(false),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let (outer, rows) = list_areas(area, WIDTH, MenuItem::ALL.len());
+
+        frame.render_widget(
+            Block::default()
+                .title(" Dilemma Tactix ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+            outer,
+        );
+
+        for (index, (item, rect)) in MenuItem::ALL.iter().zip(rows).enumerate() {
+            let style = if index == self.selected {
+                Style::new().black().on_yellow().bold()
+            } else {
+                Style::new()
+            };
+
+            frame.render_widget(Paragraph::new(item.label()).style(style).centered(), rect);
+        }
+
+        let description = Rect::new(area.x, outer.bottom() + 1, area.width, 1).intersection(area);
+
+        frame.render_widget(
+            Paragraph::new(MenuItem::ALL[self.selected].description())
+                .italic()
+                .centered(),
+            description,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::{
+        screens::settings::tests::settings,
+        ui::tests::AREA,
+    };
+
+    #[test]
+    fn test_keys_wrap_around() {
+        let settings = settings();
+        let mut menu = MenuScreen::default();
+
+        menu.on_key(KeyCode::Up, &settings);
+        assert_eq!(MenuItem::ALL[menu.selected], MenuItem::Quit);
+
+        menu.on_key(KeyCode::Down, &settings);
+        assert_eq!(MenuItem::ALL[menu.selected], MenuItem::NewGame);
+
+        assert!(matches!(
+            menu.on_key(KeyCode::Enter, &settings),
+            Action::Push(Screen::Game(_))
+        ));
+    }
+
+    #[test]
+    fn test_click_opens_item() {
+        let settings = settings();
+        let mut menu = MenuScreen::default();
+        let (_, rows) = list_areas(AREA, WIDTH, MenuItem::ALL.len());
+
+        let click = MouseEvent {
+            kind:      MouseEventKind::Down(MouseButton::Left),
+            column:    rows[6].x + 1,
+            row:       rows[6].y,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        assert!(matches!(
+            menu.on_mouse(click, AREA, &settings),
+            Action::Push(Screen::Settings(_))
+        ));
+        assert_eq!(MenuItem::ALL[menu.selected], MenuItem::Settings);
+    }
+}
@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+mod editor;
+mod game;
+mod lattice;
+mod load;
+mod menu;
+mod population;
+mod settings;
+mod tournament;
+
+use crossterm::event::{
+    KeyCode,
+    MouseEvent,
+};
+pub use editor::EditorScreen;
+pub use game::GameScreen;
+pub use lattice::LatticeScreen;
+pub use load::LoadScreen;
+pub use menu::MenuScreen;
+pub use population::PopulationScreen;
+use ratatui::{
+    layout::Rect,
+    Frame,
+};
+pub use settings::{
+    Settings,
+    SettingsScreen,
+};
+pub use tournament::TournamentScreen;
+
+use crate::events::{
+    Message,
+    Messenger,
+};
+
+/// What the app should do after a screen has handled an event.
+pub enum Action {
+    /// Stay on the current screen.
+    None,
+    /// Open a screen on top of the current one.
+    Push(Screen),
+    /// Swap the current screen for another, so that going back skips it.
+    Replace(Screen),
+    /// Return to the previous screen.
+    Back,
+    /// Leave the app.
+    Quit,
+}
+
+/// One of the screens of the app, holding its own state.
+pub enum Screen {
+    Menu(MenuScreen),
+    Game(GameScreen),
+    Editor(EditorScreen),
+    Load(LoadScreen),
+    Settings(SettingsScreen),
+    Tournament(TournamentScreen),
+    Population(PopulationScreen),
+    Lattice(LatticeScreen),
+}
+
+impl Screen {
+    /// The name shown in the header.
+    pub const fn title(&self) -> &'static str {
+        match self {
+            Self::Menu(_) => "Main Menu",
+            Self::Game(_) => "Game",
+            Self::Editor(_) => "Edit Game",
+            Self::Load(_) => "Load Game",
+            Self::Settings(_) => "Settings",
+            Self::Tournament(_) => "Tournament",
+            Self::Population(_) => "Population Dynamics",
+            Self::Lattice(_) => "Spatial Game",
+        }
+    }
+
+    /// The keys shown in the footer.
+    pub const fn hints(&self) -> &'static str {
+        match self {
+            Self::Menu(_) => "Up/Down: select | Enter: open | Q: quit",
+            Self::Game(_) => {
+                "Up/Down/Click: select | Enter: play | A/B: play a choice | Scroll: history | N: \
+                 new match | Esc: back | Q: quit"
+            }
+            Self::Editor(editor) if editor.is_typing() => {
+                "Type a value | Enter: set | Backspace: erase | Esc: cancel"
+            }
+            Self::Editor(_) => {
+                "Arrows: move | Enter: edit | S: save and play | Esc: back | Q: quit"
+            }
+            Self::Load(_) => "Up/Down: select | Enter: load and play | Esc: back | Q: quit",
+            Self::Settings(_) => "Up/Down: select | Left/Right: change | Esc: back | Q: quit",
+            Self::Tournament(tournament) if tournament.is_finished() => {
+                "Enter: play again | N: new tournament | Esc: back | Q: quit"
+            }
+            Self::Tournament(tournament) if tournament.is_running() => "Esc: back | Q: quit",
+            Self::Tournament(_) => {
+                "Up/Down: select | Space: toggle | A: toggle all | Left/Right: rounds | Enter: \
+                 start | Esc: back | Q: quit"
+            }
+            Self::Population(_) => {
+                "Up/Down: select | Space: toggle | Left/Right: generations | Esc: back | Q: quit"
+            }
+            Self::Lattice(_) => {
+                "Space: play/pause | S: step | +/-: speed | R: random | C: one defector | Click: \
+                 flip | Esc: back | Q: quit"
+            }
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, settings: &Settings) {
+        match self {
+            Self::Menu(menu) => menu.render(frame, area),
+            Self::Game(game) => game.render(frame, area),
+            Self::Editor(editor) => editor.render(frame, area, settings),
+            Self::Load(load) => load.render(frame, area),
+            Self::Settings(screen) => screen.render(frame, area, settings),
+            Self::Tournament(tournament) => tournament.render(frame, area, settings),
+            Self::Population(population) => population.render(frame, area, settings),
+            Self::Lattice(lattice) => lattice.render(frame, area),
+        }
+    }
+
+    /// Whether the screen is taking text input, so that it should get every
+    /// key, including those the app handles otherwise.
+    pub const fn is_typing(&self) -> bool {
+        matches!(self, Self::Editor(editor) if editor.is_typing())
+    }
+
+    /// Moves the animation of the screen along.
+    pub fn tick(&mut self) {
+        if let Self::Lattice(lattice) = self {
+            lattice.tick();
+        }
+    }
+
+    /// Takes in a message from a task running in the background.
+    pub fn on_message(&mut self, message: &Message) {
+        if let Self::Tournament(tournament) = self {
+            tournament.on_message(message);
+        }
+    }
+
+    /// Handles a key press that is not handled by the app as a whole.
+    pub fn on_key(
+        &mut self,
+        code: KeyCode,
+        settings: &mut Settings,
+        messenger: &Messenger,
+    ) -> Action {
+        match self {
+            Self::Menu(menu) => menu.on_key(code, settings),
+            Self::Game(game) => game.on_key(code),
+            Self::Editor(editor) => editor.on_key(code, settings),
+            Self::Load(load) => load.on_key(code, settings),
+            Self::Settings(screen) => screen.on_key(code, settings),
+            Self::Tournament(tournament) => tournament.on_key(code, settings, messenger),
+            Self::Population(population) => population.on_key(code, settings),
+            Self::Lattice(lattice) => lattice.on_key(code),
+        }
+    }
+
+    /// Handles a mouse event inside `area`, the body of the screen.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect, settings: &mut Settings) -> Action {
+        match self {
+            Self::Menu(menu) => menu.on_mouse(event, area, settings),
+            Self::Game(game) => game.on_mouse(event, area),
+            Self::Editor(editor) => editor.on_mouse(event, area),
+            Self::Load(load) => load.on_mouse(event, area, settings),
+            Self::Settings(screen) => screen.on_mouse(event, area, settings),
+            Self::Tournament(tournament) => tournament.on_mouse(event, area),
+            Self::Population(population) => population.on_mouse(event, area, settings),
+            Self::Lattice(lattice) => lattice.on_mouse(event, area),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    pub use super::settings::tests::settings;
+}
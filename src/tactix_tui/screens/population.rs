@@ -0,0 +1,549 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    GameGrid,
+    Population,
+    StrategyKind,
+};
+use ratatui::{
+    prelude::*,
+    symbols::Marker,
+    widgets::{
+        canvas::{
+            Canvas,
+            Line as CanvasLine,
+        },
+        Axis,
+        Block,
+        BorderType,
+        Borders,
+        Chart,
+        Dataset,
+        GraphType,
+        Paragraph,
+        Wrap,
+    },
+};
+
+use super::{
+    Action,
+    Settings,
+};
+use crate::ui::{
+    list_areas,
+    row_at,
+};
+
+/// The width of the controls.
+const WIDTH: u16 = 34;
+
+/// The most generations the population can be evolved for.
+const MAX_GENERATIONS: usize = 1000;
+
+/// The color each of [`StrategyKind::ALL`] is drawn in.
+const COLORS: [Color; 7] = [
+    Color::Green,
+    Color::Red,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Blue,
+    Color::Yellow,
+    Color::LightRed,
+];
+
+/// The starting points of the trajectories in the simplex, as multiples of
+/// a sixth of the population.
+const STARTS: usize = 6;
+
+/// The corners of the simplex, for the first, second and third strategy.
+const CORNERS: [(f64, f64); 3] = [(0.0, 0.0), (1.0, 0.0), (0.5, 0.866)];
+
+/// The point of the simplex for the shares of three strategies.
+fn simplex_point(shares: &[f64]) -> (f64, f64) {
+    CORNERS
+        .iter()
+        .zip(shares)
+        .fold((0.0, 0.0), |(x, y), ((corner_x, corner_y), share)| {
+            (x + corner_x * share, y + corner_y * share)
+        })
+}
+
+/// Every mix of three strategies in sixths in which each has a share.
+#[allow(clippy::cast_precision_loss)]
+fn starts() -> Vec<Vec<f64>> {
+    let step = 1.0 / STARTS as f64;
+
+    (1..STARTS)
+        .flat_map(|first| {
+            (1..STARTS - first).map(move |second| {
+                let third = STARTS - first - second;
+
+                vec![
+                    first as f64 * step,
+                    second as f64 * step,
+                    third as f64 * step,
+                ]
+            })
+        })
+        .collect()
+}
+
+/// The population view: evolving a mix of strategies by the replicator
+/// dynamics of the game chosen in the settings, and plotting how their
+/// shares change.
+#[derive(Debug)]
+pub struct PopulationScreen {
+    /// Whether each of [`StrategyKind::ALL`] is part of the population.
+    pub entered:      Vec<bool>,
+    /// The number of generations to evolve for.
+    pub generations:  usize,
+    /// The highlighted row of the controls, where the row after the
+    /// strategies holds the number of generations.
+    pub selected:     usize,
+    /// The payoffs of the strategies entered, if there are at least two.
+    pub population:   Option<Population>,
+    /// The indices into [`StrategyKind::ALL`] of the strategies entered.
+    pub kinds:        Vec<usize>,
+    /// The shares of each generation, starting from an even mix.
+    pub shares:       Vec<Vec<f64>>,
+    /// The shares of each generation from several other starting mixes, when
+    /// there are three strategies.
+    pub trajectories: Vec<Vec<Vec<f64>>>,
+}
+
+impl PopulationScreen {
+    /// Starts with always cooperate, always defect and tit for tat, which
+    /// show cooperation both collapse and recover.
+    pub fn new(settings: &Settings) -> Self {
+        let mut screen = Self {
+            entered:      StrategyKind::ALL
+                .iter()
+                .map(|kind| {
+                    matches!(
+                        kind,
+                        StrategyKind::AlwaysCooperate
+                            | StrategyKind::AlwaysDefect
+                            | StrategyKind::TitForTat
+                    )
+                })
+                .collect(),
+            generations:  100,
+            selected:     0,
+            population:   None,
+            kinds:        Vec::new(),
+            shares:       Vec::new(),
+            trajectories: Vec::new(),
+        };
+
+        screen.update(settings);
+
+        screen
+    }
+
+    /// Plays the strategies entered against each other again after they
+    /// have changed, then evolves the population.
+    fn update(&mut self, settings: &Settings) {
+        self.kinds = (0..StrategyKind::ALL.len())
+            .filter(|&index| self.entered[index])
+            .collect();
+
+        self.population = (self.kinds.len() >= 2).then(|| {
+            let kinds: Vec<StrategyKind> = self
+                .kinds
+                .iter()
+                .map(|&index| StrategyKind::ALL[index])
+                .collect();
+
+            Population::new(
+                &GameGrid::new(settings.game_options),
+                &kinds,
+                settings.rounds,
+                settings.seed,
+            )
+        });
+
+        self.evolve();
+    }
+
+    /// Evolves the population for the number of generations, keeping the
+    /// payoffs, which only change with the strategies.
+    #[allow(clippy::cast_precision_loss)]
+    fn evolve(&mut self) {
+        self.shares.clear();
+        self.trajectories.clear();
+
+        let Some(population) = &self.population else {
+            return;
+        };
+
+        let count = self.kinds.len();
+        let even = vec![1.0 / count as f64; count];
+
+        self.shares = population.evolve(&even, self.generations);
+
+        if count == 3 {
+            self.trajectories = starts()
+                .iter()
+                .map(|start| population.evolve(start, self.generations))
+                .collect();
+        }
+    }
+
+    fn toggle(&mut self, index: usize, settings: &Settings) {
+        if let Some(entered) = self.entered.get_mut(index) {
+            *entered = !*entered;
+
+            self.update(settings);
+        }
+    }
+
+    fn change_generations(&mut self, steps: isize) {
+        self.generations = self
+            .generations
+            .saturating_add_signed(steps)
+            .clamp(1, MAX_GENERATIONS);
+
+        self.evolve();
+    }
+
+    /// Handles a key press.
+    ///
+    /// `Space` adds or removes the highlighted strategy and the left and
+    /// right arrows change the number of generations, each evolving the
+    /// population again.
+    pub fn on_key(&mut self, code: KeyCode, settings: &Settings) -> Action {
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected = (self.selected + 1).min(StrategyKind::ALL.len());
+            }
+            KeyCode::Char(' ') => self.toggle(self.selected, settings),
+            KeyCode::Left | KeyCode::Char('-') => self.change_generations(-10),
+            KeyCode::Right | KeyCode::Char('+') => self.change_generations(10),
+            KeyCode::PageDown => self.change_generations(-100),
+            KeyCode::PageUp => self.change_generations(100),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// The areas of the controls and of the plots.
+    fn areas(area: Rect) -> [Rect; 2] {
+        Layout::horizontal([Constraint::Length(WIDTH), Constraint::Fill(1)]).areas(area)
+    }
+
+    /// Clicking a strategy adds or removes it, and scrolling over the number
+    /// of generations changes it.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect, settings: &Settings) -> Action {
+        let [controls, _] = Self::areas(area);
+        let (_, rows) = list_areas(controls, WIDTH, StrategyKind::ALL.len() + 1);
+
+        let Some(index) = row_at(&rows, event.column, event.row) else {
+            return Action::None;
+        };
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.selected = index;
+                self.toggle(index, settings);
+            }
+            MouseEventKind::ScrollUp if index == StrategyKind::ALL.len() => {
+                self.change_generations(10);
+            }
+            MouseEventKind::ScrollDown if index == StrategyKind::ALL.len() => {
+                self.change_generations(-10);
+            }
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, settings: &Settings) {
+        let [controls, plots] = Self::areas(area);
+
+        self.render_controls(frame, controls, settings);
+
+        if self.population.is_none() {
+            frame.render_widget(
+                Paragraph::new("Pick at least two strategies to evolve")
+                    .red()
+                    .centered()
+                    .block(titled(" Shares ")),
+                plots,
+            );
+
+            return;
+        }
+
+        if self.kinds.len() == 3 {
+            let [shares, simplex] =
+                Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+                    .areas(plots);
+
+            self.render_shares(frame, shares);
+            self.render_simplex(frame, simplex);
+        } else {
+            self.render_shares(frame, plots);
+        }
+    }
+
+    fn render_controls(&self, frame: &mut Frame, rect: Rect, settings: &Settings) {
+        let (outer, rows) = list_areas(rect, WIDTH, StrategyKind::ALL.len() + 1);
+
+        frame.render_widget(titled(format!(" {} ", settings.game_name)), outer);
+
+        let lines = StrategyKind::ALL
+            .iter()
+            .zip(&self.entered)
+            .zip(COLORS)
+            .map(|((kind, &entered), color)| {
+                Line::from(vec![
+                    Span::raw(format!(
+                        " [{}] ",
+                        if entered {
+                            "x"
+                        } else {
+                            " "
+                        }
+                    )),
+                    Span::styled(kind.name(), color),
+                ])
+            })
+            .chain(std::iter::once(Line::from(format!(
+                " Generations < {} >",
+                self.generations
+            ))));
+
+        for (index, (line, rect)) in lines.zip(rows).enumerate() {
+            let style = if index == self.selected {
+                Style::new().black().on_yellow().bold()
+            } else {
+                Style::new()
+            };
+
+            frame.render_widget(Paragraph::new(line).style(style), rect);
+        }
+
+        let note = Rect::new(rect.x, outer.bottom() + 1, rect.width, 3).intersection(rect);
+
+        frame.render_widget(
+            Paragraph::new(format!(
+                "Payoffs are averaged over matches of {} rounds.",
+                settings.rounds
+            ))
+            .italic()
+            .centered()
+            .wrap(Wrap { trim: true }),
+            note,
+        );
+    }
+
+    /// Plots the share of each strategy over the generations, starting from
+    /// an even mix.
+    #[allow(clippy::cast_precision_loss)]
+    fn render_shares(&self, frame: &mut Frame, rect: Rect) {
+        let points: Vec<Vec<(f64, f64)>> = (0..self.kinds.len())
+            .map(|strategy| {
+                self.shares
+                    .iter()
+                    .enumerate()
+                    .map(|(generation, shares)| (generation as f64, shares[strategy]))
+                    .collect()
+            })
+            .collect();
+
+        let datasets = self
+            .kinds
+            .iter()
+            .zip(&points)
+            .map(|(&kind, points)| {
+                Dataset::default()
+                    .name(StrategyKind::ALL[kind].name())
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(COLORS[kind])
+                    .data(points)
+            })
+            .collect();
+
+        let generations = self.generations as f64;
+
+        let chart = Chart::new(datasets)
+            .block(titled(" Shares over generations "))
+            .x_axis(
+                Axis::default()
+                    .title("Generation")
+                    .bounds([0.0, generations])
+                    .labels([
+                        "0".to_string(),
+                        (self.generations / 2).to_string(),
+                        self.generations.to_string(),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 1.0])
+                    .labels(["0%", "50%", "100%"]),
+            );
+
+        frame.render_widget(chart, rect);
+    }
+
+    /// Draws the mixes of the three strategies as points of a triangle,
+    /// with the path each starting mix takes over the generations.
+    fn render_simplex(&self, frame: &mut Frame, rect: Rect) {
+        let block = titled(" Simplex ");
+        let width = f64::from(block.inner(rect).width.max(1));
+
+        // The canvas spans 1.2 units across, and names are placed by the
+        // cell.
+        let cell = 1.2 / width;
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(Marker::Braille)
+            .x_bounds([-0.1, 1.1])
+            .y_bounds([-0.1, 1.0])
+            .paint(|ctx| {
+                for (index, &(x1, y1)) in CORNERS.iter().enumerate() {
+                    let (x2, y2) = CORNERS[(index + 1) % CORNERS.len()];
+
+                    ctx.draw(&CanvasLine::new(x1, y1, x2, y2, Color::White));
+                }
+
+                for (trajectory, color) in self
+                    .trajectories
+                    .iter()
+                    .map(|trajectory| (trajectory, Color::DarkGray))
+                    .chain(std::iter::once((&self.shares, Color::Yellow)))
+                {
+                    for pair in trajectory.windows(2) {
+                        let (x1, y1) = simplex_point(&pair[0]);
+                        let (x2, y2) = simplex_point(&pair[1]);
+
+                        ctx.draw(&CanvasLine::new(x1, y1, x2, y2, color));
+                    }
+                }
+
+                ctx.layer();
+
+                for (&kind, &(x, y)) in self.kinds.iter().zip(&CORNERS) {
+                    let name = StrategyKind::ALL[kind].name();
+                    let length = f64::from(u16::try_from(name.len()).unwrap_or(u16::MAX));
+
+                    // Keep the names inside the canvas: the first to the right
+                    // of its corner, the second to the left and the third
+                    // centered above.
+                    let (x, y) = match x {
+                        x if x <= 0.0 => (-0.1, y - 0.08),
+                        x if x >= 1.0 => (1.1 - length * cell, y - 0.08),
+                        x => (x - length * cell / 2.0, y + 0.08),
+                    };
+
+                    ctx.print(x, y, Line::styled(name, COLORS[kind]));
+                }
+            });
+
+        frame.render_widget(canvas, rect);
+    }
+}
+
+fn titled<'a>(title: impl Into<Line<'a>>) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        screens::settings::tests::settings,
+        ui::tests::render,
+    };
+
+    #[test]
+    fn test_starts() {
+        let starts = starts();
+
+        assert_eq!(starts.len(), 10);
+        assert!(starts
+            .iter()
+            .all(|start| (start.iter().sum::<f64>() - 1.0).abs() < 1e-9
+                && start.iter().all(|&share| share > 0.0)));
+        assert_eq!(simplex_point(&[0.0, 0.0, 1.0]), CORNERS[2]);
+    }
+
+    #[test]
+    fn test_evolve() {
+        let settings = settings();
+        let mut screen = PopulationScreen::new(&settings);
+
+        assert_eq!(screen.kinds, [0, 1, 3]);
+        assert_eq!(screen.shares.len(), 101);
+        assert_eq!(screen.trajectories.len(), 10);
+
+        // Tit for tat keeps always defect out once always cooperate is gone.
+        let last = screen.shares.last().unwrap();
+        assert!(last[2] > last[1]);
+
+        screen.on_key(KeyCode::Right, &settings);
+        assert_eq!(screen.shares.len(), 111);
+
+        screen.on_key(KeyCode::Char(' '), &settings);
+        assert_eq!(screen.kinds, [1, 3]);
+        assert!(screen.trajectories.is_empty());
+
+        screen.on_key(KeyCode::Down, &settings);
+        screen.on_key(KeyCode::Char(' '), &settings);
+        assert!(screen.population.is_none());
+        assert!(render(|frame, area| screen.render(frame, area, &settings))
+            .contains("Pick at least two strategies"));
+    }
+
+    #[test]
+    fn test_generations_keep_payoffs() {
+        let settings = settings();
+        let mut screen = PopulationScreen::new(&settings);
+
+        // Random play without a seed would score differently every time the
+        // matches were played again.
+        screen.entered = StrategyKind::ALL
+            .iter()
+            .map(|&kind| matches!(kind, StrategyKind::Random | StrategyKind::TitForTat))
+            .collect();
+        screen.update(&settings);
+
+        let population = screen.population.clone();
+
+        screen.on_key(KeyCode::Right, &settings);
+        screen.on_key(KeyCode::PageUp, &settings);
+
+        assert_eq!(screen.population, population);
+        assert_eq!(screen.shares.len(), 211);
+    }
+
+    #[test]
+    fn test_render() {
+        let settings = settings();
+        let screen = PopulationScreen::new(&settings);
+        let text = render(|frame, area| screen.render(frame, area, &settings));
+
+        assert!(text.contains("Shares over generations"));
+        assert!(text.contains("Simplex"));
+        assert!(text.contains("Generations < 100 >"));
+        assert!(text.contains("Payoffs are averaged"));
+    }
+}
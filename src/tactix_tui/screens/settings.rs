@@ -0,0 +1,276 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path::PathBuf;
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    GameOptions,
+    GamePresets,
+    StrategyKind,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block,
+        BorderType,
+        Borders,
+        Paragraph,
+    },
+};
+
+use super::Action;
+use crate::ui::{
+    list_areas,
+    row_at,
+};
+
+/// The width of the settings box.
+const WIDTH: u16 = 50;
+
+/// The choices that carry over between screens.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The strategy the user plays against.
+    pub opponent:     StrategyKind,
+    /// The number of rounds in a match.
+    pub rounds:       usize,
+    /// The seed of the random strategy, if it should play the same way
+    /// every time.
+    pub seed:         Option<u64>,
+    /// What the game is called, e.g. the name of its preset.
+    pub game_name:    String,
+    pub game_options: GameOptions,
+    /// Where the load screen looks for game files.
+    pub games_dir:    PathBuf,
+}
+
+impl Settings {
+    /// The most rounds a match can be set to.
+    pub const MAX_ROUNDS: usize = 1000;
+
+    /// Creates settings for playing `game_options` against tit-for-tat for
+    /// ten rounds.
+    pub fn new(game_name: impl Into<String>, game_options: GameOptions) -> Self {
+        Self {
+            opponent: StrategyKind::TitForTat,
+            rounds: 10,
+            seed: None,
+            game_name: game_name.into(),
+            game_options,
+            games_dir: PathBuf::from("."),
+        }
+    }
+}
+
+/// A setting that can be changed on the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Opponent,
+    Rounds,
+    Game,
+}
+
+impl Field {
+    const ALL: [Self; 3] = [Self::Opponent, Self::Rounds, Self::Game];
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Opponent => "Opponent",
+            Self::Rounds => "Rounds",
+            Self::Game => "Game",
+        }
+    }
+
+    fn value(self, settings: &Settings) -> String {
+        match self {
+            Self::Opponent => settings.opponent.name().to_string(),
+            Self::Rounds => settings.rounds.to_string(),
+            Self::Game => settings.game_name.clone(),
+        }
+    }
+
+    /// Moves the setting `steps` places forwards, or backwards if negative.
+    fn change(self, settings: &mut Settings, steps: isize) {
+        match self {
+            Self::Opponent => {
+                let position = StrategyKind::ALL
+                    .iter()
+                    .position(|&kind| kind == settings.opponent)
+                    .unwrap_or_default();
+
+                settings.opponent =
+                    StrategyKind::ALL[cycle(position, steps, StrategyKind::ALL.len())];
+            }
+            Self::Rounds => {
+                settings.rounds = settings
+                    .rounds
+                    .saturating_add_signed(steps)
+                    .clamp(1, Settings::MAX_ROUNDS);
+            }
+            Self::Game => {
+                let names = GamePresets::preset_names();
+
+                let position = names
+                    .iter()
+                    .position(|&name| name == settings.game_name)
+                    // A game that is not a preset moves to the first or last
+                    // preset.
+                    .unwrap_or(if steps > 0 { names.len() - 1 } else { 0 });
+
+                let name = names[cycle(position, steps, names.len())];
+
+                if let Some(game_options) = GamePresets::get_preset(name) {
+                    settings.game_name = name.to_string();
+                    settings.game_options = game_options;
+                }
+            }
+        }
+    }
+}
+
+/// Moves `steps` places from `position` in a list of `len` items, wrapping
+/// around at either end.
+fn cycle(position: usize, steps: isize, len: usize) -> usize {
+    let len = isize::try_from(len).unwrap_or(isize::MAX);
+    let position = isize::try_from(position).unwrap_or_default();
+
+    usize::try_from((position + steps).rem_euclid(len)).unwrap_or_default()
+}
+
+/// The settings screen.
+#[derive(Debug, Default)]
+pub struct SettingsScreen {
+    /// The index of the highlighted setting.
+    selected: usize,
+}
+
+impl SettingsScreen {
+    fn field(&self) -> Field {
+        Field::ALL[self.selected]
+    }
+
+    pub fn on_key(&mut self, code: KeyCode, settings: &mut Settings) -> Action {
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected = (self.selected + 1).min(Field::ALL.len() - 1);
+            }
+            KeyCode::Left | KeyCode::Char('-') => self.field().change(settings, -1),
+            KeyCode::Right | KeyCode::Char('+') => self.field().change(settings, 1),
+            KeyCode::PageDown => self.field().change(settings, -10),
+            KeyCode::PageUp => self.field().change(settings, 10),
+            KeyCode::Enter => return Action::Back,
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// Clicking a setting highlights it, and scrolling over it changes it.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect, settings: &mut Settings) -> Action {
+        let (_, rows) = list_areas(area, WIDTH, Field::ALL.len());
+
+        let Some(index) = row_at(&rows, event.column, event.row) else {
+            return Action::None;
+        };
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.selected = index,
+            MouseEventKind::ScrollUp => Field::ALL[index].change(settings, 1),
+            MouseEventKind::ScrollDown => Field::ALL[index].change(settings, -1),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, settings: &Settings) {
+        let (outer, rows) = list_areas(area, WIDTH, Field::ALL.len());
+
+        frame.render_widget(
+            Block::default()
+                .title(" Settings ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+            outer,
+        );
+
+        for (index, (field, rect)) in Field::ALL.iter().zip(rows).enumerate() {
+            let style = if index == self.selected {
+                Style::new().black().on_yellow().bold()
+            } else {
+                Style::new()
+            };
+
+            let line = format!(" {:<10} < {} >", field.label(), field.value(settings));
+
+            frame.render_widget(Paragraph::new(line).style(style), rect);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Settings for the prisoner's dilemma, as used throughout the tests.
+    pub fn settings() -> Settings {
+        Settings::new(
+            "prisoners_dilemma",
+            GamePresets::get_preset("prisoners_dilemma").unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case(0, 1, 3, 1)]
+    #[case(0, -1, 3, 2)]
+    #[case(2, 1, 3, 0)]
+    #[case(1, -10, 3, 0)]
+    fn test_cycle(
+        #[case] position: usize,
+        #[case] steps: isize,
+        #[case] len: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(cycle(position, steps, len), expected);
+    }
+
+    #[test]
+    fn test_change_settings() {
+        let mut settings = settings();
+        let mut screen = SettingsScreen::default();
+
+        screen.on_key(KeyCode::Right, &mut settings);
+        assert_eq!(settings.opponent, StrategyKind::TitForTwoTats);
+
+        screen.on_key(KeyCode::Down, &mut settings);
+        screen.on_key(KeyCode::PageDown, &mut settings);
+        assert_eq!(settings.rounds, 1);
+
+        screen.on_key(KeyCode::PageUp, &mut settings);
+        assert_eq!(settings.rounds, 11);
+
+        screen.on_key(KeyCode::Down, &mut settings);
+        screen.on_key(KeyCode::Right, &mut settings);
+        assert_eq!(settings.game_name, "chicken");
+        assert_eq!(
+            Some(settings.game_options),
+            GamePresets::get_preset("chicken")
+        );
+
+        assert!(matches!(
+            screen.on_key(KeyCode::Enter, &mut settings),
+            Action::Back
+        ));
+    }
+}
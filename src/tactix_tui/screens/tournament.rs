@@ -0,0 +1,798 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crossterm::event::{
+    KeyCode,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use dilemma_tactix_lib::{
+    Choice,
+    GameGrid,
+    GameOptions,
+    Pairing,
+    Player,
+    Standing,
+    StrategyKind,
+    Tournament,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Bar,
+        BarChart,
+        BarGroup,
+        Block,
+        BorderType,
+        Borders,
+        Cell,
+        Gauge,
+        Paragraph,
+        Row,
+        Table,
+    },
+};
+
+use super::{
+    Action,
+    Settings,
+};
+use crate::{
+    events::{
+        task_id,
+        Message,
+        Messenger,
+    },
+    ui::{
+        list_areas,
+        row_at,
+    },
+};
+
+/// The width of the setup box.
+const WIDTH: u16 = 50;
+
+/// The colors of the heatmap, from the lowest average score to the highest.
+const HEAT: [Color; 5] = [
+    Color::Red,
+    Color::LightRed,
+    Color::Yellow,
+    Color::LightGreen,
+    Color::Green,
+];
+
+/// A round-robin tournament played on a background thread, which reports
+/// each match to the event loop as it finishes.
+#[derive(Debug)]
+pub struct Run {
+    pub game_name:    String,
+    pub game_options: GameOptions,
+    /// The number of rounds in each match.
+    pub rounds:       usize,
+    /// The names of the entrants, in the order they were entered.
+    pub names:        Vec<String>,
+    /// The matches finished so far.
+    pub pairings:     Vec<Pairing>,
+    /// The standings over the matches finished so far, best first.
+    pub standings:    Vec<Standing>,
+    /// Whether every match has been played, or the tournament has failed.
+    pub finished:     bool,
+    /// Why the tournament stopped before every match was played, if it did.
+    pub failure:      Option<String>,
+    /// The id of the background task playing the tournament.
+    task:             u64,
+}
+
+impl Run {
+    /// Starts a tournament between `kinds` in the game chosen in the
+    /// settings.
+    pub fn start(
+        kinds: &[StrategyKind],
+        rounds: usize,
+        settings: &Settings,
+        messenger: &Messenger,
+    ) -> Self {
+        let mut entrants: Vec<_> = kinds
+            .iter()
+            .map(|kind| kind.strategy(settings.seed))
+            .collect();
+
+        let names: Vec<String> = entrants
+            .iter()
+            .map(|entrant| entrant.name().to_string())
+            .collect();
+
+        let game_grid = GameGrid::new(settings.game_options);
+        let task = task_id();
+
+        log::info!(
+            "Started a round robin of {} strategies with {rounds} rounds per match",
+            names.len()
+        );
+
+        messenger.spawn(task, move |messenger| {
+            Tournament::round_robin_observed(
+                &game_grid,
+                &mut entrants,
+                rounds,
+                &mut |aleph, beth, history| {
+                    if history.forfeit().is_some() || history.len() == rounds {
+                        // The app may have quit, in which case nobody is
+                        // listening any more.
+                        messenger.send(Message::Pairing {
+                            task,
+                            pairing: Pairing {
+                                aleph,
+                                beth,
+                                history: history.clone(),
+                            },
+                        });
+                    }
+                },
+            );
+        });
+
+        Self {
+            game_name: settings.game_name.clone(),
+            game_options: settings.game_options,
+            rounds,
+            standings: Tournament::from_pairings(names.clone(), Vec::new()).standings(),
+            names,
+            pairings: Vec::new(),
+            finished: false,
+            failure: None,
+            task,
+        }
+    }
+
+    /// The number of matches in the whole tournament.
+    pub fn matches(&self) -> usize {
+        self.names.len() * self.names.len().saturating_sub(1) / 2
+    }
+
+    /// Takes in a message from the background, ignoring those of other
+    /// tasks.
+    pub fn on_message(&mut self, message: &Message) {
+        match message {
+            Message::Pairing { task, pairing } if *task == self.task => {
+                self.pairings.push(pairing.clone());
+                self.standings =
+                    Tournament::from_pairings(self.names.clone(), self.pairings.clone())
+                        .standings();
+            }
+            Message::Finished { task } if *task == self.task => {
+                log::info!("Finished a round robin of {} matches", self.pairings.len());
+                self.finished = true;
+            }
+            Message::Failed { task, reason } if *task == self.task => {
+                self.finished = true;
+                self.failure = Some(reason.clone());
+            }
+            _ => {}
+        }
+    }
+
+    /// The total score of `player` against `opponent`, and the number of
+    /// rounds it was earned over, if they have played.
+    fn score_against(&self, player: usize, opponent: usize) -> Option<(u64, usize)> {
+        self.pairings.iter().find_map(|pairing| {
+            if (pairing.aleph, pairing.beth) == (player, opponent) {
+                Some((pairing.history.aleph_total(), pairing.history.len()))
+            } else if (pairing.aleph, pairing.beth) == (opponent, player) {
+                Some((pairing.history.beth_total(), pairing.history.len()))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The percentage of its moves in which the entrant cooperated, over
+    /// the matches finished so far.
+    fn cooperation(&self, entrant: usize) -> u64 {
+        let (cooperated, moves) = self
+            .pairings
+            .iter()
+            .filter_map(|pairing| {
+                if pairing.aleph == entrant {
+                    Some(pairing.history.choices(Player::Aleph))
+                } else if pairing.beth == entrant {
+                    Some(pairing.history.choices(Player::Beth))
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .fold((0, 0), |(cooperated, moves), &choice| {
+                (
+                    cooperated + u64::from(choice == Choice::Atlantis),
+                    moves + 1,
+                )
+            });
+
+        (cooperated * 100).checked_div(moves).unwrap_or_default()
+    }
+
+    /// The color of a heatmap cell, placing the average score between the
+    /// lowest and highest payoffs of the game.
+    fn heat(&self, total: u64, rounds: usize) -> Color {
+        let payoffs = [
+            self.game_options.atlantis_atlantis(),
+            self.game_options.atlantis_olympus(),
+            self.game_options.olympus_atlantis(),
+            self.game_options.olympus_olympus(),
+        ]
+        .into_iter()
+        .flat_map(|pair| [pair.first(), pair.second()]);
+
+        let lowest = u64::from(payoffs.clone().min().unwrap_or_default());
+        let highest = u64::from(payoffs.max().unwrap_or_default());
+        let rounds = u64::try_from(rounds).unwrap_or(u64::MAX);
+
+        let range = (highest - lowest) * rounds;
+
+        if range == 0 {
+            return HEAT[HEAT.len() / 2];
+        }
+
+        let position = total.saturating_sub(lowest * rounds) * HEAT.len() as u64 / range;
+
+        HEAT[usize::try_from(position)
+            .unwrap_or(usize::MAX)
+            .min(HEAT.len() - 1)]
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let height = u16::try_from(self.names.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+
+        let [progress, main, charts] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Fill(1),
+            Constraint::Length(height),
+        ])
+        .areas(area);
+
+        let [ranking, heatmap] =
+            Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+                .areas(main);
+
+        let [totals, cooperation] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(charts);
+
+        self.render_progress(frame, progress);
+        self.render_ranking(frame, ranking);
+        self.render_heatmap(frame, heatmap);
+        self.render_totals(frame, totals);
+        self.render_cooperation(frame, cooperation);
+    }
+
+    fn render_progress(&self, frame: &mut Frame, rect: Rect) {
+        let played = self.pairings.len();
+        let matches = self.matches().max(1);
+
+        let label = match (&self.failure, self.finished) {
+            (Some(reason), _) => format!("Stopped after {played} matches: {reason}"),
+            (None, true) => format!("All {played} matches played"),
+            (None, false) => format!("{played} of {matches} matches played"),
+        };
+
+        let color = if self.failure.is_some() {
+            Color::Red
+        } else {
+            Color::Green
+        };
+
+        let gauge = Gauge::default()
+            .block(titled(format!(
+                " Round robin of {} rounds in {} ",
+                self.rounds, self.game_name
+            )))
+            .gauge_style(Style::new().fg(color).on_black())
+            .percent(u16::try_from(played * 100 / matches).unwrap_or(100))
+            .label(label);
+
+        frame.render_widget(gauge, rect);
+    }
+
+    fn render_ranking(&self, frame: &mut Frame, rect: Rect) {
+        let header = Row::new(["#", "Strategy", "Total", "Wins", "Ties", "Losses"]).bold();
+
+        let rows = self.standings.iter().enumerate().map(|(rank, standing)| {
+            Row::new([
+                (rank + 1).to_string(),
+                standing.name.clone(),
+                standing.total.to_string(),
+                standing.wins.to_string(),
+                standing.ties.to_string(),
+                standing.losses.to_string(),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(7),
+                Constraint::Length(5),
+                Constraint::Length(5),
+                Constraint::Length(6),
+            ],
+        )
+        .header(header)
+        .block(titled(" Ranking "));
+
+        frame.render_widget(table, rect);
+    }
+
+    /// Shows the average score per round of each entrant, by row, against
+    /// each other entrant, by column.
+    fn render_heatmap(&self, frame: &mut Frame, rect: Rect) {
+        let count = self.names.len();
+
+        let header = Row::new(
+            std::iter::once(String::new()).chain((1..=count).map(|number| number.to_string())),
+        )
+        .bold();
+
+        let rows = self.names.iter().enumerate().map(|(player, name)| {
+            let cells = (0..count).map(|opponent| {
+                if player == opponent {
+                    return Cell::from("-").dark_gray();
+                }
+
+                match self.score_against(player, opponent) {
+                    Some((total, rounds)) => Cell::from(format!("{:.1}", average(total, rounds)))
+                        .black()
+                        .bg(self.heat(total, rounds)),
+                    None => Cell::from("·").dark_gray(),
+                }
+            });
+
+            Row::new(std::iter::once(Cell::from(format!("{} {name}", player + 1))).chain(cells))
+        });
+
+        let widths = std::iter::once(Constraint::Fill(1))
+            .chain(std::iter::repeat(Constraint::Length(5)).take(count));
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(titled(" Average score per round "));
+
+        frame.render_widget(table, rect);
+    }
+
+    fn render_totals(&self, frame: &mut Frame, rect: Rect) {
+        let bars: Vec<Bar> = self
+            .standings
+            .iter()
+            .map(|standing| {
+                Bar::default()
+                    .label(Line::from(standing.name.clone()))
+                    .value(standing.total)
+            })
+            .collect();
+
+        frame.render_widget(
+            bar_chart(" Total score ", &bars).bar_style(Color::Cyan),
+            rect,
+        );
+    }
+
+    fn render_cooperation(&self, frame: &mut Frame, rect: Rect) {
+        let bars: Vec<Bar> = self
+            .names
+            .iter()
+            .enumerate()
+            .map(|(entrant, name)| {
+                let cooperation = self.cooperation(entrant);
+
+                Bar::default()
+                    .label(Line::from(name.clone()))
+                    .value(cooperation)
+                    .text_value(format!("{cooperation}%"))
+            })
+            .collect();
+
+        frame.render_widget(
+            bar_chart(" Cooperation ", &bars)
+                .bar_style(Color::Green)
+                .max(100),
+            rect,
+        );
+    }
+}
+
+/// The average of `total` over `rounds`.
+#[allow(clippy::cast_precision_loss)]
+fn average(total: u64, rounds: usize) -> f64 {
+    if rounds == 0 {
+        0.0
+    } else {
+        total as f64 / rounds as f64
+    }
+}
+
+fn titled<'a>(title: impl Into<Line<'a>>) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+}
+
+/// A horizontal bar chart with one line per bar.
+fn bar_chart<'a>(title: &'a str, bars: &[Bar<'a>]) -> BarChart<'a> {
+    BarChart::default()
+        .block(titled(title))
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .data(BarGroup::default().bars(bars))
+}
+
+/// The tournament dashboard: picking the strategies and the length of the
+/// matches, then following the tournament as it is played.
+#[derive(Debug)]
+pub struct TournamentScreen {
+    /// Whether each of [`StrategyKind::ALL`] takes part.
+    pub entered:  Vec<bool>,
+    /// The number of rounds in each match.
+    pub rounds:   usize,
+    /// The highlighted row of the setup, where the row after the
+    /// strategies holds the number of rounds.
+    pub selected: usize,
+    /// Why the tournament could not be started.
+    pub error:    Option<String>,
+    /// The tournament being played or last played.
+    pub run:      Option<Run>,
+}
+
+impl TournamentScreen {
+    /// Sets up a tournament between every built-in strategy, with matches
+    /// as long as chosen in the settings.
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            entered:  vec![true; StrategyKind::ALL.len()],
+            rounds:   settings.rounds,
+            selected: 0,
+            error:    None,
+            run:      None,
+        }
+    }
+
+    /// Whether a tournament is being played or its results are shown.
+    pub const fn is_running(&self) -> bool {
+        self.run.is_some()
+    }
+
+    /// Whether the tournament being shown has finished playing.
+    pub const fn is_finished(&self) -> bool {
+        matches!(&self.run, Some(run) if run.finished)
+    }
+
+    /// The strategies taking part.
+    fn kinds(&self) -> Vec<StrategyKind> {
+        StrategyKind::ALL
+            .iter()
+            .zip(&self.entered)
+            .filter_map(|(&kind, &entered)| entered.then_some(kind))
+            .collect()
+    }
+
+    /// Starts the tournament, unless fewer than two strategies take part.
+    fn start(&mut self, settings: &Settings, messenger: &Messenger) {
+        let kinds = self.kinds();
+
+        if kinds.len() < 2 {
+            self.error = Some("Pick at least two strategies".to_string());
+
+            return;
+        }
+
+        self.error = None;
+        self.run = Some(Run::start(&kinds, self.rounds, settings, messenger));
+    }
+
+    fn toggle(&mut self, index: usize) {
+        if let Some(entered) = self.entered.get_mut(index) {
+            *entered = !*entered;
+        }
+    }
+
+    fn change_rounds(&mut self, steps: isize) {
+        self.rounds = self
+            .rounds
+            .saturating_add_signed(steps)
+            .clamp(1, Settings::MAX_ROUNDS);
+    }
+
+    /// Passes a message from the background on to the tournament.
+    pub fn on_message(&mut self, message: &Message) {
+        if let Some(run) = &mut self.run {
+            run.on_message(message);
+        }
+    }
+
+    /// Handles a key press.
+    ///
+    /// While setting up, `Space` toggles the highlighted strategy, `A`
+    /// toggles them all, the left and right arrows change the number of
+    /// rounds and `Enter` starts the tournament. Once it has finished,
+    /// `Enter` plays it again and `N` returns to the setup; until then both
+    /// are ignored, so that only one tournament is ever played at a time.
+    pub fn on_key(&mut self, code: KeyCode, settings: &Settings, messenger: &Messenger) -> Action {
+        if let Some(run) = &self.run {
+            match code {
+                KeyCode::Enter if run.finished => self.start(settings, messenger),
+                KeyCode::Char('n' | 'N') if run.finished => self.run = None,
+                _ => {}
+            }
+
+            return Action::None;
+        }
+
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected = (self.selected + 1).min(StrategyKind::ALL.len());
+            }
+            KeyCode::Char(' ') => self.toggle(self.selected),
+            KeyCode::Char('a' | 'A') => {
+                let entered = !self.entered.iter().all(|&entered| entered);
+
+                self.entered.fill(entered);
+            }
+            KeyCode::Left | KeyCode::Char('-') => self.change_rounds(-1),
+            KeyCode::Right | KeyCode::Char('+') => self.change_rounds(1),
+            KeyCode::PageDown => self.change_rounds(-10),
+            KeyCode::PageUp => self.change_rounds(10),
+            KeyCode::Enter => self.start(settings, messenger),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// While setting up, clicking a strategy toggles it and scrolling over
+    /// the number of rounds changes it.
+    pub fn on_mouse(&mut self, event: MouseEvent, area: Rect) -> Action {
+        if self.is_running() {
+            return Action::None;
+        }
+
+        let (_, rows) = list_areas(area, WIDTH, StrategyKind::ALL.len() + 1);
+
+        let Some(index) = row_at(&rows, event.column, event.row) else {
+            return Action::None;
+        };
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.selected = index;
+                self.toggle(index);
+            }
+            MouseEventKind::ScrollUp if index == StrategyKind::ALL.len() => {
+                self.change_rounds(1);
+            }
+            MouseEventKind::ScrollDown if index == StrategyKind::ALL.len() => {
+                self.change_rounds(-1);
+            }
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, settings: &Settings) {
+        match &self.run {
+            Some(run) => run.render(frame, area),
+            None => self.render_setup(frame, area, settings),
+        }
+    }
+
+    fn render_setup(&self, frame: &mut Frame, area: Rect, settings: &Settings) {
+        let (outer, rows) = list_areas(area, WIDTH, StrategyKind::ALL.len() + 1);
+
+        frame.render_widget(
+            titled(format!(" Tournament in {} ", settings.game_name)),
+            outer,
+        );
+
+        let lines = StrategyKind::ALL
+            .iter()
+            .zip(&self.entered)
+            .map(|(kind, &entered)| {
+                format!(
+                    " [{}] {}",
+                    if entered {
+                        "x"
+                    } else {
+                        " "
+                    },
+                    kind.name()
+                )
+            })
+            .chain(std::iter::once(format!(
+                " Rounds per match < {} >",
+                self.rounds
+            )));
+
+        for (index, (line, rect)) in lines.zip(rows).enumerate() {
+            let style = if index == self.selected {
+                Style::new().black().on_yellow().bold()
+            } else {
+                Style::new()
+            };
+
+            frame.render_widget(Paragraph::new(line).style(style), rect);
+        }
+
+        if let Some(error) = &self.error {
+            let rect = Rect::new(area.x, outer.bottom() + 1, area.width, 1).intersection(area);
+
+            frame.render_widget(Paragraph::new(error.as_str()).red().centered(), rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        thread,
+        time::{
+            Duration,
+            Instant,
+        },
+    };
+
+    use tokio::sync::mpsc::UnboundedReceiver;
+
+    use super::*;
+    use crate::{
+        events::AppEvent,
+        screens::settings::tests::settings,
+        ui::tests::render,
+    };
+
+    /// Passes on the messages of the background tasks until the tournament
+    /// has finished.
+    fn finish(screen: &mut TournamentScreen, receiver: &mut UnboundedReceiver<AppEvent>) {
+        let start = Instant::now();
+
+        while !screen.run.as_ref().unwrap().finished {
+            assert!(start.elapsed() < Duration::from_secs(10));
+
+            match receiver.try_recv() {
+                Ok(AppEvent::Message(message)) => screen.on_message(&message),
+                _ => thread::sleep(Duration::from_millis(1)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_setup() {
+        let settings = settings();
+        let (messenger, _receiver) = Messenger::channel();
+        let mut screen = TournamentScreen::new(&settings);
+
+        assert_eq!(screen.kinds(), StrategyKind::ALL);
+
+        screen.on_key(KeyCode::Char('a'), &settings, &messenger);
+        screen.on_key(KeyCode::Char(' '), &settings, &messenger);
+        screen.on_key(KeyCode::Enter, &settings, &messenger);
+
+        assert!(!screen.is_running());
+        assert!(render(|frame, area| screen.render(frame, area, &settings))
+            .contains("Pick at least two strategies"));
+
+        screen.on_key(KeyCode::Down, &settings, &messenger);
+        screen.on_key(KeyCode::Char(' '), &settings, &messenger);
+        screen.on_key(KeyCode::PageDown, &settings, &messenger);
+
+        assert_eq!(
+            screen.kinds(),
+            [StrategyKind::AlwaysCooperate, StrategyKind::AlwaysDefect]
+        );
+        assert_eq!(screen.rounds, 1);
+    }
+
+    #[test]
+    fn test_run() {
+        let settings = settings();
+        let (messenger, mut receiver) = Messenger::channel();
+        let mut screen = TournamentScreen::new(&settings);
+
+        screen.entered = vec![false; StrategyKind::ALL.len()];
+        screen.entered[0] = true;
+        screen.entered[1] = true;
+        screen.entered[3] = true;
+
+        screen.on_key(KeyCode::Enter, &settings, &messenger);
+        finish(&mut screen, &mut receiver);
+
+        let run = screen.run.as_ref().unwrap();
+
+        assert_eq!(run.pairings.len(), 3);
+        assert_eq!(run.standings[0].name, "always-defect");
+        assert_eq!(run.standings[0].total, 64);
+        assert_eq!(run.cooperation(1), 0);
+        assert_eq!(run.cooperation(2), 55);
+        assert_eq!(run.score_against(2, 1), Some((9, 10)));
+
+        let screen_text = render(|frame, area| screen.render(frame, area, &settings));
+
+        assert!(screen_text.contains("All 3 matches played"));
+        assert!(screen_text.contains("Round robin of 10 rounds in prisoners_dilemma"));
+        assert!(screen_text.contains("1 always-cooperate"));
+        assert!(screen_text.contains("55%"));
+        assert!(screen_text.contains("5.0"));
+
+        screen.on_key(KeyCode::Char('n'), &settings, &messenger);
+        assert!(!screen.is_running());
+    }
+
+    #[test]
+    fn test_new_waits_for_the_run_to_finish() {
+        let settings = settings();
+        let (messenger, mut receiver) = Messenger::channel();
+        let mut screen = TournamentScreen::new(&settings);
+
+        screen.on_key(KeyCode::Enter, &settings, &messenger);
+
+        let task = screen.run.as_ref().unwrap().task;
+
+        screen.on_key(KeyCode::Char('n'), &settings, &messenger);
+        screen.on_key(KeyCode::Enter, &settings, &messenger);
+
+        assert_eq!(screen.run.as_ref().map(|run| run.task), Some(task));
+
+        finish(&mut screen, &mut receiver);
+
+        screen.on_key(KeyCode::Char('n'), &settings, &messenger);
+        assert!(!screen.is_running());
+    }
+
+    #[test]
+    fn test_ignores_other_tasks() {
+        let settings = settings();
+        let (messenger, _receiver) = Messenger::channel();
+        let mut run = Run::start(
+            &[StrategyKind::AlwaysCooperate, StrategyKind::AlwaysDefect],
+            10,
+            &settings,
+            &messenger,
+        );
+
+        run.on_message(&Message::Finished { task: run.task + 1 });
+        assert!(!run.finished);
+
+        run.on_message(&Message::Finished { task: run.task });
+        assert!(run.finished);
+    }
+
+    #[test]
+    fn test_failure_stops_run() {
+        let settings = settings();
+        let (messenger, _receiver) = Messenger::channel();
+        let mut run = Run::start(
+            &[StrategyKind::AlwaysCooperate, StrategyKind::AlwaysDefect],
+            10,
+            &settings,
+            &messenger,
+        );
+
+        run.on_message(&Message::Failed {
+            task:   run.task,
+            reason: "out of moves".to_string(),
+        });
+
+        assert!(run.finished);
+        assert!(render(|frame, area| run.render(frame, area))
+            .contains("Stopped after 0 matches: out of moves"));
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io::{
+        self,
+        Stdout,
+    },
+    ops::{
+        Deref,
+        DerefMut,
+    },
+    panic,
+    thread,
+};
+
+use crossterm::{
+    cursor::Show,
+    event::{
+        DisableMouseCapture,
+        EnableMouseCapture,
+    },
+    execute,
+    terminal::{
+        disable_raw_mode,
+        enable_raw_mode,
+        EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    Terminal,
+};
+
+/// Puts the terminal back the way it was found: out of raw mode, back on
+/// the main screen, without mouse capture and with the cursor showing.
+///
+/// Every step is tried even if an earlier one fails, and doing it twice is
+/// harmless.
+pub fn restore() -> io::Result<()> {
+    let raw_mode = disable_raw_mode();
+
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        Show
+    )?;
+
+    raw_mode
+}
+
+/// Logs every panic for the log pane and the log file.
+///
+/// A panic on the main thread takes the app down, so the terminal is
+/// restored first and the panic reported as usual, on the main screen rather
+/// than being lost with the alternate one. Other threads are left to report
+/// their panics to the app, which keeps drawing.
+pub fn install_panic_hook() {
+    let report = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        let main = thread::current().name() == Some("main");
+
+        if main {
+            let _ = restore();
+        }
+
+        log::error!("{info}");
+        log::logger().flush();
+
+        if main {
+            report(info);
+        }
+    }));
+}
+
+/// The terminal the app draws on, set up for the app on creation and
+/// restored when dropped, whether the app returns or unwinds.
+pub struct TerminalGuard(Terminal<CrosstermBackend<Stdout>>);
+
+impl TerminalGuard {
+    /// Enters raw mode on the alternate screen and captures the mouse.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the terminal cannot be set up, having restored
+    /// whatever was set up already.
+    pub fn new() -> io::Result<Self> {
+        let setup = || {
+            enable_raw_mode()?;
+            execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+
+            Terminal::new(CrosstermBackend::new(io::stdout()))
+        };
+
+        match setup() {
+            Ok(terminal) => Ok(Self(terminal)),
+            Err(e) => {
+                let _ = restore();
+
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Deref for TerminalGuard {
+    type Target = Terminal<CrosstermBackend<Stdout>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TerminalGuard {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if let Err(e) = restore() {
+            log::error!("Could not restore the terminal: {e}");
+        }
+    }
+}
@@ -15,13 +15,74 @@ use ratatui::{
 };
 
 pub use crate::app::App;
+use crate::logger::LogPane;
 
-fn render_header(frame: &mut Frame, rect: Rect) {
+/// A bordered, centered cell of the payoff grid.
+pub fn cell<'a>(text: impl Into<Text<'a>>, style: Style) -> Paragraph<'a> {
+    Paragraph::new(text)
+        .block(Block::default().title("").borders(Borders::ALL))
+        .style(style)
+        .alignment(Alignment::Center)
+}
+
+/// Splits the terminal into the header, the body shown by the current
+/// screen, the log pane, which is empty unless `log` is set, and the footer.
+pub fn screen_layout(area: Rect, log: bool) -> [Rect; 4] {
+    let log_height = if log {
+        LogPane::HEIGHT
+    } else {
+        0
+    };
+
+    Layout::vertical([
+        Constraint::Length(5),
+        Constraint::Fill(1),
+        Constraint::Length(log_height),
+        Constraint::Length(3),
+    ])
+    .flex(layout::Flex::Center)
+    .areas(area)
+}
+
+/// Centers a list of `rows` one-line rows inside a bordered box at most
+/// `width` wide.
+///
+/// Returns the box and the area of each row that fits inside it, so that the
+/// same areas can be used to draw the rows and to find the row under the
+/// mouse.
+pub fn list_areas(area: Rect, width: u16, rows: usize) -> (Rect, Vec<Rect>) {
+    let height = u16::try_from(rows)
+        .unwrap_or(u16::MAX)
+        .saturating_add(2)
+        .min(area.height);
+
+    let [outer] = Layout::horizontal([Constraint::Length(width)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let [outer] = Layout::vertical([Constraint::Length(height)])
+        .flex(layout::Flex::Center)
+        .areas(outer);
+
+    let inner = outer.inner(Margin::new(1, 1));
+    let rows = (inner.y..inner.bottom())
+        .map(|y| Rect::new(inner.x, y, inner.width, 1))
+        .collect();
+
+    (outer, rows)
+}
+
+/// The index of the row under `(column, row)`, if any.
+pub fn row_at(rows: &[Rect], column: u16, row: u16) -> Option<usize> {
+    rows.iter()
+        .position(|rect| rect.contains(Position::new(column, row)))
+}
+
+fn render_header(frame: &mut Frame, rect: Rect, title: &str) {
     let header_block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::new(1, 1, 1, 0));
-    let header = Paragraph::new("Tactix")
+    let header = Paragraph::new(format!("Tactix | {title}"))
         .style(Style::new().white().on_red().bold())
         .centered()
         .block(header_block);
@@ -29,8 +90,8 @@ fn render_header(frame: &mut Frame, rect: Rect) {
     frame.render_widget(header, rect);
 }
 
-fn render_footer(frame: &mut Frame, rect: Rect) {
-    let footer = Paragraph::new("Press 'Q' to quit".to_string())
+fn render_footer(frame: &mut Frame, rect: Rect, hints: &str) {
+    let footer = Paragraph::new(hints.to_string())
         .block(
             Block::default()
                 .title("")
@@ -44,121 +105,67 @@ fn render_footer(frame: &mut Frame, rect: Rect) {
 }
 
 pub fn ui(frame: &mut Frame, app: &App) {
-    let size = frame.area();
-
-    let screen_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(5),
-            Constraint::Fill(1),
-            Constraint::Length(3),
-        ])
-        .flex(layout::Flex::Center)
-        .split(size);
-
-    render_header(frame, screen_layout[0]);
-
-    let main_content_layout = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(3, 5),
-            Constraint::Ratio(1, 5),
-        ])
-        .flex(layout::Flex::Center)
-        .split(screen_layout[1]);
-
-    let game_rows = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(20); 5])
-        .split(main_content_layout[1]);
-
-    let beth_header_row = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(2, 5),
-            Constraint::Ratio(1, 5),
-        ])
-        .split(game_rows[1]);
-
-    let aleph_header_row = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(20); 5])
-        .split(game_rows[2]);
-
-    let atlantis_row = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(20); 5])
-        .split(game_rows[3]);
-
-    let olympus_row = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(20); 5])
-        .split(game_rows[4]);
-
-    let beth_text = Paragraph::new("Player Beth")
-        .block(Block::default().title("").borders(Borders::ALL))
-        .style(Style::new().white().on_black())
-        .alignment(Alignment::Center);
-
-    frame.render_widget(beth_text, beth_header_row[2]);
-
-    let aleph_text = Paragraph::new("Player Aleph")
-        .block(Block::default().title("").borders(Borders::ALL))
-        .style(Style::new().white().on_black())
-        .alignment(Alignment::Center);
-
-    frame.render_widget(aleph_text, aleph_header_row[1]);
-
-    let atlantis_text = Paragraph::new(app.game_grid.game_options.choice_atlantis().to_string())
-        .block(Block::default().title("").borders(Borders::ALL))
-        .style(Style::new().white().on_black())
-        .alignment(Alignment::Center);
-
-    frame.render_widget(atlantis_text.clone(), aleph_header_row[2]);
-    frame.render_widget(atlantis_text.clone(), atlantis_row[1]);
-
-    let atlantis_atlantis_score =
-        Paragraph::new(app.game_grid.game_options.atlantis_atlantis().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
-
-    frame.render_widget(atlantis_atlantis_score, atlantis_row[2]);
-
-    let atlantis_olympus_score =
-        Paragraph::new(app.game_grid.game_options.atlantis_olympus().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
-
-    frame.render_widget(atlantis_olympus_score, atlantis_row[3]);
-
-    let olympus_text = Paragraph::new(app.game_grid.game_options.choice_olympus().to_string())
-        .block(Block::default().title("").borders(Borders::ALL))
-        .style(Style::new().white().on_black())
-        .alignment(Alignment::Center);
-
-    frame.render_widget(olympus_text.clone(), aleph_header_row[3]);
-    frame.render_widget(olympus_text.clone(), olympus_row[1]);
-
-    let olympus_atlantis_score =
-        Paragraph::new(app.game_grid.game_options.olympus_atlantis().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
-
-    frame.render_widget(olympus_atlantis_score, olympus_row[2]);
-
-    let olympus_olympus_score =
-        Paragraph::new(app.game_grid.game_options.olympus_olympus().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
-
-    frame.render_widget(olympus_olympus_score, olympus_row[3]);
+    let [header, body, log, footer] = screen_layout(frame.area(), app.log.shown);
+    let screen = app.screen();
+
+    render_header(frame, header, screen.title());
+    screen.render(frame, body, &app.settings);
+
+    if app.log.shown {
+        app.log.render(frame, log);
+    }
+
+    // The log can be toggled from any screen, unless its keys are taken as
+    // text.
+    if screen.is_typing() {
+        render_footer(frame, footer, screen.hints());
+    } else {
+        render_footer(frame, footer, &format!("{} | L: log", screen.hints()));
+    }
+}
 
-    render_footer(frame, screen_layout[2]);
+#[cfg(test)]
+pub mod tests {
+    use ratatui::backend::TestBackend;
+
+    use super::*;
+
+    /// The size of the terminal used in tests.
+    pub const AREA: Rect = Rect::new(0, 0, 120, 40);
+
+    /// Draws with `draw` on a test terminal and returns what is shown, one
+    /// line per row.
+    pub fn render(draw: impl FnOnce(&mut Frame, Rect)) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(AREA.width, AREA.height)).unwrap();
+
+        terminal.draw(|frame| draw(frame, frame.area())).unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .chunks(usize::from(AREA.width))
+            .map(|row| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_list_areas() {
+        let (outer, rows) = list_areas(AREA, 30, 4);
+
+        assert_eq!(outer.width, 30);
+        assert_eq!(outer.height, 6);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(row_at(&rows, rows[2].x + 3, rows[2].y), Some(2));
+        assert_eq!(row_at(&rows, outer.x, outer.y), None);
+
+        let (_, rows) = list_areas(Rect::new(0, 0, 40, 5), 30, 10);
+
+        assert_eq!(rows.len(), 3);
+    }
 }